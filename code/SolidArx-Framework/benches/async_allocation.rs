@@ -0,0 +1,73 @@
+//! Benchmark dell'allocazione concorrente dal pool del `MemoryManager`.
+//!
+//! Confronta 100 task che usano `allocate`/`deallocate` sincroni dentro `spawn_blocking`,
+//! con il gestore condiviso dietro un `std::sync::Mutex`, con gli stessi task che usano
+//! `allocate_async`/`deallocate_async` sul gestore condiviso con un `Arc`, il cui pool è
+//! protetto dal `tokio::sync::Mutex` interno.
+//!
+//! Esecuzione:
+//! cargo bench --bench async_allocation --features async
+
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use solid_arx_lib::config::{global_config::ApplicationType, memory_config::MemoryConfig};
+use solid_arx_lib::core::memory_management::MemoryManager;
+
+const TASKS: usize = 100;
+const ITERATIONS: usize = 1_000;
+const BUFFER_SIZE: usize = 4096;
+
+fn config() -> MemoryConfig {
+    MemoryConfig::new(32 * BUFFER_SIZE, BUFFER_SIZE, 1)
+}
+
+async fn run_blocking(manager: Arc<Mutex<MemoryManager>>) -> Duration {
+    let start = Instant::now();
+    let handles: Vec<_> = (0..TASKS)
+        .map(|_| {
+            let manager = Arc::clone(&manager);
+            tokio::task::spawn_blocking(move || {
+                for _ in 0..ITERATIONS {
+                    let mut manager = manager.lock().unwrap();
+                    let buffer = manager.allocate(None, BUFFER_SIZE).unwrap();
+                    manager.deallocate(buffer).unwrap();
+                }
+            })
+        })
+        .collect();
+    for handle in handles {
+        handle.await.unwrap();
+    }
+    start.elapsed()
+}
+
+async fn run_async(manager: Arc<MemoryManager>) -> Duration {
+    let start = Instant::now();
+    let handles: Vec<_> = (0..TASKS)
+        .map(|_| {
+            let manager = Arc::clone(&manager);
+            tokio::spawn(async move {
+                for _ in 0..ITERATIONS {
+                    let buffer = manager.allocate_async().await.unwrap();
+                    manager.deallocate_async(buffer).await.unwrap();
+                }
+            })
+        })
+        .collect();
+    for handle in handles {
+        handle.await.unwrap();
+    }
+    start.elapsed()
+}
+
+#[tokio::main]
+async fn main() {
+    let blocking = Arc::new(Mutex::new(MemoryManager::new(ApplicationType::WebApp, config()).unwrap()));
+    let elapsed = run_blocking(blocking).await;
+    println!("spawn_blocking + allocate:  {:?} ({} task x {} allocazioni)", elapsed, TASKS, ITERATIONS);
+
+    let native = Arc::new(MemoryManager::new(ApplicationType::WebApp, config()).unwrap());
+    let elapsed = run_async(native).await;
+    println!("allocate_async:             {:?} ({} task x {} allocazioni)", elapsed, TASKS, ITERATIONS);
+}
@@ -1,5 +1,10 @@
 use std::process::Command;
 fn main() {
+    // Interfaccia C del MemoryManager: header generato con cbindgen e libreria C dei test
+    if std::env::var("CARGO_FEATURE_FFI").is_ok() {
+        generate_ffi_header();
+    }
+
     // Verifica se Python è installato
     let python_check = Command::new("python")
         .arg("--version")
@@ -62,3 +67,37 @@ fn main() {
     // Notifica il compilatore Rust che c'è una dipendenza nativa (Python) da trovare
     println!("cargo:rerun-if-changed=build.rs");
 }
+
+/// Genera `arx_memory.h` in `OUT_DIR/include` a partire da `src/ffi/memory.rs` e compila il codice C
+/// che lo utilizza nei test di integrazione (`tests/ffi/memory_roundtrip.c`).
+///
+/// Nessun file viene scritto nell'albero dei sorgenti.
+fn generate_ffi_header() {
+    let crate_dir = std::env::var("CARGO_MANIFEST_DIR").expect("CARGO_MANIFEST_DIR non impostata");
+    let out_dir = std::env::var("OUT_DIR").expect("OUT_DIR non impostata");
+    let include_dir = std::path::Path::new(&out_dir).join("include");
+
+    cbindgen::Builder::new()
+        .with_src(std::path::Path::new(&crate_dir).join("src/ffi/memory.rs"))
+        .with_language(cbindgen::Language::C)
+        .with_include_guard("ARX_MEMORY_H")
+        .with_header("/* Generato automaticamente da cbindgen, non modificare. */")
+        .generate()
+        .expect("Generazione dell'header C fallita")
+        .write_to_file(include_dir.join("arx_memory.h"));
+
+    println!("cargo:rerun-if-changed=src/ffi/memory.rs");
+
+    // La libreria viene collegata solo ai test, non agli utilizzatori del crate,
+    // e non viene compilata se i test non sono presenti (ad esempio nel pacchetto pubblicato)
+    let roundtrip = std::path::Path::new(&crate_dir).join("tests/ffi/memory_roundtrip.c");
+    if roundtrip.exists() {
+        cc::Build::new()
+            .file(&roundtrip)
+            .include(&include_dir)
+            .cargo_metadata(false)
+            .compile("arx_ffi_roundtrip");
+        println!("cargo:rustc-link-arg-tests={}/libarx_ffi_roundtrip.a", out_dir);
+        println!("cargo:rerun-if-changed=tests/ffi/memory_roundtrip.c");
+    }
+}
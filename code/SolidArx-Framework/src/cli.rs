@@ -23,6 +23,10 @@ impl FromStr for DatabaseType {
             "postgresql" | "postgre" | "pgs" => Ok(DatabaseType::PostgreSQL(ConnectionConfig::default())),
             "sqlite" | "sl" => Ok(DatabaseType::SQLite(ConnectionConfig::default())),
             "mongodb" | "mongo" | "mg" => Ok(DatabaseType::MongoDB(ConnectionConfig::default())),
+            #[cfg(feature = "mysql")]
+            "mysql" | "mariadb" | "my" => Ok(DatabaseType::MySQL(ConnectionConfig::default())),
+            #[cfg(feature = "redis")]
+            "redis" | "rd" => Ok(DatabaseType::Redis(ConnectionConfig::default())),
             "none" | "None" => Ok(DatabaseType::None),
             _ => Err(format!("Tipo di database non riconosciuto: {}", input)),
         }
@@ -42,6 +46,9 @@ impl FromStr for ApplicationType {
             "desktopapp" | "desktop" | "da" => Ok(ApplicationType::DesktopApp),
             "automationscript" | "automation" | "as" => Ok(ApplicationType::AutomationScript),
             "embeddedsystem" | "embedded" | "es" => Ok(ApplicationType::EmbeddedSystem),
+            "wasmbased" | "wasm" | "wb" => Ok(ApplicationType::WasmBased),
+            "gameserver" | "game" | "gs" => Ok(ApplicationType::GameServer),
+            "mlinference" | "ml" | "mli" => Ok(ApplicationType::MLInference),
             _ => Err(format!("Tipo di applicazione non riconosciuto: {}", input)),
         }
     }
@@ -115,6 +122,9 @@ pub enum Commands {
             --app_type <DesktopApp>\n
             --app_type <AutomationScript>\n
             --app_type <EmbeddedSystem>\n
+            --app_type <WasmBased>\n
+            --app_type <GameServer>\n
+            --app_type <MLInference>\n
         \n
         --memory_scale u8 or --m u8           # Set Memory Multiplier, DEFAULT: 1\n
         --max_threads u8 or --t u8            # Set Max Threads, DEFAULT: 8\n
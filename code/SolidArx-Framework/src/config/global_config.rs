@@ -9,13 +9,19 @@
 
 // Tipi di applicazione supportati
 use clap::{Parser, ValueEnum}; // Necessario per l'implementazione di delle versioni di ApplicationType nel CLI
+#[cfg(feature = "serde")]
+use serde::{Serialize, Deserialize};
 #[derive(Debug,Clone,Parser, ValueEnum)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum ApplicationType {
     WebApp,
     ApiBackend,
     DesktopApp,
     AutomationScript,
     EmbeddedSystem,
+    WasmBased,  // Moduli WebAssembly: allocatore standard e pagine di memoria da 64 KB
+    GameServer,  // Server di gioco: due pool di buffer scambiati ad ogni frame
+    MLInference,  // Inferenza di modelli ML: buffer su pagine huge da 2 MB per ridurre la pressione sul TLB
     None,
 }
 
@@ -1,11 +1,105 @@
+use std::collections::VecDeque;
+use crate::config::global_config::ApplicationType;
+use crate::core::system_core::CoreError;
+use crate::core::memory_management::{AllocationStrategy, ManagedBuffer};
+#[cfg(feature = "serde")]
+use serde::{Serialize, Deserialize};
+#[cfg(feature = "serde")]
+use std::path::Path;
+
+/// Capacità in byte del buffer statico utilizzato dalla strategia `CustomEmbedded`
+/// quando la feature `embedded` è attiva. Essendo un parametro const-generic deve essere noto in compile time.
+pub const EMBEDDED_CAPACITY: usize = 64 * 1024; // 64 KB
+
+/// Dimensione massima in byte di `buffer_size` per i sistemi embedded, pari alla capacità del buffer statico.
+pub const MAX_EMBEDDED_BUFFER: usize = EMBEDDED_CAPACITY;
+
+/// Limite predefinito di una singola allocazione per le strategie diverse da `PoolBased` (vedi `MemoryConfig::max_allocation_size_for`).
+pub const DEFAULT_MAX_ALLOCATION_SIZE: usize = usize::MAX / 2;
+
+/// Variabili d'ambiente lette da `MemoryConfig::from_env_with_fallback`.
+pub const ENV_BUFFER_SIZE: &str = "SOLIDARX_BUFFER_SIZE";
+pub const ENV_POOL_SIZE: &str = "SOLIDARX_POOL_SIZE";
+pub const ENV_MEMORY_SCALE: &str = "SOLIDARX_MEMORY_SCALE";
+
+/// Politica di azzeramento dei buffer gestiti dal `MemoryManager`.
+///
+/// - `NeverZero`: I buffer riutilizzati dal pool mantengono il contenuto precedente.
+/// - `ZeroOnAllocate`: I buffer vengono azzerati prima di essere restituiti da `allocate`.
+/// - `ZeroOnDeallocate`: I buffer vengono azzerati in `deallocate`, prima di tornare al pool o al sistema.
+/// - `ZeroOnBoth`: I buffer vengono azzerati sia in allocazione che in deallocazione.
+/// - `ProtectAndZeroOnReuse`: Le pagine dei buffer restituiti al pool vengono protette con `mprotect`,
+///   così che un accesso ad un buffer già deallocato termini il processo. Quando il buffer viene nuovamente
+///   allocato le pagine tornano accessibili e l'intero buffer viene azzerato subito, prima di essere
+///   restituito da `allocate` (solo Linux con la feature `lazy-zero`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ZeroingPolicy {
+    NeverZero,
+    ZeroOnAllocate,
+    ZeroOnDeallocate,
+    ZeroOnBoth,
+    #[cfg(all(target_os = "linux", feature = "lazy-zero"))]
+    ProtectAndZeroOnReuse,
+}
+
+impl ZeroingPolicy {
+    /// Indica se i buffer devono essere azzerati in allocazione.
+    pub fn zero_on_allocate(&self) -> bool {
+        matches!(self, ZeroingPolicy::ZeroOnAllocate | ZeroingPolicy::ZeroOnBoth)
+    }
+
+    /// Indica se i buffer devono essere azzerati in deallocazione.
+    pub fn zero_on_deallocate(&self) -> bool {
+        matches!(self, ZeroingPolicy::ZeroOnDeallocate | ZeroingPolicy::ZeroOnBoth)
+    }
+
+    /// Indica se i buffer restituiti al pool devono essere protetti e azzerati alla successiva allocazione.
+    pub fn protect_free_buffers(&self) -> bool {
+        #[cfg(all(target_os = "linux", feature = "lazy-zero"))]
+        let protect = matches!(self, ZeroingPolicy::ProtectAndZeroOnReuse);
+        #[cfg(not(all(target_os = "linux", feature = "lazy-zero")))]
+        let protect = false;
+        protect
+    }
+}
+
+/// Stima della memoria fisica occupata dal pool configurato, prodotta da `MemoryConfig::estimate_memory_usage`.
+/// - `pool_bytes`: Byte dei buffer del pool (`pool_size`).
+/// - `overhead_bytes`: Byte delle strutture di gestione (`VecDeque` e puntatori `Box` dei buffer).
+/// - `total_bytes`: Somma di `pool_bytes` e `overhead_bytes`.
+/// - `percentage_of_system`: Percentuale di `total_bytes` rispetto alla memoria totale del sistema
+///   (sempre `0.0` senza la feature `system-check`).
+#[derive(Debug, Clone, PartialEq)]
+pub struct MemoryEstimate {
+    pub pool_bytes: usize,
+    pub overhead_bytes: usize,
+    pub total_bytes: usize,
+    pub percentage_of_system: f64,
+}
+
 /// Configurazione della memoria per il sistema.
 ///
 /// Questa configurazione è utilizzata per gestire le impostazioni relative alla memoria,
 /// come la dimensione del pool di buffer o la dimensione del buffer nei sistemi embedded.
+///
+/// Con la feature `serde` i campi assenti in fase di deserializzazione assumono i valori di `MemoryConfig::default()`.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(default))]
 pub struct MemoryConfig {
     pub pool_size: usize,    // Dimensione del pool di buffer (per PoolBased)
     pub buffer_size: usize,  // Dimensione del buffer (per Embedded)
     pub memory_scale: u8,   // Scala per la dimensione della memoria dei modelli
+    pub lazy_pool: bool,    // Rimanda l'allocazione dei buffer del pool alla prima `allocate`
+    pub zeroing_policy: ZeroingPolicy,  // Quando azzerare il contenuto dei buffer
+    pub max_allocation_size: Option<usize>,  // Limite invalicabile di una singola allocazione, `None` per il limite della strategia (vedi `max_allocation_size_for`)
+    pub max_single_allocation: Option<usize>,  // Alias di `max_allocation_size`: `allocate` rifiuta le richieste oltre il limite con `CoreError::ResourceAllocationError`
+    pub dynamic_fallback: bool,  // A pool esaurito alloca dinamicamente invece di restituire `CoreError::PoolExhausted`
+    pub required_alignment: Option<usize>,  // Allineamento imposto ad `allocate_aligned` dalla strategia `CustomEmbedded`
+    pub pressure_threshold: f32,  // Utilizzo del pool (0.0–1.0) oltre il quale viene invocata la callback di pressione
+    pub copy_on_transfer: bool,  // `MemoryManager::transfer` copia il buffer se le dimensioni dei buffer differiscono
+    #[cfg(feature = "compact-retry")]
+    pub compact_and_retry: bool,  // Compatta l'heap e ritenta una volta le allocazioni `Standard` fallite
 }
 
 /// Implementazione del valore di default per `MemoryConfig`.
@@ -17,6 +111,16 @@ impl Default for MemoryConfig {
             pool_size: 10,      // Valore di default: 10 buffer per il pool
             buffer_size: 1024,   // Valore di default: 1024 byte per buffer
             memory_scale: 1,  // Valore di default: scala 1.0 per la dimensione della memoria
+            lazy_pool: false,  // Valore di default: pool allocato alla creazione del MemoryManager
+            zeroing_policy: ZeroingPolicy::ZeroOnAllocate,  // Valore di default: buffer sempre azzerati in allocazione
+            max_allocation_size: None,  // Valore di default: `pool_size` per `PoolBased`, `usize::MAX / 2` altrimenti
+            max_single_allocation: None,  // Valore di default: nessun limite aggiuntivo
+            dynamic_fallback: true,  // Valore di default: allocazione dinamica a pool esaurito
+            required_alignment: None,  // Valore di default: nessun allineamento imposto
+            pressure_threshold: 0.9,  // Valore di default: pressione segnalata oltre il 90% dei buffer in uso
+            copy_on_transfer: true,  // Valore di default: trasferimento con copia se le dimensioni differiscono
+            #[cfg(feature = "compact-retry")]
+            compact_and_retry: true,  // Valore di default: un nuovo tentativo dopo la compattazione
         }
     }
 }
@@ -30,6 +134,185 @@ impl MemoryConfig {
             pool_size,
             buffer_size,
             memory_scale,
+            lazy_pool: false,
+            zeroing_policy: ZeroingPolicy::ZeroOnAllocate,
+            max_allocation_size: None,
+            max_single_allocation: None,
+            dynamic_fallback: true,
+            required_alignment: None,
+            pressure_threshold: 0.9,
+            copy_on_transfer: true,
+            #[cfg(feature = "compact-retry")]
+            compact_and_retry: true,
+        }
+    }
+
+    /// Verifica che la configurazione sia coerente con il tipo di applicazione.
+    ///
+    /// I vincoli controllati sono:
+    /// - `buffer_size > 0` per ogni tipo di applicazione;
+    /// - `pool_size >= buffer_size` per le applicazioni con strategia `PoolBased` (`WebApp`, `ApiBackend`),
+    ///   così che `pool_size / buffer_size` produca almeno un buffer;
+    /// - `pool_size >= 2 * buffer_size` per `GameServer`, così che ciascuno dei due pool di
+    ///   `DoubleBuffered` contenga almeno un buffer;
+    /// - `buffer_size <= MAX_EMBEDDED_BUFFER` per `EmbeddedSystem`;
+    /// - `pressure_threshold` compreso tra 0.0 e 1.0.
+    ///
+    /// # Ritorna
+    /// `Ok(())`, oppure `CoreError::ConfigurationError` con il campo e il vincolo violato.
+    pub fn validate(&self, app_type: ApplicationType) -> Result<(), CoreError> {
+        self.validate_common()?;
+        match app_type {
+            ApplicationType::WebApp | ApplicationType::ApiBackend => {
+                if self.pool_size < self.buffer_size {
+                    return Err(CoreError::ConfigurationError(format!(
+                        "pool_size non valido: {} deve essere almeno buffer_size ({}) per contenere un buffer del pool",
+                        self.pool_size, self.buffer_size
+                    ), Vec::new()));
+                }
+            }
+            ApplicationType::GameServer => {
+                if self.pool_size / self.buffer_size < 2 {
+                    return Err(CoreError::ConfigurationError(format!(
+                        "pool_size non valido: {} deve essere almeno il doppio di buffer_size ({}) per i due pool del frame",
+                        self.pool_size, self.buffer_size
+                    ), Vec::new()));
+                }
+            }
+            ApplicationType::EmbeddedSystem => {
+                if self.buffer_size > MAX_EMBEDDED_BUFFER {
+                    return Err(CoreError::ConfigurationError(format!(
+                        "buffer_size non valido: {} supera il massimo di {} byte per i sistemi embedded",
+                        self.buffer_size, MAX_EMBEDDED_BUFFER
+                    ), Vec::new()));
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Verifica i vincoli indipendenti dal tipo di applicazione (`buffer_size > 0` e `pressure_threshold`
+    /// compreso tra 0.0 e 1.0), controllati anche da `MemoryManager::with_strategy`.
+    pub(crate) fn validate_common(&self) -> Result<(), CoreError> {
+        if self.buffer_size == 0 {
+            return Err(CoreError::ConfigurationError(
+                "buffer_size non valido: deve essere maggiore di 0".to_string(),
+                Vec::new(),
+            ));
+        }
+        if !(0.0..=1.0).contains(&self.pressure_threshold) {
+            return Err(CoreError::ConfigurationError(format!(
+                "pressure_threshold non valido: {} deve essere compreso tra 0.0 e 1.0", self.pressure_threshold
+            ), Vec::new()));
         }
+        Ok(())
+    }
+
+    /// Stima la memoria fisica che il pool configurato occuperà, utile per la pianificazione della capacità.
+    ///
+    /// # Nota
+    /// L'overhead comprende la struttura `VecDeque` del pool e un `ManagedBuffer` per ciascun buffer;
+    /// i metadati dell'allocatore di sistema non sono considerati.
+    pub fn estimate_memory_usage(&self) -> MemoryEstimate {
+        let buffer_count = if self.buffer_size == 0 { 0 } else { self.pool_size / self.buffer_size };
+        let pool_bytes = self.pool_size;
+        let overhead_bytes = std::mem::size_of::<VecDeque<ManagedBuffer>>() + buffer_count * std::mem::size_of::<ManagedBuffer>();
+        let total_bytes = pool_bytes + overhead_bytes;
+
+        #[cfg(feature = "system-check")]
+        let percentage_of_system = match sys_info::mem_info() {
+            // `mem_info` restituisce la memoria totale in kilobyte
+            Ok(info) if info.total > 0 => total_bytes as f64 / (info.total as f64 * 1024.0) * 100.0,
+            _ => 0.0,
+        };
+        #[cfg(not(feature = "system-check"))]
+        let percentage_of_system = 0.0;
+
+        MemoryEstimate { pool_bytes, overhead_bytes, total_bytes, percentage_of_system }
     }
-}
\ No newline at end of file
+
+    /// Limite di una singola allocazione con la strategia indicata, verificato prima di ogni altra operazione.
+    ///
+    /// # Ritorna
+    /// `max_allocation_size` se impostato, altrimenti `pool_size` per `PoolBased` e
+    /// `DEFAULT_MAX_ALLOCATION_SIZE` (`usize::MAX / 2`) per le altre strategie.
+    /// Se è impostato anche l'alias `max_single_allocation` prevale il più restrittivo dei due.
+    pub fn max_allocation_size_for(&self, strategy: &AllocationStrategy) -> usize {
+        let max = match (self.max_allocation_size, strategy) {
+            (Some(max), _) => max,
+            (None, AllocationStrategy::PoolBased) => self.pool_size,
+            (None, _) => DEFAULT_MAX_ALLOCATION_SIZE,
+        };
+        self.max_single_allocation.map_or(max, |single| single.min(max))
+    }
+
+    /// Capacità del buffer statico per sistemi embedded, utilizzabile come parametro const-generic.
+    pub const fn embedded_capacity() -> usize {
+        EMBEDDED_CAPACITY
+    }
+
+    /// Costruisce la configurazione a livelli: valori di default, poi il file indicato (vedi
+    /// `config_from_file`) e infine le variabili d'ambiente `SOLIDARX_BUFFER_SIZE`,
+    /// `SOLIDARX_POOL_SIZE` e `SOLIDARX_MEMORY_SCALE`, che sostituiscono i campi corrispondenti.
+    ///
+    /// # Parametri
+    /// - `path`: Il file TOML o JSON da leggere, `None` per partire dai valori di default.
+    ///
+    /// # Ritorna
+    /// La configurazione risultante, oppure `CoreError::ConfigurationError` se il file non è valido
+    /// o una variabile d'ambiente non contiene un numero intero (il messaggio riporta il nome della variabile).
+    #[cfg(feature = "serde")]
+    pub fn from_env_with_fallback(path: Option<&Path>) -> Result<MemoryConfig, CoreError> {
+        let mut config = match path {
+            Some(path) => config_from_file(path)?,
+            None => MemoryConfig::default(),
+        };
+        if let Some(buffer_size) = env_override(ENV_BUFFER_SIZE)? {
+            config.buffer_size = buffer_size;
+        }
+        if let Some(pool_size) = env_override(ENV_POOL_SIZE)? {
+            config.pool_size = pool_size;
+        }
+        if let Some(memory_scale) = env_override(ENV_MEMORY_SCALE)? {
+            config.memory_scale = memory_scale;
+        }
+        Ok(config)
+    }
+}
+
+/// Legge e converte la variabile d'ambiente indicata; `None` se non è impostata.
+#[cfg(feature = "serde")]
+fn env_override<T: std::str::FromStr>(name: &str) -> Result<Option<T>, CoreError> {
+    let value = match std::env::var(name) {
+        Ok(value) => value,
+        Err(std::env::VarError::NotPresent) => return Ok(None),
+        Err(std::env::VarError::NotUnicode(_)) => {
+            return Err(CoreError::ConfigurationError(format!("{} non valido: il valore non è UTF-8", name), Vec::new()));
+        }
+    };
+    value.trim().parse().map(Some).map_err(|_| {
+        CoreError::ConfigurationError(format!("{} non valido: {:?} non è un numero intero", name, value), Vec::new())
+    })
+}
+
+/// Legge una `MemoryConfig` da un file JSON (estensione `.json`) o TOML (qualsiasi altra estensione);
+/// i campi non presenti assumono i valori di default.
+///
+/// # Ritorna
+/// La configurazione letta, oppure `CoreError::ConfigurationError` se il file non è leggibile
+/// o il contenuto non è una `MemoryConfig` valida.
+#[cfg(feature = "serde")]
+pub fn config_from_file(path: &Path) -> Result<MemoryConfig, CoreError> {
+    let content = std::fs::read_to_string(path).map_err(|e| {
+        CoreError::ConfigurationError(format!("Lettura di {:?} fallita: {}", path, e), Vec::new())
+    })?;
+    let invalid = |e: String| {
+        CoreError::ConfigurationError(format!("Configurazione della memoria in {:?} non valida: {}", path, e), Vec::new())
+    };
+    if path.extension().map_or(false, |ext| ext.eq_ignore_ascii_case("json")) {
+        serde_json::from_str(&content).map_err(|e| invalid(e.to_string()))
+    } else {
+        toml::from_str(&content).map_err(|e| invalid(e.to_string()))
+    }
+}
@@ -1,5 +1,9 @@
 use log::{info, error};
+use std::time::Duration;
+use std::path::PathBuf;
+use diesel_migrations::EmbeddedMigrations;
 use cfg_if::cfg_if;
+use crate::network::connection_management::mask_connection_url;
 
 /// Struttura che rappresenta le configurazioni specifiche per ogni tipo di database
 /// - `database_url`: URL del database da utilizzare.
@@ -7,6 +11,15 @@ use cfg_if::cfg_if;
 /// - `retry_attempts`: Numero massimo di tentativi di riconnessione in caso di fallimento.
 /// - `max_idle_time`: Durata massima per mantenere una connessione inattiva.
 /// - `connection_timeout`: Tempo massimo di attesa per stabilire una connessione.
+/// - `daily_query_budget`: Numero massimo di query eseguibili al giorno, `None` per nessun limite.
+/// - `auto_reconnect`: Ristabilisce la connessione e ritenta la query se la connessione è caduta.
+/// - `backoff_base`: Attesa di base del backoff esponenziale, `None` per usare `connection_timeout`.
+/// - `backoff_cap`: Attesa massima tra due tentativi di connessione, `None` per il limite predefinito.
+/// - `tls_config`: Certificati per le connessioni cifrate, `None` per connessioni in chiaro.
+/// - `auto_migrate`: Esegue le migrazioni in sospeso dopo ogni connessione stabilita da `initialize_connection`.
+/// - `migrations`: Restituisce le migrazioni dell'applicazione, incorporate con `embed_migrations!`,
+///   eseguite da `auto_migrate` (ad esempio `Some(|| MIGRATIONS)`).
+/// - `query_timeout`: Durata massima di una query, `None` per nessun limite (vedi `ConnectionManager::apply_session_settings`).
 #[derive(Debug, Clone)]
 pub struct ConnectionConfig {
     pub database_url: Option<String>,
@@ -14,6 +27,194 @@ pub struct ConnectionConfig {
     pub retry_attempts: Option<u32>,
     pub max_idle_time: Option<u64>,       // Durata massima per mantenere una connessione inattiva
     pub connection_timeout: Option<u64>,  // Tempo massimo di attesa per stabilire una connessione
+    pub daily_query_budget: Option<u64>,  // Limite giornaliero di query per evitare consumi incontrollati
+    pub auto_reconnect: bool,             // Riconnessione automatica sulle connessioni cadute
+    pub backoff_base: Option<Duration>,   // Attesa di base del backoff tra i tentativi di connessione
+    pub backoff_cap: Option<Duration>,    // Attesa massima del backoff tra i tentativi di connessione
+    pub tls_config: Option<TlsConfig>,    // Configurazione TLS delle connessioni cifrate
+    pub auto_migrate: bool,               // Migrazioni automatiche alla connessione
+    pub migrations: Option<fn() -> EmbeddedMigrations>,  // Migrazioni dell'applicazione per `auto_migrate`
+    pub query_timeout: Option<Duration>,  // Durata massima di una query
+}
+
+/// Configurazione TLS per le connessioni cifrate a PostgreSQL e MongoDB.
+/// - `ca_cert_path`: Certificato dell'autorità di certificazione che ha firmato quello del server.
+/// - `client_cert_path`: Certificato del client per l'autenticazione reciproca. Per MongoDB il file
+///   deve contenere sia il certificato che la chiave privata.
+/// - `client_key_path`: Chiave privata del certificato del client (solo PostgreSQL).
+/// - `verify_hostname`: Verifica che il certificato del server corrisponda all'host
+///   (`sslmode=verify-full`); se disattivato viene verificata solo la catena (`sslmode=verify-ca`).
+#[derive(Debug, Clone, PartialEq)]
+pub struct TlsConfig {
+    pub ca_cert_path: PathBuf,
+    pub client_cert_path: Option<PathBuf>,
+    pub client_key_path: Option<PathBuf>,
+    pub verify_hostname: bool,
+}
+
+impl TlsConfig {
+    /// File di certificati e chiavi configurati, nell'ordine CA, certificato e chiave del client.
+    pub fn files(&self) -> Vec<&PathBuf> {
+        std::iter::once(&self.ca_cert_path)
+            .chain(self.client_cert_path.as_ref())
+            .chain(self.client_key_path.as_ref())
+            .collect()
+    }
+}
+
+impl Default for ConnectionConfig {
+    fn default() -> Self {
+        ConnectionConfig {
+            database_url: None,
+            max_connections: None,
+            retry_attempts: None,
+            max_idle_time: None,
+            connection_timeout: None,
+            daily_query_budget: None,
+            auto_reconnect: true,  // Valore di default: riconnessione automatica attiva
+            backoff_base: None,
+            backoff_cap: None,
+            tls_config: None,
+            auto_migrate: false,  // Valore di default: migrazioni eseguite solo con `ConnectionManager::migrate`
+            migrations: None,
+            query_timeout: None,
+        }
+    }
+}
+
+impl ConnectionConfig {
+    /// Crea un `ConnectionConfigBuilder` vuoto.
+    pub fn builder() -> ConnectionConfigBuilder {
+        ConnectionConfigBuilder::default()
+    }
+}
+
+/// Errori restituiti da `ConnectionConfigBuilder::build`.
+/// - `MissingField`: Un campo obbligatorio non è stato impostato.
+/// - `InvalidValue`: Un campo ha un valore non ammesso.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConfigBuildError {
+    MissingField(&'static str),
+    InvalidValue { field: &'static str, reason: String },
+}
+
+impl std::fmt::Display for ConfigBuildError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConfigBuildError::MissingField(field) => write!(f, "Campo obbligatorio non impostato: {}", field),
+            ConfigBuildError::InvalidValue { field, reason } => write!(f, "Valore non valido per {}: {}", field, reason),
+        }
+    }
+}
+
+impl std::error::Error for ConfigBuildError {}
+
+/// Builder di `ConnectionConfig` che verifica i campi obbligatori prima della costruzione,
+/// così che `ConnectionManager` non debba gestire valori mancanti a runtime.
+///
+/// I campi non impostati restano `None` nella configurazione prodotta, come in `ConnectionConfig::default()`.
+#[derive(Debug, Clone, Default)]
+pub struct ConnectionConfigBuilder {
+    config: ConnectionConfig,
+}
+
+impl ConnectionConfigBuilder {
+    /// Imposta l'URL del database (obbligatorio).
+    pub fn database_url(&mut self, url: &str) -> &mut Self {
+        self.config.database_url = Some(url.to_string());
+        self
+    }
+
+    /// Imposta il numero massimo di tentativi di connessione.
+    pub fn max_attempts(&mut self, n: u32) -> &mut Self {
+        self.config.retry_attempts = Some(n);
+        self
+    }
+
+    /// Imposta il tempo massimo di attesa per stabilire una connessione, arrotondato ai secondi.
+    pub fn retry_timeout(&mut self, timeout: Duration) -> &mut Self {
+        self.config.connection_timeout = Some(timeout.as_secs());
+        self
+    }
+
+    /// Imposta il numero massimo di connessioni simultanee.
+    pub fn pool_size(&mut self, n: u32) -> &mut Self {
+        self.config.max_connections = Some(n);
+        self
+    }
+
+    /// Imposta la durata massima di inattività di una connessione, arrotondata ai secondi.
+    pub fn max_idle_time(&mut self, idle: Duration) -> &mut Self {
+        self.config.max_idle_time = Some(idle.as_secs());
+        self
+    }
+
+    /// Imposta il limite giornaliero di query.
+    pub fn daily_query_budget(&mut self, budget: u64) -> &mut Self {
+        self.config.daily_query_budget = Some(budget);
+        self
+    }
+
+    /// Attiva o disattiva la riconnessione automatica.
+    pub fn auto_reconnect(&mut self, enabled: bool) -> &mut Self {
+        self.config.auto_reconnect = enabled;
+        self
+    }
+
+    /// Imposta l'attesa di base del backoff esponenziale.
+    pub fn backoff_base(&mut self, base: Duration) -> &mut Self {
+        self.config.backoff_base = Some(base);
+        self
+    }
+
+    /// Imposta l'attesa massima tra due tentativi di connessione.
+    pub fn backoff_cap(&mut self, cap: Duration) -> &mut Self {
+        self.config.backoff_cap = Some(cap);
+        self
+    }
+
+    /// Imposta i certificati per le connessioni cifrate.
+    pub fn tls_config(&mut self, tls: TlsConfig) -> &mut Self {
+        self.config.tls_config = Some(tls);
+        self
+    }
+
+    /// Attiva o disattiva le migrazioni automatiche alla connessione.
+    pub fn auto_migrate(&mut self, enabled: bool) -> &mut Self {
+        self.config.auto_migrate = enabled;
+        self
+    }
+
+    /// Imposta le migrazioni dell'applicazione eseguite da `auto_migrate`.
+    pub fn migrations(&mut self, migrations: fn() -> EmbeddedMigrations) -> &mut Self {
+        self.config.migrations = Some(migrations);
+        self
+    }
+
+    /// Imposta la durata massima di una query.
+    pub fn query_timeout(&mut self, timeout: Duration) -> &mut Self {
+        self.config.query_timeout = Some(timeout);
+        self
+    }
+
+    /// Verifica i campi impostati e costruisce la `ConnectionConfig`.
+    ///
+    /// # Ritorna
+    /// - `Ok(ConnectionConfig)`: La configurazione costruita.
+    /// - `Err(ConfigBuildError::MissingField)`: Se `database_url` non è impostato o è vuoto.
+    /// - `Err(ConfigBuildError::InvalidValue)`: Se `max_attempts` o `pool_size` valgono 0.
+    pub fn build(&self) -> Result<ConnectionConfig, ConfigBuildError> {
+        if self.config.database_url.as_deref().map_or(true, |url| url.trim().is_empty()) {
+            return Err(ConfigBuildError::MissingField("database_url"));
+        }
+        if self.config.retry_attempts == Some(0) {
+            return Err(ConfigBuildError::InvalidValue { field: "max_attempts", reason: "deve essere almeno 1".to_string() });
+        }
+        if self.config.max_connections == Some(0) {
+            return Err(ConfigBuildError::InvalidValue { field: "pool_size", reason: "deve essere almeno 1".to_string() });
+        }
+        Ok(self.config.clone())
+    }
 }
 
 /// Enum per definire i diversi tipi di sistemi di database supportati.
@@ -23,6 +224,10 @@ pub enum DatabaseType {
     PostgreSQL(ConnectionConfig),
     SQLite(ConnectionConfig),
     MongoDB(ConnectionConfig),
+    #[cfg(feature = "mysql")]
+    MySQL(ConnectionConfig),
+    #[cfg(feature = "redis")]
+    Redis(ConnectionConfig),
     None,
 }
 
@@ -113,17 +318,23 @@ impl DatabaseType {
             }
         }
         
-        return Ok(
-            DatabaseType::PostgreSQL(
-                ConnectionConfig {
-                    database_url:du,
-                    max_connections:mc,
-                    retry_attempts:ra,
-                    max_idle_time:mit,       
-                    connection_timeout:ct,   
-                }
-            )
-        );
+        let config = ConnectionConfig {
+            database_url:du,
+            max_connections:mc,
+            retry_attempts:ra,
+            max_idle_time:mit,       
+            connection_timeout:ct,   
+            daily_query_budget: None,
+            auto_reconnect: true,
+            backoff_base: None,
+            backoff_cap: None,
+            tls_config: None,
+            auto_migrate: false,
+            migrations: None,
+            query_timeout: None,
+        };
+
+        return Ok(DatabaseType::from(config));
             
     }
        
@@ -134,27 +345,74 @@ impl DatabaseType {
         match self {
             // Cattura ogni variante di database e logga i rispettivi dettagli di configurazione
             DatabaseType::PostgreSQL(config) | DatabaseType::SQLite(config) | DatabaseType::MongoDB(config) => {
-                info!("Database URL: {}", config.database_url.as_ref().unwrap_or(&"Non specificato".to_string()));
-                // Log del numero massimo di connessioni, se definito
-                if let Some(max_conn) = config.max_connections {
-                    info!("Max Connections: {}", max_conn);
-                }
-                // Log del numero di tentativi di riconnessione, se definito
-                if let Some(retries) = config.retry_attempts {
-                    info!("Retry Attempts: {}", retries);
-                }
-                // Log della durata massima di inattività, se definita
-                if let Some(idle_time) = config.max_idle_time {
-                    info!("Max Idle Time: {}", idle_time);
-                }
-                // Log di attesa massimo per la connessione, se definito
-                if let Some(timeout) = config.connection_timeout {
-                    info!("Connection Timeout: {}", timeout);
-                }
+                config.log_status();
+            }
+            #[cfg(feature = "mysql")]
+            DatabaseType::MySQL(config) => {
+                config.log_status();
+            }
+            #[cfg(feature = "redis")]
+            DatabaseType::Redis(config) => {
+                config.log_status();
             }
             DatabaseType::None => {
                 info!("Nessun database configurato.");
             }
         }
     }
+}
+
+/// Determina il tipo di database dallo schema di `database_url`.
+///
+/// Gli URL `mongodb://` e `mongodb+srv://` identificano MongoDB, gli URL `sqlite://`, `:memory:` e i file
+/// `.db`/`.sqlite` identificano SQLite, `mysql://` MySQL/MariaDB e `redis://`/`rediss://` Redis (con le
+/// rispettive feature); ogni altro URL viene trattato come PostgreSQL.
+impl From<ConnectionConfig> for DatabaseType {
+    fn from(config: ConnectionConfig) -> Self {
+        let url = config.database_url.clone().unwrap_or_default();
+
+        // Gli URL `mysql://` identificano un database MySQL/MariaDB
+        #[cfg(feature = "mysql")]
+        if url.starts_with("mysql://") {
+            return DatabaseType::MySQL(config);
+        }
+
+        // Gli URL `redis://` e `rediss://` identificano un server Redis
+        #[cfg(feature = "redis")]
+        if url.starts_with("redis://") || url.starts_with("rediss://") {
+            return DatabaseType::Redis(config);
+        }
+
+        if url.starts_with("mongodb://") || url.starts_with("mongodb+srv://") {
+            DatabaseType::MongoDB(config)
+        } else if url.starts_with("sqlite:") || url == ":memory:" || url.ends_with(".db") || url.ends_with(".sqlite") {
+            DatabaseType::SQLite(config)
+        } else {
+            DatabaseType::PostgreSQL(config)
+        }
+    }
+}
+
+impl ConnectionConfig {
+    /// Registra come `info` nel log i dettagli della configurazione.
+    fn log_status(&self) {
+        // Le credenziali non devono comparire nel log
+        info!("Database URL: {}", self.database_url.as_deref().map_or("Non specificato".to_string(), mask_connection_url));
+        // Log del numero massimo di connessioni, se definito
+        if let Some(max_conn) = self.max_connections {
+            info!("Max Connections: {}", max_conn);
+        }
+        // Log del numero di tentativi di riconnessione, se definito
+        if let Some(retries) = self.retry_attempts {
+            info!("Retry Attempts: {}", retries);
+        }
+        // Log della durata massima di inattività, se definita
+        if let Some(idle_time) = self.max_idle_time {
+            info!("Max Idle Time: {}", idle_time);
+        }
+        // Log di attesa massimo per la connessione, se definito
+        if let Some(timeout) = self.connection_timeout {
+            info!("Connection Timeout: {}", timeout);
+        }
+    }
 }
\ No newline at end of file
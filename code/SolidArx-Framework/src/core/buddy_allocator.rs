@@ -0,0 +1,157 @@
+//! Modulo per l'allocatore buddy system.
+//!
+//! Il `BuddyAllocator` suddivide un'unica area di memoria in blocchi di dimensione potenza di due.
+//! Ogni richiesta viene servita dal blocco libero più piccolo sufficiente, dividendo i blocchi più
+//! grandi quando necessario; alla deallocazione il blocco viene fuso con il proprio "buddy"
+//! se anch'esso è libero.
+
+use std::collections::BTreeSet;
+use log::info;
+
+use crate::core::system_core::CoreError;
+
+/// Blocco assegnato dal `BuddyAllocator`.
+/// - `offset`: Posizione di inizio del blocco nell'area di memoria.
+/// - `order`: Ordine del blocco, la cui dimensione è `min_block << order`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BuddyBlock {
+    pub offset: usize,
+    pub order: usize,
+}
+
+/// Allocatore buddy system su un'area di memoria di dimensione potenza di due.
+pub struct BuddyAllocator {
+    memory: Box<[u8]>,
+    min_block: usize,
+    free_lists: Vec<BTreeSet<usize>>,  // Offset dei blocchi liberi per ciascun ordine
+}
+
+impl BuddyAllocator {
+    /// Crea un allocatore su un'area di `total_size` byte con blocchi minimi di `min_block` byte.
+    ///
+    /// # Ritorna
+    /// L'allocatore, oppure `CoreError::ConfigurationError` se le dimensioni non sono potenze
+    /// di due o `min_block` supera `total_size`.
+    pub fn new(total_size: usize, min_block: usize) -> Result<Self, CoreError> {
+        if !total_size.is_power_of_two() || !min_block.is_power_of_two() || min_block > total_size {
+            return Err(CoreError::ConfigurationError(format!(
+                "Dimensioni non valide per il buddy system: totale {}, blocco minimo {}", total_size, min_block
+            ), Vec::new()));
+        }
+        let max_order = (total_size / min_block).trailing_zeros() as usize;
+        let mut free_lists = vec![BTreeSet::new(); max_order + 1];
+        free_lists[max_order].insert(0);
+
+        info!("BuddyAllocator inizializzato con {} byte e blocchi minimi di {} byte", total_size, min_block);
+        Ok(BuddyAllocator { memory: vec![0u8; total_size].into_boxed_slice(), min_block, free_lists })
+    }
+
+    /// Dimensione in byte di un blocco dell'ordine indicato.
+    pub fn block_size(&self, order: usize) -> usize {
+        self.min_block << order
+    }
+
+    /// Assegna un blocco di almeno `size` byte.
+    ///
+    /// # Ritorna
+    /// Il blocco assegnato, oppure `CoreError::ResourceAllocationError` se non esiste un blocco
+    /// libero abbastanza grande.
+    pub fn allocate(&mut self, size: usize) -> Result<BuddyBlock, CoreError> {
+        let order = (0..self.free_lists.len())
+            .find(|order| self.block_size(*order) >= size)
+            .ok_or_else(|| CoreError::ResourceAllocationError(format!("Richiesta di {} byte oltre la capacità del buddy system", size), Vec::new()))?;
+
+        let available = (order..self.free_lists.len())
+            .find(|o| !self.free_lists[*o].is_empty())
+            .ok_or_else(|| CoreError::ResourceAllocationError(format!("Nessun blocco libero per {} byte", size), Vec::new()))?;
+
+        let offset = self.free_lists[available].pop_first().expect("Lista verificata non vuota");
+        // Divide il blocco fino all'ordine richiesto, liberando la metà superiore ad ogni passo
+        for split in (order..available).rev() {
+            self.free_lists[split].insert(offset + self.block_size(split));
+        }
+
+        let block = BuddyBlock { offset, order };
+        self.slice_mut(block).fill(0);
+        Ok(block)
+    }
+
+    /// Restituisce un blocco all'allocatore fondendolo con i buddy liberi.
+    pub fn deallocate(&mut self, block: BuddyBlock) -> Result<(), CoreError> {
+        if block.order >= self.free_lists.len() || block.offset % self.block_size(block.order) != 0 {
+            return Err(CoreError::ResourceAllocationError(format!("Blocco buddy non valido: {:?}", block), Vec::new()));
+        }
+
+        let mut offset = block.offset;
+        let mut order = block.order;
+        while order + 1 < self.free_lists.len() {
+            let buddy = offset ^ self.block_size(order);
+            if !self.free_lists[order].remove(&buddy) {
+                break;
+            }
+            offset = offset.min(buddy);
+            order += 1;
+        }
+        self.free_lists[order].insert(offset);
+        Ok(())
+    }
+
+    /// Restituisce lo slice di memoria del blocco.
+    pub fn slice_mut(&mut self, block: BuddyBlock) -> &mut [u8] {
+        let size = self.block_size(block.order);
+        &mut self.memory[block.offset..block.offset + size]
+    }
+
+    /// Numero totale di byte liberi.
+    pub fn total_free_bytes(&self) -> usize {
+        self.free_lists
+            .iter()
+            .enumerate()
+            .map(|(order, list)| list.len() * self.block_size(order))
+            .sum()
+    }
+
+    /// Dimensione in byte della più grande area libera contigua, anche composta da blocchi non buddy adiacenti.
+    pub fn largest_free_contiguous_bytes(&self) -> usize {
+        let mut blocks: Vec<(usize, usize)> = self.free_lists
+            .iter()
+            .enumerate()
+            .flat_map(|(order, list)| list.iter().map(move |offset| (*offset, order)))
+            .map(|(offset, order)| (offset, self.block_size(order)))
+            .collect();
+        blocks.sort_unstable();
+        largest_contiguous_run(&blocks)
+    }
+
+    /// Frammentazione della memoria libera: `1.0 - (largest_free_contiguous_bytes / total_free_bytes)`.
+    ///
+    /// Vale `0.0` se la memoria libera è interamente contigua o assente.
+    pub fn fragmentation_ratio(&self) -> f64 {
+        fragmentation_ratio(self.largest_free_contiguous_bytes(), self.total_free_bytes())
+    }
+}
+
+/// Calcola la più lunga sequenza contigua di aree `(offset, len)` ordinate per offset.
+pub(crate) fn largest_contiguous_run(areas: &[(usize, usize)]) -> usize {
+    let mut largest = 0;
+    let mut run_end = None;
+    let mut run_len = 0;
+    for (offset, len) in areas.iter() {
+        if run_end == Some(*offset) {
+            run_len += len;
+        } else {
+            run_len = *len;
+        }
+        run_end = Some(offset + len);
+        largest = largest.max(run_len);
+    }
+    largest
+}
+
+/// Calcola il rapporto di frammentazione a partire dall'area contigua più grande e dal totale libero.
+pub(crate) fn fragmentation_ratio(largest_free: usize, total_free: usize) -> f64 {
+    if total_free == 0 {
+        return 0.0;
+    }
+    1.0 - (largest_free as f64 / total_free as f64)
+}
@@ -0,0 +1,336 @@
+//! Modulo per le mappe in memoria con politica di rimozione.
+//!
+//! L'`EvictingMap` si usa come una `HashMap` ma applica una `EvictionPolicy` ad ogni inserimento,
+//! così che le mappe dei modelli CRUD (`TASKS_IN_MEMORY`, `DEVICES_IN_MEMORY`, ...) non crescano senza limiti. Le voci sono collegate in una
+//! lista doppiamente concatenata (dalla più recente alla meno recente) indicizzata per chiave:
+//! con `LRU` la voce da rimuovere è sempre in coda e viene trovata in O(1), con `TTL` la coda
+//! contiene le voci inserite per prime e quindi le prime a scadere.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::Hash;
+use std::ops::Index;
+use std::time::{Duration, Instant};
+use log::info;
+
+/// Politica di rimozione delle voci di un'`EvictingMap`.
+///
+/// - `None`: Nessuna rimozione, la mappa cresce senza limiti.
+/// - `LRU`: Oltre `max_entries` voci viene rimossa quella letta o scritta meno di recente.
+/// - `LFU`: Oltre `max_entries` voci viene rimossa quella letta meno volte (a parità, la più vecchia).
+/// - `TTL`: Le voci inserite da almeno `duration` vengono rimosse alla lettura successiva.
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum EvictionPolicy {
+    #[default]
+    None,
+    LRU { max_entries: usize },
+    LFU { max_entries: usize },
+    TTL { duration: Duration },
+}
+
+/// Callback invocata con chiave e valore di ogni voce rimossa dalla politica.
+pub type EvictionCallback<K, V> = Box<dyn Fn(K, V) + Send + Sync>;
+
+/// Voce dell'`EvictingMap` con i collegamenti alle voci adiacenti nella lista.
+struct Entry<K, V> {
+    value: V,
+    inserted_at: Instant,
+    hits: u64,
+    prev: Option<K>,  // Voce più recente
+    next: Option<K>,  // Voce meno recente
+}
+
+/// Mappa con politica di rimozione (vedi `EvictionPolicy`).
+///
+/// # Nota
+/// Con `TTL` le voci scadute restano nella mappa fino alla lettura successiva (`get`, `get_mut`
+/// o `purge_expired`): `len` può quindi includerle, mentre `contains_key` e `iter` le ignorano.
+pub struct EvictingMap<K, V> {
+    entries: HashMap<K, Entry<K, V>>,
+    head: Option<K>,
+    tail: Option<K>,
+    policy: EvictionPolicy,
+    on_evict: Option<EvictionCallback<K, V>>,
+}
+
+impl<K: Eq + Hash + Clone, V> Default for EvictingMap<K, V> {
+    fn default() -> Self {
+        Self::new(EvictionPolicy::None)
+    }
+}
+
+impl<K: fmt::Debug, V> fmt::Debug for EvictingMap<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EvictingMap")
+            .field("policy", &self.policy)
+            .field("len", &self.entries.len())
+            .finish()
+    }
+}
+
+impl<K: Eq + Hash + Clone, V> EvictingMap<K, V> {
+    /// Crea una mappa vuota con la politica indicata.
+    pub fn new(policy: EvictionPolicy) -> Self {
+        Self { entries: HashMap::new(), head: None, tail: None, policy, on_evict: None }
+    }
+
+    /// Imposta la callback invocata con chiave e valore di ogni voce rimossa dalla politica.
+    /// Le voci rimosse esplicitamente con `remove` o `clear` non la invocano.
+    pub fn set_on_evict(&mut self, callback: EvictionCallback<K, V>) {
+        self.on_evict = Some(callback);
+    }
+
+    /// Politica di rimozione della mappa.
+    pub fn policy(&self) -> EvictionPolicy {
+        self.policy
+    }
+
+    /// Sostituisce la politica di rimozione, applicandola subito alle voci già presenti.
+    ///
+    /// # Ritorna
+    /// Il numero di voci rimosse per rispettare la nuova politica.
+    pub fn set_policy(&mut self, policy: EvictionPolicy) -> usize {
+        info!("Politica di rimozione impostata a {:?}", policy);
+        self.policy = policy;
+        let mut evicted = self.purge_expired();
+        if let EvictionPolicy::LRU { max_entries } | EvictionPolicy::LFU { max_entries } = policy {
+            while self.entries.len() > max_entries {
+                let Some(victim) = self.victim() else { break };
+                self.evict(&victim);
+                evicted += 1;
+            }
+        }
+        evicted
+    }
+
+    /// Inserisce una voce applicando la politica di rimozione.
+    ///
+    /// # Ritorna
+    /// Il valore precedente associato alla chiave, se presente.
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        if let Some(entry) = self.entries.get_mut(&key) {
+            let previous = std::mem::replace(&mut entry.value, value);
+            entry.inserted_at = Instant::now();
+            self.unlink(&key);
+            self.push_front(key);
+            return Some(previous);
+        }
+
+        if let EvictionPolicy::LRU { max_entries } | EvictionPolicy::LFU { max_entries } = self.policy {
+            if max_entries == 0 {
+                self.notify_evict(key, value);
+                return None;
+            }
+            if self.entries.len() >= max_entries {
+                if let Some(victim) = self.victim() {
+                    self.evict(&victim);
+                }
+            }
+        }
+
+        self.entries.insert(key.clone(), Entry { value, inserted_at: Instant::now(), hits: 0, prev: None, next: None });
+        self.push_front(key);
+        None
+    }
+
+    /// Restituisce il valore associato alla chiave, registrando l'accesso per `LRU` e `LFU`.
+    pub fn get(&mut self, key: &K) -> Option<&V> {
+        self.touch(key)?;
+        self.entries.get(key).map(|entry| &entry.value)
+    }
+
+    /// Come `get`, ma restituisce un riferimento mutabile al valore.
+    pub fn get_mut(&mut self, key: &K) -> Option<&mut V> {
+        self.touch(key)?;
+        self.entries.get_mut(key).map(|entry| &mut entry.value)
+    }
+
+    /// Restituisce il valore associato alla chiave senza registrare l'accesso, ignorando le voci scadute.
+    pub fn peek(&self, key: &K) -> Option<&V> {
+        self.entries.get(key).filter(|entry| !self.is_expired(entry)).map(|entry| &entry.value)
+    }
+
+    /// Indica se la chiave è presente e non scaduta, senza registrare l'accesso.
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.entries.get(key).map_or(false, |entry| !self.is_expired(entry))
+    }
+
+    /// Rimuove la voce associata alla chiave senza invocare la callback di rimozione.
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        if !self.entries.contains_key(key) {
+            return None;
+        }
+        self.unlink(key);
+        self.entries.remove(key).map(|entry| entry.value)
+    }
+
+    /// Rimuove tutte le voci senza invocare la callback di rimozione.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.head = None;
+        self.tail = None;
+    }
+
+    /// Numero di voci nella mappa.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Indica se la mappa è vuota.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Itera sulle voci non scadute, senza registrare l'accesso.
+    pub fn iter(&self) -> impl Iterator<Item = (&K, &V)> {
+        self.entries
+            .iter()
+            .filter(move |(_, entry)| !self.is_expired(entry))
+            .map(|(key, entry)| (key, &entry.value))
+    }
+
+    /// Come `iter`, ma restituisce riferimenti mutabili ai valori.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (&K, &mut V)> {
+        let policy = self.policy;
+        self.entries
+            .iter_mut()
+            .filter(move |(_, entry)| !Self::expired_under(policy, entry))
+            .map(|(key, entry)| (key, &mut entry.value))
+    }
+
+    /// Chiavi delle voci non scadute.
+    pub fn keys(&self) -> impl Iterator<Item = &K> {
+        self.iter().map(|(key, _)| key)
+    }
+
+    /// Valori delle voci non scadute.
+    pub fn values(&self) -> impl Iterator<Item = &V> {
+        self.iter().map(|(_, value)| value)
+    }
+
+    /// Valori mutabili delle voci non scadute.
+    pub fn values_mut(&mut self) -> impl Iterator<Item = &mut V> {
+        self.iter_mut().map(|(_, value)| value)
+    }
+
+    /// Rimuove le voci scadute con la politica `TTL`, invocando la callback per ciascuna.
+    ///
+    /// # Ritorna
+    /// Il numero di voci rimosse.
+    pub fn purge_expired(&mut self) -> usize {
+        let mut purged = 0;
+        while let Some(oldest) = self.tail.clone() {
+            let expired = self.entries.get(&oldest).map_or(false, |entry| self.is_expired(entry));
+            if !expired {
+                break;
+            }
+            self.evict(&oldest);
+            purged += 1;
+        }
+        if purged > 0 {
+            info!("Rimosse {} voci scadute", purged);
+        }
+        purged
+    }
+
+    /// Indica se la voce è scaduta secondo la politica `TTL`.
+    fn is_expired(&self, entry: &Entry<K, V>) -> bool {
+        Self::expired_under(self.policy, entry)
+    }
+
+    fn expired_under(policy: EvictionPolicy, entry: &Entry<K, V>) -> bool {
+        match policy {
+            EvictionPolicy::TTL { duration } => entry.inserted_at.elapsed() >= duration,
+            _ => false,
+        }
+    }
+
+    /// Registra l'accesso alla chiave dopo aver rimosso le voci scadute; `None` se la chiave è assente.
+    fn touch(&mut self, key: &K) -> Option<()> {
+        self.purge_expired();
+        let entry = self.entries.get_mut(key)?;
+        entry.hits += 1;
+        if let EvictionPolicy::LRU { .. } = self.policy {
+            self.unlink(key);
+            self.push_front(key.clone());
+        }
+        Some(())
+    }
+
+    /// Chiave della voce da rimuovere per fare spazio ad una nuova voce.
+    fn victim(&self) -> Option<K> {
+        match self.policy {
+            EvictionPolicy::LFU { .. } => self.entries
+                .iter()
+                .min_by_key(|(_, entry)| (entry.hits, entry.inserted_at))
+                .map(|(key, _)| key.clone()),
+            _ => self.tail.clone(),
+        }
+    }
+
+    /// Rimuove la voce e invoca la callback di rimozione.
+    fn evict(&mut self, key: &K) {
+        if let Some(value) = self.remove(key) {
+            self.notify_evict(key.clone(), value);
+        }
+    }
+
+    fn notify_evict(&self, key: K, value: V) {
+        if let Some(callback) = &self.on_evict {
+            callback(key, value);
+        }
+    }
+
+    /// Scollega la voce dalla lista, collegando tra loro le voci adiacenti.
+    fn unlink(&mut self, key: &K) {
+        let (prev, next) = match self.entries.get_mut(key) {
+            Some(entry) => (entry.prev.take(), entry.next.take()),
+            None => return,
+        };
+        match &prev {
+            Some(prev_key) => {
+                if let Some(entry) = self.entries.get_mut(prev_key) {
+                    entry.next = next.clone();
+                }
+            }
+            None => self.head = next.clone(),
+        }
+        match &next {
+            Some(next_key) => {
+                if let Some(entry) = self.entries.get_mut(next_key) {
+                    entry.prev = prev;
+                }
+            }
+            None => self.tail = prev,
+        }
+    }
+
+    /// Inserisce la voce (già presente in `entries` e scollegata) in testa alla lista.
+    fn push_front(&mut self, key: K) {
+        let old_head = self.head.replace(key.clone());
+        match &old_head {
+            Some(head_key) => {
+                if let Some(entry) = self.entries.get_mut(head_key) {
+                    entry.prev = Some(key.clone());
+                }
+            }
+            None => self.tail = Some(key.clone()),
+        }
+        if let Some(entry) = self.entries.get_mut(&key) {
+            entry.next = old_head;
+        }
+    }
+}
+
+/// Accesso in sola lettura come `HashMap`, senza registrare l'accesso (vedi `peek`).
+///
+/// # Panics
+/// Se la chiave è assente o scaduta.
+impl<K: Eq + Hash + Clone, V> Index<&K> for EvictingMap<K, V> {
+    type Output = V;
+
+    fn index(&self, key: &K) -> &V {
+        self.peek(key).expect("Chiave assente nell'EvictingMap")
+    }
+}
@@ -0,0 +1,61 @@
+//! Modulo per la protezione dei buffer liberi del pool (`ZeroingPolicy::ProtectAndZeroOnReuse`).
+//!
+//! Alla deallocazione le pagine interamente contenute nel buffer vengono protette con
+//! `mprotect(PROT_NONE)`, così che un accesso ad un buffer già restituito al pool termini il processo
+//! invece di leggere o alterare dati altrui. Non viene installato alcun gestore di `SIGSEGV`:
+//! all'allocazione successiva `unprotect` rende di nuovo accessibili le pagine e azzera subito l'intero
+//! buffer prima che `allocate` lo restituisca, pertanto il chiamante (e il kernel, ad esempio in una `read`)
+//! non vede mai pagine protette e il costo dell'azzeramento è pagato per intero in allocazione.
+//!
+//! # Nota
+//! I buffer protetti devono passare da `unprotect` prima di essere restituiti all'allocatore di sistema
+//! o letti dal `MemoryManager` (istantanee, trasferimenti tra gestori).
+
+use std::sync::OnceLock;
+use log::warn;
+
+/// Dimensione della pagina, letta una sola volta.
+static PAGE_SIZE: OnceLock<usize> = OnceLock::new();
+
+fn page_size() -> usize {
+    // SAFETY: sysconf non ha precondizioni.
+    *PAGE_SIZE.get_or_init(|| unsafe { libc::sysconf(libc::_SC_PAGESIZE) } as usize)
+}
+
+/// Pagine interamente contenute nel buffer: `(inizio, fine)` allineati alla pagina.
+fn interior_pages(buffer: &[u8], page_size: usize) -> (usize, usize) {
+    let start = buffer.as_ptr() as usize;
+    let end = start + buffer.len();
+    let first = start.div_ceil(page_size) * page_size;
+    let last = end - end % page_size;
+    if first >= last { (first, first) } else { (first, last) }
+}
+
+/// Protegge con `PROT_NONE` le pagine interamente contenute nel buffer.
+///
+/// Le porzioni che condividono la pagina con altra memoria restano accessibili. Se la chiamata
+/// di sistema fallisce il buffer resta accessibile e viene comunque azzerato da `unprotect`.
+pub(crate) fn protect(buffer: &mut [u8]) {
+    let page_size = page_size();
+    let (first, last) = interior_pages(buffer, page_size);
+    if first == last {
+        return;
+    }
+    // SAFETY: l'intervallo copre solo pagine interamente contenute nel buffer, di proprietà del pool.
+    if unsafe { libc::mprotect(first as *mut libc::c_void, last - first, libc::PROT_NONE) } != 0 {
+        warn!("Protezione del buffer non disponibile: {}", std::io::Error::last_os_error());
+    }
+}
+
+/// Rende nuovamente leggibili e scrivibili le pagine del buffer e ne azzera l'intero contenuto.
+///
+/// Può essere chiamata anche su buffer mai protetti.
+pub(crate) fn unprotect(buffer: &mut [u8]) {
+    let page_size = page_size();
+    let (first, last) = interior_pages(buffer, page_size);
+    if first != last {
+        // SAFETY: l'intervallo copre solo pagine interamente contenute nel buffer, di proprietà del pool.
+        unsafe { libc::mprotect(first as *mut libc::c_void, last - first, libc::PROT_READ | libc::PROT_WRITE) };
+    }
+    buffer.fill(0);
+}
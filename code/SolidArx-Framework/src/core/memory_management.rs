@@ -5,192 +5,3399 @@
 //! - `Standard`: allocazione standard, utilizza il sistema di allocazione predefinito di Rust.
 //! - `PoolBased`: allocazione basata su un pool di buffer pre-allocati per migliorare le prestazioni.
 //! - `CustomEmbedded`: allocazione personalizzata per applicazioni embedded con requisiti specifici.
+//! - `GrowingPool`: pool che aumenta la propria capacità ad ogni esaurimento, fino a un limite massimo.
 //!
 //! È possibile configurare la dimensione dei buffer e del pool utilizzando la struttura `MemoryConfig`.
 
 use crate::config::{
     global_config::ApplicationType,
-    memory_config::MemoryConfig,
+    memory_config::{MemoryConfig, ZeroingPolicy},
 };
+#[cfg(feature = "embedded")]
+use crate::config::memory_config::EMBEDDED_CAPACITY;
 use crate::core::system_core::CoreError;
-use log::{info};
+use crate::core::buddy_allocator;
+#[cfg(all(target_os = "linux", feature = "lazy-zero"))]
+use crate::core::lazy_zero;
+use log::{info, warn};
+use tokio::sync::{Mutex as AsyncMutex, MutexGuard as AsyncMutexGuard, Semaphore};
+use serde::{Serialize, Deserialize};
 use std::collections::VecDeque;
-use std::io::{self, Write};
+#[cfg(feature = "crud")]
+use std::collections::BinaryHeap;
+#[cfg(feature = "crud")]
+use std::cmp::Ordering as CmpOrdering;
+use std::io::{self, Read, Write};
+use std::fs::File;
+use std::path::Path;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
+use std::cell::RefCell;
+#[cfg(feature = "embedded")]
+use std::cell::UnsafeCell;
+use std::alloc::{self, Layout};
+use std::ops::{Deref, DerefMut};
+use std::ptr::NonNull;
+#[cfg(any(feature = "tracking", feature = "crud"))]
+use std::collections::BTreeMap;
 
 // Importing di lazy static per la creazione di strutture dati globali
 
 
-// struttura globale TASKS_IN_MEMORY che mantiene tutti i Task in memoria
-#[cfg(feature = "crud")]
-use std::sync::Mutex;
-#[cfg(feature = "crud")]
-use lazy_static::lazy_static;
-#[cfg(feature = "crud")]
-use std::collections::HashMap;
-#[cfg(feature = "crud")]
-use crate::crud::models::default::{
-    task::model::Task,
-    device::model::Device,
-    job::model::Job,
-    macro_script::model::Macro,
-    sensor_data::model::SensorData,
-    log_event::model::LogEvent,
-    command::model::Command,
-    configuration::model::Configuration,
-};
-#[cfg(feature = "crud")]
-lazy_static! {
-    pub static ref TASKS_IN_MEMORY: Mutex<HashMap<u32, Task>> = Mutex::new(HashMap::new());
-    pub static ref CONFIGURATIONS_IN_MEMORY: Mutex<HashMap<u32, Configuration>> = Mutex::new(HashMap::new());
-    pub static ref DEVICES_IN_MEMORY: Mutex<HashMap<u32, Device>> = Mutex::new(HashMap::new());
-    pub static ref JOBS_IN_MEMORY: Mutex<HashMap<u32, Job>> = Mutex::new(HashMap::new());
-    pub static ref MACROS_IN_MEMORY: Mutex<HashMap<u32, Macro>> = Mutex::new(HashMap::new());
-    pub static ref SENSOR_DATA_IN_MEMORY: Mutex<HashMap<u32, SensorData>> = Mutex::new(HashMap::new());
-    pub static ref LOG_EVENTS_IN_MEMORY: Mutex<HashMap<u32, LogEvent>> = Mutex::new(HashMap::new());
-    pub static ref COMMANDS_IN_MEMORY: Mutex<HashMap<u32, Command>> = Mutex::new(HashMap::new());
-}
+// struttura globale TASKS_IN_MEMORY che mantiene tutti i Task in memoria
+use std::sync::{Mutex, MutexGuard};
+#[cfg(feature = "crud")]
+use lazy_static::lazy_static;
+use std::collections::{HashMap, HashSet};
+use std::any::{Any, TypeId};
+#[cfg(feature = "crud")]
+use crate::crud::models::default::{
+    task::model::Task,
+    device::model::Device,
+    job::model::Job,
+    macro_script::model::Macro,
+    sensor_data::model::SensorData,
+    log_event::model::LogEvent,
+    command::model::Command,
+    configuration::model::Configuration,
+};
+#[cfg(feature = "crud")]
+use crate::core::evicting_map::EvictingMap;
+#[cfg(feature = "crud")]
+use crate::core::evicting_map::EvictionPolicy;
+#[cfg(feature = "crud")]
+lazy_static! {
+    // Registro predefinito, condiviso dagli store creati con `new()` e dai gestori che non ne ricevono uno proprio
+    pub static ref DEFAULT_REGISTRY: Arc<MemoryRegistry> = Arc::new(MemoryRegistry::new());
+    // Mappe dei modelli CRUD del registro predefinito: senza limiti per impostazione predefinita, la politica
+    // di rimozione si imposta con `EvictingMap::set_policy` (ad esempio `TASKS_IN_MEMORY.lock()?.set_policy(...)`)
+    pub static ref TASKS_IN_MEMORY: &'static Mutex<EvictingMap<u32, Task>> = DEFAULT_REGISTRY.tasks();
+    pub static ref CONFIGURATIONS_IN_MEMORY: &'static Mutex<EvictingMap<u32, Configuration>> = DEFAULT_REGISTRY.configurations();
+    pub static ref DEVICES_IN_MEMORY: &'static Mutex<EvictingMap<u32, Device>> = DEFAULT_REGISTRY.devices();
+    pub static ref JOBS_IN_MEMORY: &'static Mutex<EvictingMap<u32, Job>> = DEFAULT_REGISTRY.jobs();
+    pub static ref MACROS_IN_MEMORY: &'static Mutex<EvictingMap<u32, Macro>> = DEFAULT_REGISTRY.macros();
+    pub static ref SENSOR_DATA_IN_MEMORY: &'static Mutex<EvictingMap<u32, SensorData>> = DEFAULT_REGISTRY.sensor_data();
+    // Indice temporale dei `SensorData` per (timestamp in secondi, ID), aggiornato da ogni scrittura in `SENSOR_DATA_IN_MEMORY`
+    // (vedi `update_sensor_data_index`)
+    pub static ref SENSOR_DATA_TIME_INDEX: &'static Mutex<BTreeMap<(i64, u32), ()>> = DEFAULT_REGISTRY.sensor_data_time_index();
+    // I log sono in sola aggiunta: il buffer circolare sovrascrive gli eventi più vecchi (vedi `LogRingBuffer`)
+    pub static ref LOG_EVENTS_IN_MEMORY: &'static Mutex<LogRingBuffer> = DEFAULT_REGISTRY.log_events();
+    // Coda dei comandi in ordine di priorità (vedi `PrioritizedCommand`)
+    pub static ref COMMANDS_IN_MEMORY: &'static Mutex<BinaryHeap<PrioritizedCommand>> = DEFAULT_REGISTRY.commands();
+    // Task completati rimossi da TASKS_IN_MEMORY tramite archiviazione
+    pub static ref ARCHIVED_TASKS: &'static Mutex<HashMap<u32, Task>> = DEFAULT_REGISTRY.archived_tasks();
+    // Code FIFO dei Command indirizzati ai singoli Device
+    pub static ref DEVICE_COMMAND_QUEUES: &'static Mutex<HashMap<u32, VecDeque<Command>>> = DEFAULT_REGISTRY.device_command_queues();
+}
+
+/// Registro istanziabile delle strutture in memoria dei modelli CRUD.
+///
+/// Le strutture globali (`TASKS_IN_MEMORY`, `DEVICES_IN_MEMORY`, ...) sono le mappe di `DEFAULT_REGISTRY`.
+/// Ogni altra istanza è indipendente: gli store creati con `with_registry` e il `MemoryManager` che
+/// ne riceve una con `use_registry` operano solo sulle proprie mappe, ad esempio per isolare i test.
+///
+/// # Nota
+/// Le operazioni generiche di `crud_ops.rs` e le funzioni libere di questo modulo (`push_command`,
+/// `insert_tasks_bulk`, `query_sensor_data_range`, ...) operano sempre sul registro predefinito.
+#[cfg(feature = "crud")]
+pub struct MemoryRegistry {
+    tasks: Mutex<EvictingMap<u32, Task>>,
+    configurations: Mutex<EvictingMap<u32, Configuration>>,
+    devices: Mutex<EvictingMap<u32, Device>>,
+    jobs: Mutex<EvictingMap<u32, Job>>,
+    macros: Mutex<EvictingMap<u32, Macro>>,
+    sensor_data: Mutex<EvictingMap<u32, SensorData>>,
+    sensor_data_time_index: Mutex<BTreeMap<(i64, u32), ()>>,
+    log_events: Mutex<LogRingBuffer>,
+    commands: Mutex<BinaryHeap<PrioritizedCommand>>,
+    archived_tasks: Mutex<HashMap<u32, Task>>,
+    device_command_queues: Mutex<HashMap<u32, VecDeque<Command>>>,
+}
+
+#[cfg(feature = "crud")]
+impl Default for MemoryRegistry {
+    fn default() -> Self {
+        Self::with_policy(EvictionPolicy::None)
+    }
+}
+
+#[cfg(feature = "crud")]
+impl std::fmt::Debug for MemoryRegistry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MemoryRegistry").finish_non_exhaustive()
+    }
+}
+
+#[cfg(feature = "crud")]
+impl MemoryRegistry {
+    /// Crea un registro con tutte le strutture vuote e senza politica di rimozione.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Crea un registro le cui mappe dei modelli applicano tutte la politica di rimozione indicata.
+    /// Le callback di rimozione si impostano sulle singole mappe con `EvictingMap::set_on_evict`.
+    pub fn with_policy(policy: EvictionPolicy) -> Self {
+        MemoryRegistry {
+            tasks: Mutex::new(EvictingMap::new(policy)),
+            configurations: Mutex::new(EvictingMap::new(policy)),
+            devices: Mutex::new(EvictingMap::new(policy)),
+            jobs: Mutex::new(EvictingMap::new(policy)),
+            macros: Mutex::new(EvictingMap::new(policy)),
+            sensor_data: Mutex::new(EvictingMap::new(policy)),
+            sensor_data_time_index: Mutex::new(BTreeMap::new()),
+            log_events: Mutex::new(LogRingBuffer::new(DEFAULT_LOG_CAPACITY)),
+            commands: Mutex::new(BinaryHeap::new()),
+            archived_tasks: Mutex::new(HashMap::new()),
+            device_command_queues: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Registro predefinito `DEFAULT_REGISTRY`, le cui mappe sono le strutture globali.
+    pub fn global() -> Arc<MemoryRegistry> {
+        Arc::clone(&DEFAULT_REGISTRY)
+    }
+
+    /// Mappa dei `Task`.
+    pub fn tasks(&self) -> &Mutex<EvictingMap<u32, Task>> {
+        &self.tasks
+    }
+
+    /// Mappa delle `Configuration`.
+    pub fn configurations(&self) -> &Mutex<EvictingMap<u32, Configuration>> {
+        &self.configurations
+    }
+
+    /// Mappa dei `Device`.
+    pub fn devices(&self) -> &Mutex<EvictingMap<u32, Device>> {
+        &self.devices
+    }
+
+    /// Mappa dei `Job`.
+    pub fn jobs(&self) -> &Mutex<EvictingMap<u32, Job>> {
+        &self.jobs
+    }
+
+    /// Mappa delle `Macro`.
+    pub fn macros(&self) -> &Mutex<EvictingMap<u32, Macro>> {
+        &self.macros
+    }
+
+    /// Mappa dei `SensorData`.
+    pub fn sensor_data(&self) -> &Mutex<EvictingMap<u32, SensorData>> {
+        &self.sensor_data
+    }
+
+    /// Indice temporale dei `SensorData`, da acquisire sempre dopo `sensor_data`.
+    pub fn sensor_data_time_index(&self) -> &Mutex<BTreeMap<(i64, u32), ()>> {
+        &self.sensor_data_time_index
+    }
+
+    /// Buffer circolare dei `LogEvent`.
+    pub fn log_events(&self) -> &Mutex<LogRingBuffer> {
+        &self.log_events
+    }
+
+    /// Coda dei `Command` in ordine di priorità.
+    pub fn commands(&self) -> &Mutex<BinaryHeap<PrioritizedCommand>> {
+        &self.commands
+    }
+
+    /// Task archiviati, da acquisire sempre dopo `tasks`.
+    pub fn archived_tasks(&self) -> &Mutex<HashMap<u32, Task>> {
+        &self.archived_tasks
+    }
+
+    /// Code FIFO dei `Command` indirizzati ai singoli `Device`.
+    pub fn device_command_queues(&self) -> &Mutex<HashMap<u32, VecDeque<Command>>> {
+        &self.device_command_queues
+    }
+}
+
+// Gestore della memoria condiviso dalle operazioni CRUD eseguite da più task
+#[cfg(all(feature = "crud", feature = "async"))]
+lazy_static! {
+    pub static ref CRUD_MEMORY: SharedMemoryManager = SharedMemoryManager::new(
+        MemoryManager::with_strategy(AllocationStrategy::Standard, MemoryConfig::default())
+            .expect("La strategia Standard non richiede parametri da validare")
+    );
+}
+
+/// Numero progressivo di inserimento dei comandi in `COMMANDS_IN_MEMORY`.
+#[cfg(feature = "crud")]
+static NEXT_COMMAND_SEQUENCE: AtomicU64 = AtomicU64::new(0);
+
+/// `Command` in attesa in `COMMANDS_IN_MEMORY`.
+///
+/// L'ordinamento segue la `CommandPriority` del comando e, a parità di priorità, l'ordine di
+/// inserimento: il massimo del `BinaryHeap` è quindi il comando più prioritario inserito per primo.
+#[cfg(feature = "crud")]
+#[derive(Debug, Clone)]
+pub struct PrioritizedCommand {
+    pub command: Command,
+    sequence: u64,
+}
+
+#[cfg(feature = "crud")]
+impl PrioritizedCommand {
+    /// Incapsula il comando assegnandogli il numero di inserimento successivo.
+    pub fn new(command: Command) -> Self {
+        PrioritizedCommand { command, sequence: NEXT_COMMAND_SEQUENCE.fetch_add(1, Ordering::Relaxed) }
+    }
+}
+
+#[cfg(feature = "crud")]
+impl PartialEq for PrioritizedCommand {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == CmpOrdering::Equal
+    }
+}
+
+#[cfg(feature = "crud")]
+impl Eq for PrioritizedCommand {}
+
+#[cfg(feature = "crud")]
+impl PartialOrd for PrioritizedCommand {
+    fn partial_cmp(&self, other: &Self) -> Option<CmpOrdering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[cfg(feature = "crud")]
+impl Ord for PrioritizedCommand {
+    fn cmp(&self, other: &Self) -> CmpOrdering {
+        self.command.priority.rank()
+            .cmp(&other.command.priority.rank())
+            // Il comando inserito prima ha la precedenza
+            .then_with(|| other.sequence.cmp(&self.sequence))
+    }
+}
+
+/// Aggiunge un comando a `COMMANDS_IN_MEMORY`.
+///
+/// # Ritorna
+/// `CoreError::GenericError` se il lock su `COMMANDS_IN_MEMORY` non è disponibile.
+#[cfg(feature = "crud")]
+pub fn enqueue_command(cmd: Command) -> Result<(), CoreError> {
+    let mut queue = COMMANDS_IN_MEMORY.lock()
+        .map_err(|e| CoreError::GenericError(format!("Errore di lock sul mutex: {}", e), Vec::new()).with_context("enqueue_command"))?;
+    queue.push(PrioritizedCommand::new(cmd));
+    Ok(())
+}
+
+/// Estrae da `COMMANDS_IN_MEMORY` il comando con la priorità più alta, inserito per primo a parità di priorità.
+///
+/// Se la coda è vuota o il lock non è disponibile restituisce `None`.
+#[cfg(feature = "crud")]
+pub fn dequeue_command() -> Option<Command> {
+    match COMMANDS_IN_MEMORY.lock() {
+        Ok(mut queue) => queue.pop().map(|entry| entry.command),
+        Err(e) => {
+            warn!("Estrazione del comando fallita, lock non disponibile: {}", e);
+            None
+        }
+    }
+}
+
+/// Trasferisce in `COMMANDS_IN_MEMORY` i comandi di una mappa indicizzata per ID, come quella usata
+/// prima della coda con priorità o `MemoryStores::commands`.
+///
+/// Da invocare all'avvio: i comandi vengono inseriti in ordine di ID, che diventa l'ordine di
+/// estrazione a parità di priorità.
+///
+/// # Ritorna
+/// - `Ok(usize)`: Il numero di comandi trasferiti.
+/// - `Err(CoreError::GenericError)`: Se il lock su `COMMANDS_IN_MEMORY` non è disponibile.
+#[cfg(feature = "crud")]
+pub fn migrate_commands(commands: HashMap<u32, Command>) -> Result<usize, CoreError> {
+    let mut commands: Vec<Command> = commands.into_values().collect();
+    commands.sort_by_key(|command| command.id);
+
+    let mut queue = COMMANDS_IN_MEMORY.lock()
+        .map_err(|e| CoreError::GenericError(format!("Errore di lock sul mutex: {}", e), Vec::new()).with_context("migrate_commands"))?;
+    let migrated = commands.len();
+    queue.extend(commands.into_iter().map(PrioritizedCommand::new));
+    info!("Trasferiti {} comandi nella coda con priorità", migrated);
+    Ok(migrated)
+}
+
+/// Capacità predefinita di `LOG_EVENTS_IN_MEMORY`.
+#[cfg(feature = "crud")]
+pub const DEFAULT_LOG_CAPACITY: usize = 1024;
+
+/// Buffer circolare in sola aggiunta dei `LogEvent`, in ordine di inserimento.
+///
+/// Raggiunta la `capacity`, ogni nuovo evento sovrascrive il più vecchio. Ad ogni evento viene
+/// assegnata una chiave `u32` da un contatore monotono (scritta anche in `LogEvent::id`), che
+/// sostituisce l'ID usato come chiave dalla precedente `HashMap`.
+///
+/// # Nota
+/// Il contatore riparte da zero dopo `u32::MAX` eventi.
+#[cfg(feature = "crud")]
+#[derive(Debug)]
+pub struct LogRingBuffer {
+    events: VecDeque<LogEvent>,
+    capacity: usize,
+    next_key: u32,
+}
+
+#[cfg(feature = "crud")]
+impl LogRingBuffer {
+    /// Crea un buffer vuoto con la capacità indicata. Con capacità zero gli eventi vengono scartati.
+    pub fn new(capacity: usize) -> Self {
+        LogRingBuffer { events: VecDeque::with_capacity(capacity), capacity, next_key: 0 }
+    }
+
+    /// Capacità del buffer.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Modifica la capacità del buffer, rimuovendo gli eventi più vecchi in eccesso.
+    pub fn set_capacity(&mut self, capacity: usize) {
+        self.capacity = capacity;
+        while self.events.len() > capacity {
+            self.events.pop_front();
+        }
+    }
+
+    /// Numero di eventi nel buffer.
+    pub fn len(&self) -> usize {
+        self.events.len()
+    }
+
+    /// Indica se il buffer è vuoto.
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+
+    /// Aggiunge un evento, sovrascrivendo il più vecchio se il buffer è pieno.
+    ///
+    /// # Ritorna
+    /// La chiave assegnata all'evento.
+    pub fn push(&mut self, mut event: LogEvent) -> u32 {
+        let key = self.next_key;
+        self.next_key = self.next_key.wrapping_add(1);
+        event.id = key;
+        if self.capacity == 0 {
+            return key;
+        }
+        if self.events.len() == self.capacity {
+            self.events.pop_front();
+        }
+        self.events.push_back(event);
+        key
+    }
+
+    /// Restituisce l'evento con la chiave indicata.
+    pub fn get(&self, key: u32) -> Option<&LogEvent> {
+        self.events.iter().find(|event| event.id == key)
+    }
+
+    /// Rimuove l'evento con la chiave indicata.
+    pub fn remove(&mut self, key: u32) -> Option<LogEvent> {
+        let position = self.events.iter().position(|event| event.id == key)?;
+        self.events.remove(position)
+    }
+
+    /// Chiavi degli eventi, dal più vecchio al più recente.
+    pub fn keys(&self) -> impl Iterator<Item = &u32> {
+        self.events.iter().map(|event| &event.id)
+    }
+
+    /// Itera sugli eventi dal più vecchio al più recente.
+    pub fn iter(&self) -> impl Iterator<Item = &LogEvent> {
+        self.events.iter()
+    }
+
+    /// Restituisce una copia degli ultimi `n` eventi, dal più vecchio al più recente.
+    pub fn peek_last(&self, n: usize) -> Vec<LogEvent> {
+        let skip = self.events.len().saturating_sub(n);
+        self.events.iter().skip(skip).cloned().collect()
+    }
+
+    /// Rimuove tutti gli eventi restituendoli dal più vecchio al più recente.
+    ///
+    /// Il buffer viene svuotato anche se l'iteratore non viene consumato per intero.
+    pub fn drain(&mut self) -> std::collections::vec_deque::Drain<'_, LogEvent> {
+        self.events.drain(..)
+    }
+}
+
+/// Aggiunge un evento a `LOG_EVENTS_IN_MEMORY`, sovrascrivendo il più vecchio se il buffer è pieno.
+///
+/// # Nota
+/// L'ID dell'evento viene sostituito dalla chiave assegnata dal buffer (vedi `LogRingBuffer`).
+///
+/// # Ritorna
+/// `CoreError::GenericError` se il lock su `LOG_EVENTS_IN_MEMORY` non è disponibile.
+#[cfg(feature = "crud")]
+pub fn append_log(event: LogEvent) -> Result<(), CoreError> {
+    let mut events = LOG_EVENTS_IN_MEMORY.lock()
+        .map_err(|e| CoreError::GenericError(format!("Errore di lock sul mutex: {}", e), Vec::new()).with_context("append_log"))?;
+    events.push(event);
+    Ok(())
+}
+
+/// Rimuove e restituisce tutti gli eventi di `LOG_EVENTS_IN_MEMORY`, dal più vecchio al più recente.
+///
+/// Se il lock non è disponibile il vettore restituito è vuoto.
+#[cfg(feature = "crud")]
+pub fn drain_logs() -> Vec<LogEvent> {
+    match LOG_EVENTS_IN_MEMORY.lock() {
+        Ok(mut events) => events.drain().collect(),
+        Err(e) => {
+            warn!("Svuotamento dei log fallito, lock non disponibile: {}", e);
+            Vec::new()
+        }
+    }
+}
+
+/// Restituisce una copia degli ultimi `n` eventi di `LOG_EVENTS_IN_MEMORY` senza rimuoverli,
+/// dal più vecchio al più recente.
+///
+/// Se il lock non è disponibile il vettore restituito è vuoto.
+#[cfg(feature = "crud")]
+pub fn peek_logs(n: usize) -> Vec<LogEvent> {
+    match LOG_EVENTS_IN_MEMORY.lock() {
+        Ok(events) => events.peek_last(n),
+        Err(e) => {
+            warn!("Lettura dei log fallita, lock non disponibile: {}", e);
+            Vec::new()
+        }
+    }
+}
+
+/// Comportamento di `insert_tasks_bulk` per i task con un ID già presente in `TASKS_IN_MEMORY`
+/// o ripetuto all'interno dello stesso lotto.
+///
+/// - `Skip`: Il task esistente (o il primo del lotto) viene mantenuto e il nuovo scartato.
+/// - `Overwrite`: Il nuovo task sostituisce quello esistente.
+/// - `Error`: L'intero lotto viene rifiutato senza inserire alcun task.
+#[cfg(feature = "crud")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConflictResolution {
+    Skip,
+    Overwrite,
+    Error,
+}
+
+/// Inserisce un lotto di task in `TASKS_IN_MEMORY` acquisendo il lock una sola volta.
+///
+/// # Parametri
+/// - `tasks`: I task da inserire, nell'ordine in cui vengono applicati.
+/// - `on_conflict`: Il comportamento per gli ID già presenti o ripetuti nel lotto.
+///
+/// # Ritorna
+/// - `Ok(usize)`: Il numero di task inseriti (sovrascritture incluse, task scartati esclusi).
+/// - `Err(CoreError::InvalidOperation)`: Con `ConflictResolution::Error`, se un ID è in conflitto.
+/// - `Err(CoreError::GenericError)`: Se il lock su `TASKS_IN_MEMORY` non è disponibile.
+#[cfg(feature = "crud")]
+pub fn insert_tasks_bulk(tasks: Vec<Task>, on_conflict: ConflictResolution) -> Result<usize, CoreError> {
+    let mut map = TASKS_IN_MEMORY.lock()
+        .map_err(|e| CoreError::GenericError(format!("Errore di lock sul mutex: {}", e), Vec::new()).with_context("insert_tasks_bulk"))?;
+
+    if on_conflict == ConflictResolution::Error {
+        // Il lotto viene verificato per intero prima di qualsiasi inserimento
+        let mut seen = std::collections::HashSet::with_capacity(tasks.len());
+        if let Some(task) = tasks.iter().find(|task| map.contains_key(&task.id) || !seen.insert(task.id)) {
+            return Err(CoreError::InvalidOperation(format!("Task con ID {} già presente", task.id), Vec::new()));
+        }
+    }
+
+    let mut inserted = 0;
+    for task in tasks {
+        if on_conflict == ConflictResolution::Skip && map.contains_key(&task.id) {
+            continue;
+        }
+        map.insert(task.id, task);
+        inserted += 1;
+    }
+    info!("Inseriti {} task in memoria", inserted);
+    Ok(inserted)
+}
+
+/// Esito di `upsert_devices`.
+/// - `inserted`: Dispositivi con un ID non ancora presente.
+/// - `updated`: Dispositivi che hanno sostituito un dispositivo diverso con lo stesso ID.
+/// - `unchanged`: Dispositivi identici a quello già presente, non riscritti.
+#[cfg(feature = "crud")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct UpsertReport {
+    pub inserted: usize,
+    pub updated: usize,
+    pub unchanged: usize,
+}
+
+/// Inserisce o aggiorna un lotto di dispositivi in `DEVICES_IN_MEMORY` acquisendo il lock una sola volta.
+///
+/// Ogni dispositivo viene confrontato con quello già presente tramite `Eq`: se sono identici
+/// la scrittura viene saltata. Gli ID ripetuti nel lotto vengono applicati in ordine.
+///
+/// # Ritorna
+/// - `Ok(UpsertReport)`: Il numero di dispositivi inseriti, aggiornati e invariati.
+/// - `Err(CoreError::GenericError)`: Se il lock su `DEVICES_IN_MEMORY` non è disponibile.
+#[cfg(feature = "crud")]
+pub fn upsert_devices(devices: Vec<Device>) -> Result<UpsertReport, CoreError> {
+    let mut map = DEVICES_IN_MEMORY.lock()
+        .map_err(|e| CoreError::GenericError(format!("Errore di lock sul mutex: {}", e), Vec::new()).with_context("upsert_devices"))?;
+
+    let mut report = UpsertReport::default();
+    for device in devices {
+        match map.peek(&device.id) {
+            Some(existing) if *existing == device => report.unchanged += 1,
+            Some(_) => {
+                map.insert(device.id, device);
+                report.updated += 1;
+            }
+            None => {
+                map.insert(device.id, device);
+                report.inserted += 1;
+            }
+        }
+    }
+    info!(
+        "Dispositivi in memoria: {} inseriti, {} aggiornati, {} invariati",
+        report.inserted, report.updated, report.unchanged
+    );
+    Ok(report)
+}
+
+/// Converte il timestamp di un `SensorData` in secondi dall'epoca Unix.
+///
+/// Sono accettati il formato ISO 8601 `%Y-%m-%dT%H:%M:%S` (interpretato come UTC) e i secondi interi.
+#[cfg(feature = "crud")]
+fn sensor_timestamp(timestamp: &str) -> Option<i64> {
+    let timestamp = timestamp.trim();
+    chrono::NaiveDateTime::parse_from_str(timestamp, "%Y-%m-%dT%H:%M:%S")
+        .map(|datetime| datetime.and_utc().timestamp())
+        .ok()
+        .or_else(|| timestamp.parse().ok())
+}
+
+/// Aggiorna `SENSOR_DATA_TIME_INDEX` dopo la scrittura di `data` in `SENSOR_DATA_IN_MEMORY`.
+///
+/// Rimuove la voce della lettura sostituita (`previous`) e aggiunge quella di `data`, se il suo
+/// timestamp è in uno dei formati accettati da `sensor_timestamp`. Il chiamante deve mantenere
+/// entrambi i lock, acquisiti nell'ordine mappa, indice.
+#[cfg(feature = "crud")]
+pub(crate) fn update_sensor_data_index(index: &mut BTreeMap<(i64, u32), ()>, previous: Option<&SensorData>, data: &SensorData) {
+    if let Some(timestamp) = previous.and_then(|previous| sensor_timestamp(&previous.timestamp)) {
+        index.remove(&(timestamp, data.id));
+    }
+    if let Some(timestamp) = sensor_timestamp(&data.timestamp) {
+        index.insert((timestamp, data.id), ());
+    }
+}
+
+/// Inserisce un `SensorData` in `SENSOR_DATA_IN_MEMORY` aggiornando `SENSOR_DATA_TIME_INDEX`.
+///
+/// Se una lettura con lo stesso ID è già presente viene sostituita e la sua voce nell'indice rimossa.
+/// A differenza di `SensorDataStore::insert`, rifiuta le letture con un timestamp non valido.
+/// I lock vengono acquisiti nell'ordine mappa, indice, lo stesso di `query_sensor_data_range`.
+///
+/// # Ritorna
+/// - `Ok(())`: Se la lettura è stata inserita.
+/// - `Err(CoreError::InvalidOperation)`: Se il timestamp non è nei formati accettati da `sensor_timestamp`.
+/// - `Err(CoreError::GenericError)`: Se uno dei lock non è disponibile.
+#[cfg(feature = "crud")]
+pub fn insert_sensor_data_indexed(data: SensorData) -> Result<(), CoreError> {
+    sensor_timestamp(&data.timestamp).ok_or_else(|| CoreError::InvalidOperation(
+        format!("Timestamp non valido per la lettura {}: {:?}", data.id, data.timestamp),
+        Vec::new(),
+    ).with_context("insert_sensor_data_indexed"))?;
+    let lock_error = |e: String| CoreError::GenericError(format!("Errore di lock sul mutex: {}", e), Vec::new()).with_context("insert_sensor_data_indexed");
+    let mut readings = SENSOR_DATA_IN_MEMORY.lock().map_err(|e| lock_error(e.to_string()))?;
+    let mut index = SENSOR_DATA_TIME_INDEX.lock().map_err(|e| lock_error(e.to_string()))?;
+
+    let previous = readings.insert(data.id, data.clone());
+    update_sensor_data_index(&mut index, previous.as_ref(), &data);
+    Ok(())
+}
+
+/// Restituisce una copia delle letture con timestamp compreso nell'intervallo `[start, end]` (in secondi).
+///
+/// Le letture sono ordinate per timestamp crescente e, a parità di timestamp, per ID crescente.
+/// Le voci dell'indice la cui lettura non è più in `SENSOR_DATA_IN_MEMORY` (ad esempio perché rimossa
+/// dalla politica della mappa) o ha cambiato timestamp vengono ignorate e rimosse dall'indice.
+/// Se `start > end` o uno dei lock non è disponibile il vettore restituito è vuoto.
+#[cfg(feature = "crud")]
+pub fn query_sensor_data_range(start: i64, end: i64) -> Vec<SensorData> {
+    if start > end {
+        return Vec::new();
+    }
+    let (mut readings, mut index) = match (SENSOR_DATA_IN_MEMORY.lock(), SENSOR_DATA_TIME_INDEX.lock()) {
+        (Ok(readings), Ok(index)) => (readings, index),
+        _ => {
+            warn!("Lettura dell'indice temporale fallita, lock non disponibile");
+            return Vec::new();
+        }
+    };
+
+    let mut found = Vec::new();
+    let mut stale = Vec::new();
+    for &(timestamp, id) in index.range((start, u32::MIN)..=(end, u32::MAX)).map(|(key, _)| key) {
+        match readings.get(&id) {
+            Some(reading) if sensor_timestamp(&reading.timestamp) == Some(timestamp) => found.push(reading.clone()),
+            _ => stale.push((timestamp, id)),
+        }
+    }
+    for key in stale.iter() {
+        index.remove(key);
+    }
+    found
+}
+
+/// Restituisce una copia dei task di `TASKS_IN_MEMORY` con gli ID indicati, acquisendo il lock una sola volta.
+///
+/// Gli ID non presenti vengono ignorati; se il lock non è disponibile la mappa restituita è vuota.
+#[cfg(feature = "crud")]
+pub fn get_tasks_bulk(ids: &[u32]) -> HashMap<u32, Task> {
+    match TASKS_IN_MEMORY.lock() {
+        Ok(mut map) => ids.iter().filter_map(|id| map.get(id).map(|task| (*id, task.clone()))).collect(),
+        Err(e) => {
+            warn!("Lettura dei task fallita, lock non disponibile: {}", e);
+            HashMap::new()
+        }
+    }
+}
+
+
+/// Enum per rappresentare le diverse strategie di allocazione della memoria.
+///
+/// `GrowingPool` si comporta come `PoolBased` ma parte da `initial_size` buffer e, ad ogni
+/// esaurimento, moltiplica la capacità del pool per `growth_factor` fino a `max_size` buffer.
+///
+/// `DoubleBuffered` mantiene due pool con lo stesso numero di buffer: le allocazioni usano il pool
+/// attivo mentre l'altro viene preparato per il frame successivo, e `MemoryManager::swap_buffers`
+/// li scambia ad ogni frame.
+///
+/// `HugePage` alloca con `MemoryManager::allocate_huge_page` su pagine da `page_size` byte
+/// (`mmap` con `MAP_HUGETLB` su Linux); `allocate` restituisce buffer dell'allocatore
+/// globale e si comporta quindi come `Standard`.
+///
+/// Con la feature `serde` viene serializzata con il nome della variante nel campo `type`,
+/// ad esempio `{"type":"GrowingPool","initial_size":4,"max_size":16,"growth_factor":2.0}`.
+#[derive(Debug,Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(tag = "type"))]
+pub enum AllocationStrategy {
+    Standard,
+    PoolBased,
+    CustomEmbedded,
+    GrowingPool { initial_size: usize, max_size: usize, growth_factor: f32 },
+    DoubleBuffered,
+    HugePage { page_size: usize },
+}
+
+/// Dimensione predefinita delle pagine huge su Linux (x86_64 e aarch64).
+pub const HUGE_PAGE_SIZE: usize = 2 * 1024 * 1024;
+
+impl AllocationStrategy {
+    /// Indica se la strategia utilizza il pool di buffer pre-allocati (`PoolBased`, `GrowingPool` o `DoubleBuffered`).
+    pub fn is_pool_based(&self) -> bool {
+        matches!(self, AllocationStrategy::PoolBased | AllocationStrategy::GrowingPool { .. } | AllocationStrategy::DoubleBuffered)
+    }
+}
+
+/// Pesi delle componenti di `MemoryManager::health_score`.
+const HEALTH_WEIGHT_POOL_FREE: f64 = 0.5;
+const HEALTH_WEIGHT_FALLBACK: f64 = 0.3;
+const HEALTH_WEIGHT_FRAGMENTATION: f64 = 0.2;
+
+/// Punteggio sotto il quale `MemoryManager::health_score` segnala la memoria come in sofferenza.
+const HEALTH_WARNING_THRESHOLD: f64 = 0.5;
+
+/// Contatori condivisi dello stato del `MemoryManager`.
+///
+/// Sono mantenuti in un `Arc` con campi atomici così da poter essere letti da altri thread
+/// (ad esempio dalla dashboard HTTP) senza bloccare il gestore della memoria.
+#[derive(Debug, Default)]
+pub(crate) struct PoolCounters {
+    pub(crate) free_buffers: AtomicUsize,
+    pub(crate) pool_capacity: AtomicUsize,
+    pub(crate) total_allocations: AtomicU64,
+    pub(crate) total_deallocations: AtomicU64,
+    pub(crate) dynamic_fallbacks: AtomicU64,  // Allocazioni dinamiche effettuate a pool esaurito
+    pub(crate) peak_usage: AtomicUsize,  // Massimo numero di buffer del pool in uso contemporaneamente
+}
+
+/// Istantanea delle statistiche del `MemoryManager`.
+/// - `strategy`: Strategia di allocazione di default.
+/// - `buffer_size`: Dimensione in byte di ciascun buffer.
+/// - `pool_capacity`: Numero totale di buffer del pool.
+/// - `free_buffers`: Numero di buffer attualmente disponibili nel pool.
+/// - `total_allocations`: Numero di allocazioni effettuate.
+/// - `total_deallocations`: Numero di deallocazioni effettuate.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MemoryStats {
+    pub strategy: String,
+    pub buffer_size: usize,
+    pub pool_capacity: usize,
+    pub free_buffers: usize,
+    pub total_allocations: u64,
+    pub total_deallocations: u64,
+}
+
+impl MemoryStats {
+    /// Costruisce l'istantanea a partire dai contatori condivisi.
+    pub(crate) fn from_counters(strategy: &AllocationStrategy, buffer_size: usize, counters: &PoolCounters) -> Self {
+        MemoryStats {
+            strategy: format!("{:?}", strategy),
+            buffer_size,
+            pool_capacity: counters.pool_capacity.load(Ordering::Relaxed),
+            free_buffers: counters.free_buffers.load(Ordering::Relaxed),
+            total_allocations: counters.total_allocations.load(Ordering::Relaxed),
+            total_deallocations: counters.total_deallocations.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// Metriche di utilizzo del pool restituite da `MemoryManager::stats`, utili per la pianificazione della capacità.
+/// - `total_buffers`: Numero totale di buffer del pool.
+/// - `available_buffers`: Numero di buffer attualmente liberi nel pool.
+/// - `dynamic_fallback_count`: Allocazioni dinamiche effettuate a pool esaurito.
+/// - `peak_usage`: Massimo numero di buffer del pool in uso contemporaneamente.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct PoolStats {
+    pub total_buffers: usize,
+    pub available_buffers: usize,
+    pub dynamic_fallback_count: u64,
+    pub peak_usage: usize,
+}
+
+/// Stato del pool prodotto da `MemoryManager::snapshot` e ripristinato da `MemoryManager::restore_from_snapshot`,
+/// ad esempio per salvarlo su flash prima di un riavvio a caldo nei sistemi embedded.
+/// - `strategy`: La strategia di allocazione del gestore.
+/// - `buffers`: Il contenuto dei buffer liberi del pool, nell'ordine del pool (vuoto per le strategie senza pool).
+/// - `config`: La configurazione della memoria del gestore.
+///
+/// # Nota
+/// I buffer allocati e non ancora restituiti al pool non fanno parte dell'istantanea: il gestore
+/// ripristinato ha un pool formato dai soli buffer liberi al momento di `snapshot`.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MemorySnapshot {
+    pub strategy: AllocationStrategy,
+    pub buffers: Vec<Vec<u8>>,
+    pub config: MemoryConfig,
+}
+
+/// Callback invocata da `MemoryManager` quando l'utilizzo del pool supera `MemoryConfig::pressure_threshold`
+/// (vedi `MemoryManager::set_pressure_callback`).
+pub type MemoryPressureCallback = Box<dyn Fn(PoolStats) + Send + Sync>;
+
+/// Con la feature `jemalloc` l'intero processo utilizza jemalloc al posto dell'allocatore di sistema,
+/// inclusi i buffer del pool e le allocazioni dinamiche del `MemoryManager`.
+#[cfg(feature = "jemalloc")]
+#[global_allocator]
+static ALLOC: tikv_jemallocator::Jemalloc = tikv_jemallocator::Jemalloc;
+
+/// Statistiche dell'allocatore jemalloc restituite da `MemoryManager::jemalloc_stats`.
+/// - `active_bytes`: Byte nelle pagine attive, multiplo della dimensione della pagina.
+/// - `allocated_bytes`: Byte allocati dall'applicazione.
+/// - `metadata_bytes`: Byte dedicati ai metadati interni di jemalloc.
+#[cfg(feature = "jemalloc")]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct JemallocStats {
+    pub active_bytes: usize,
+    pub allocated_bytes: usize,
+    pub metadata_bytes: usize,
+}
+
+// Pila dei contesti di allocazione del thread corrente (vedi `MemoryManager::push_context`)
+thread_local! {
+    static ALLOCATION_CONTEXT: RefCell<Vec<String>> = RefCell::new(Vec::new());
+}
+
+/// Separatore dei contesti nel percorso restituito da `MemoryManager::current_context`.
+const CONTEXT_SEPARATOR: &str = " > ";
+
+/// Allocazione registrata dal `MemoryManager` con la feature `tracking`.
+/// - `size`: Dimensione del buffer allocato in byte.
+/// - `context`: Percorso dei contesti attivi al momento dell'allocazione (ad esempio `"request > db_fetch"`).
+#[cfg(feature = "tracking")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct AllocationRecord {
+    pub size: usize,
+    pub context: String,
+}
+
+/// Evento registrato su un singolo buffer, identificato dal suo indirizzo.
+#[cfg(feature = "tracking")]
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum BufferEvent {
+    Allocate(usize),
+    Deallocate(usize),
+}
+
+/// Intestazione dei file prodotti da `MemoryManager::freeze`.
+const FREEZE_MAGIC: &[u8; 4] = b"ARXP";
+
+/// Dimensione minima del primo bucket dell'`AllocationHistogram`.
+#[cfg(feature = "tracking")]
+const HISTOGRAM_MIN_BUCKET: usize = 64;
+
+/// Distribuzione delle dimensioni delle allocazioni effettuate dal `MemoryManager`.
+///
+/// Ogni bucket è una coppia `(upper_bound_bytes, count)`: contiene le allocazioni
+/// di dimensione compresa tra il limite del bucket precedente (escluso) e `upper_bound_bytes` (incluso).
+/// I limiti sono potenze di due a partire da 64 byte.
+#[cfg(feature = "tracking")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct AllocationHistogram {
+    pub buckets: Vec<(usize, u64)>,
+}
+
+#[cfg(feature = "tracking")]
+impl AllocationHistogram {
+    /// Calcola l'istogramma a partire dalle dimensioni delle allocazioni.
+    pub fn from_sizes(sizes: &[usize]) -> Self {
+        let mut counts: BTreeMap<usize, u64> = BTreeMap::new();
+        for size in sizes {
+            let upper_bound = size.max(&HISTOGRAM_MIN_BUCKET).checked_next_power_of_two().unwrap_or(usize::MAX);
+            *counts.entry(upper_bound).or_insert(0) += 1;
+        }
+        AllocationHistogram { buckets: counts.into_iter().collect() }
+    }
+
+    /// Restituisce il conteggio del bucket con il limite superiore indicato.
+    pub fn count_for(&self, upper_bound: usize) -> u64 {
+        self.buckets.iter().find(|(bound, _)| *bound == upper_bound).map_or(0, |(_, count)| *count)
+    }
+}
+
+/// Numero massimo di regioni tracciate contemporaneamente dall'`EmbeddedPool`.
+#[cfg(feature = "embedded")]
+const EMBEDDED_MAX_REGIONS: usize = 64;
+
+/// Regione di memoria assegnata dall'`EmbeddedPool`.
+/// - `offset`: Posizione di inizio della regione nel buffer statico.
+/// - `len`: Dimensione della regione in byte.
+#[cfg(feature = "embedded")]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EmbeddedRegion {
+    pub offset: usize,
+    pub len: usize,
+    free: bool,
+}
+
+/// Regioni dell'`EmbeddedPool`, modificate sotto il suo lock.
+/// - `used`: Byte del buffer statico assegnati almeno una volta, a partire dall'inizio.
+/// - `regions`: Regioni assegnate o liberate entro `used`.
+#[cfg(feature = "embedded")]
+struct EmbeddedRegions {
+    used: usize,
+    regions: heapless::Vec<EmbeddedRegion, EMBEDDED_MAX_REGIONS>,
+}
+
+/// Pool a dimensione fissa per la strategia `CustomEmbedded` che non utilizza l'heap.
+///
+/// Il buffer è un array di `N` byte dimensionato in compile time, pertanto risiede interamente
+/// nella memoria della struttura che lo contiene (stack o statico). Le regioni liberate vengono
+/// riutilizzate con una politica first-fit e fuse con le regioni libere adiacenti.
+///
+/// # Nota
+/// Il `MemoryManager` serve la strategia `CustomEmbedded` da un unico pool statico condiviso
+/// da tutti i gestori, così che il buffer non venga replicato in ciascuno di essi.
+#[cfg(feature = "embedded")]
+pub struct EmbeddedPool<const N: usize> {
+    buffer: UnsafeCell<[u8; N]>,
+    state: Mutex<EmbeddedRegions>,
+}
+
+// SAFETY: il buffer viene letto e scritto solo nelle regioni assegnate da `reserve`, disgiunte
+// tra loro, e le regioni vengono modificate sotto il lock `state`.
+#[cfg(feature = "embedded")]
+unsafe impl<const N: usize> Sync for EmbeddedPool<N> {}
+
+#[cfg(feature = "embedded")]
+impl<const N: usize> Default for EmbeddedPool<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "embedded")]
+impl<const N: usize> EmbeddedPool<N> {
+    /// Crea un pool vuoto con capacità `N` byte.
+    pub const fn new() -> Self {
+        EmbeddedPool {
+            buffer: UnsafeCell::new([0; N]),
+            state: Mutex::new(EmbeddedRegions { used: 0, regions: heapless::Vec::new() }),
+        }
+    }
+
+    /// Capacità totale del pool in byte, fissata in compile time.
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Assegna una regione di `size` byte all'interno del buffer statico.
+    ///
+    /// # Ritorna
+    /// La regione assegnata e lo slice corrispondente, oppure `CoreError::ResourceAllocationError`
+    /// se la capacità del pool o il numero massimo di regioni è esaurito.
+    pub fn allocate(&mut self, size: usize) -> Result<(EmbeddedRegion, &mut [u8]), CoreError> {
+        let (region, ptr) = self.reserve(size)?;
+        // SAFETY: la regione è valida per `region.len` byte e non è assegnata ad altri; `&mut self`
+        // impedisce di liberarla con `deallocate` finché lo slice è in uso.
+        Ok((region, unsafe { std::slice::from_raw_parts_mut(ptr.as_ptr(), region.len) }))
+    }
+
+    /// Numero totale di byte liberi, incluse le regioni liberate e lo spazio mai assegnato.
+    pub fn total_free_bytes(&self) -> usize {
+        match self.state.lock() {
+            Ok(state) => state.regions.iter().filter(|r| r.free).map(|r| r.len).sum::<usize>() + (N - state.used),
+            Err(_) => 0,
+        }
+    }
+
+    /// Dimensione in byte della più grande area libera contigua.
+    pub fn largest_free_contiguous_bytes(&self) -> usize {
+        let Ok(state) = self.state.lock() else {
+            return 0;
+        };
+        let mut areas: heapless::Vec<(usize, usize), { EMBEDDED_MAX_REGIONS + 1 }> = state.regions
+            .iter()
+            .filter(|r| r.free)
+            .map(|r| (r.offset, r.len))
+            .collect();
+        // Lo spazio mai assegnato in coda al buffer è contiguo all'ultima regione liberata adiacente
+        let _ = areas.push((state.used, N - state.used));
+        areas.sort_unstable();
+        buddy_allocator::largest_contiguous_run(&areas)
+    }
+
+    /// Segna come libera una regione precedentemente assegnata, fondendola con le regioni libere adiacenti.
+    pub fn deallocate(&mut self, region: EmbeddedRegion) -> Result<(), CoreError> {
+        self.release(region)
+    }
+
+    /// Assegna una regione azzerata di `size` byte, restituendone l'indirizzo iniziale.
+    fn reserve(&self, size: usize) -> Result<(EmbeddedRegion, NonNull<u8>), CoreError> {
+        let regions_exhausted = || CoreError::ResourceAllocationError("Numero massimo di regioni embedded raggiunto".to_string(), Vec::new());
+        let mut state = self.state.lock().map_err(|e| CoreError::GenericError(e.to_string(), Vec::new()).with_context("EmbeddedPool::reserve"))?;
+        // Riutilizza la prima regione libera abbastanza grande
+        let region = match state.regions.iter().position(|r| r.free && r.len >= size) {
+            Some(index) => {
+                let offset = state.regions[index].offset;
+                let remaining = state.regions[index].len - size;
+                if remaining > 0 {
+                    // Divide la regione mantenendo libera la parte non utilizzata
+                    state.regions
+                        .push(EmbeddedRegion { offset: offset + size, len: remaining, free: true })
+                        .map_err(|_| regions_exhausted())?;
+                }
+                state.regions[index] = EmbeddedRegion { offset, len: size, free: false };
+                state.regions[index]
+            }
+            None => {
+                let offset = state.used;
+                if size > N - offset {
+                    return Err(CoreError::ResourceAllocationError(format!(
+                        "Capacità embedded esaurita: richiesti {} byte, disponibili {}", size, N - offset
+                    ), Vec::new()).with_context("EmbeddedPool::reserve"));
+                }
+                let region = EmbeddedRegion { offset, len: size, free: false };
+                state.regions.push(region).map_err(|_| regions_exhausted())?;
+                state.used = offset + size;
+                region
+            }
+        };
+        // SAFETY: `offset + len <= N`, la regione è interna al buffer e non è assegnata ad altri;
+        // il puntatore deriva da `UnsafeCell::get` e non è mai nullo.
+        let ptr = unsafe {
+            let ptr = (self.buffer.get() as *mut u8).add(region.offset);
+            std::ptr::write_bytes(ptr, 0, region.len);
+            NonNull::new_unchecked(ptr)
+        };
+        Ok((region, ptr))
+    }
+
+    /// Libera una regione e la fonde con le regioni libere adiacenti; se l'area risultante
+    /// termina a `used` torna allo spazio mai assegnato.
+    fn release(&self, region: EmbeddedRegion) -> Result<(), CoreError> {
+        let mut state = self.state.lock().map_err(|e| CoreError::GenericError(e.to_string(), Vec::new()).with_context("EmbeddedPool::release"))?;
+        let index = state.regions
+            .iter()
+            .position(|r| r.offset == region.offset && r.len == region.len && !r.free)
+            .ok_or_else(|| CoreError::ResourceAllocationError(format!(
+                "Regione embedded con offset {} non allocata", region.offset
+            ), Vec::new()).with_context("EmbeddedPool::release"))?;
+        let mut merged = state.regions.swap_remove(index);
+        merged.free = true;
+        while let Some(index) = state.regions
+            .iter()
+            .position(|r| r.free && (r.offset + r.len == merged.offset || merged.offset + merged.len == r.offset))
+        {
+            let neighbour = state.regions.swap_remove(index);
+            merged.offset = merged.offset.min(neighbour.offset);
+            merged.len += neighbour.len;
+        }
+        if merged.offset + merged.len == state.used {
+            state.used = merged.offset;
+        } else {
+            // Non può fallire: è stata appena rimossa almeno la regione liberata
+            let _ = state.regions.push(merged);
+        }
+        Ok(())
+    }
+}
+
+/// Pool statico da cui il `MemoryManager` serve la strategia `CustomEmbedded`, condiviso da tutti i gestori.
+#[cfg(feature = "embedded")]
+static EMBEDDED_POOL: EmbeddedPool<EMBEDDED_CAPACITY> = EmbeddedPool::new();
+
+/// Trait per osservare le operazioni del `MemoryManager`.
+///
+/// Gli osservatori vengono registrati con `MemoryManager::observe` e notificati in modo sincrono
+/// ad ogni operazione, pertanto le implementazioni devono essere rapide e non bloccanti.
+pub trait MemoryObserver: Send + Sync {
+    /// Chiamato dopo ogni allocazione riuscita con la dimensione effettiva del buffer.
+    fn on_allocate(&self, size: usize, strategy: &AllocationStrategy);
+    /// Chiamato ad ogni deallocazione con la dimensione del buffer restituito.
+    fn on_deallocate(&self, size: usize);
+    /// Chiamato quando il pool è esaurito e l'allocazione ricade su quella dinamica.
+    fn on_pool_exhaustion(&self);
+}
+
+/// Allocatore dell'heap utilizzato dalla strategia `Standard` con la feature `compact-retry`.
+///
+/// Le allocazioni sono fallibili: in caso di errore, se `MemoryConfig::compact_and_retry` è attivo,
+/// il `MemoryManager` chiama `MemoryManager::defragment`, che a sua volta invoca `defragment`,
+/// e ritenta una sola volta prima di restituire l'errore.
+/// L'implementazione predefinita è `SystemHeap`; implementazioni alternative permettono di simulare
+/// gli errori di allocazione nei test.
+#[cfg(feature = "compact-retry")]
+pub trait HeapAllocator: Send + Sync {
+    /// Tenta di allocare un buffer azzerato di `size` byte, restituendo `None` se la memoria non è disponibile.
+    fn try_allocate(&self, size: usize) -> Option<Box<[u8]>>;
+    /// Compatta l'heap restituendo al sistema la memoria libera frammentata.
+    fn defragment(&self);
+}
+
+/// Allocatore predefinito basato sull'allocatore globale di Rust.
+#[cfg(feature = "compact-retry")]
+#[derive(Debug, Default)]
+pub struct SystemHeap;
+
+#[cfg(feature = "compact-retry")]
+impl HeapAllocator for SystemHeap {
+    fn try_allocate(&self, size: usize) -> Option<Box<[u8]>> {
+        // `try_reserve_exact` segnala l'errore invece di terminare il processo con `handle_alloc_error`
+        let mut buffer = Vec::new();
+        buffer.try_reserve_exact(size).ok()?;
+        buffer.resize(size, 0u8);
+        Some(buffer.into_boxed_slice())
+    }
+
+    fn defragment(&self) {
+        // Con glibc `malloc_trim` restituisce al sistema le pagine libere dell'heap
+        #[cfg(all(target_os = "linux", target_env = "gnu"))]
+        // SAFETY: malloc_trim non ha precondizioni.
+        unsafe {
+            libc::malloc_trim(0);
+        }
+    }
+}
+
+/// Buffer allocato con `MemoryManager::allocate_aligned`, allineato al confine richiesto.
+///
+/// Conserva il `Layout` dell'allocazione così che la memoria venga restituita all'allocatore
+/// globale con lo stesso allineamento al momento del rilascio. Si usa come uno slice `&[u8]`.
+///
+/// # Nota
+/// Il buffer non torna al pool: il rilascio avviene con `drop` e non aggiorna le statistiche
+/// delle deallocazioni del `MemoryManager`.
+#[derive(Debug)]
+pub struct AlignedBuffer {
+    ptr: NonNull<u8>,
+    layout: Layout,
+}
+
+// SAFETY: il buffer è l'unico proprietario della memoria puntata, come un `Box<[u8]>`.
+unsafe impl Send for AlignedBuffer {}
+// SAFETY: l'accesso condiviso è in sola lettura tramite `Deref`.
+unsafe impl Sync for AlignedBuffer {}
+
+impl AlignedBuffer {
+    /// Alloca un buffer azzerato con il layout indicato.
+    ///
+    /// # Ritorna
+    /// Il buffer, oppure `None` se l'allocatore globale non dispone di memoria sufficiente.
+    fn zeroed(layout: Layout) -> Option<Self> {
+        if layout.size() == 0 {
+            // Le allocazioni vuote non toccano l'allocatore: basta un puntatore allineato non nullo
+            let ptr = NonNull::new(layout.align() as *mut u8)?;
+            return Some(AlignedBuffer { ptr, layout });
+        }
+        // SAFETY: il layout ha dimensione diversa da zero.
+        let ptr = NonNull::new(unsafe { alloc::alloc_zeroed(layout) })?;
+        Some(AlignedBuffer { ptr, layout })
+    }
+
+    /// Allineamento in byte garantito per l'indirizzo iniziale del buffer.
+    pub fn alignment(&self) -> usize {
+        self.layout.align()
+    }
+}
+
+impl Deref for AlignedBuffer {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        // SAFETY: `ptr` è valido e inizializzato per `layout.size()` byte per tutta la vita del buffer.
+        unsafe { std::slice::from_raw_parts(self.ptr.as_ptr(), self.layout.size()) }
+    }
+}
+
+impl DerefMut for AlignedBuffer {
+    fn deref_mut(&mut self) -> &mut [u8] {
+        // SAFETY: come per `deref`, con accesso esclusivo garantito da `&mut self`.
+        unsafe { std::slice::from_raw_parts_mut(self.ptr.as_ptr(), self.layout.size()) }
+    }
+}
+
+impl Drop for AlignedBuffer {
+    fn drop(&mut self) {
+        if self.layout.size() != 0 {
+            // SAFETY: la memoria è stata allocata da `alloc_zeroed` con questo stesso layout.
+            unsafe { alloc::dealloc(self.ptr.as_ptr(), self.layout) };
+        }
+    }
+}
+
+/// Buffer allocato con `MemoryManager::allocate_huge_page`.
+///
+/// Su Linux è una mappatura anonima su pagine huge, rilasciata con `munmap` al `drop`; sulle altre
+/// piattaforme contiene un buffer dell'allocatore globale. Si usa come uno slice `&[u8]`.
+///
+/// # Nota
+/// Come `AlignedBuffer`, il buffer non torna al pool e il rilascio non aggiorna le statistiche.
+#[derive(Debug)]
+pub struct HugePageBuffer {
+    #[cfg(target_os = "linux")]
+    ptr: NonNull<u8>,
+    #[cfg(target_os = "linux")]
+    len: usize,
+    #[cfg(target_os = "linux")]
+    mapped_len: usize,
+    #[cfg(not(target_os = "linux"))]
+    buffer: Box<[u8]>,
+    page_size: usize,
+}
+
+// SAFETY: il buffer è l'unico proprietario della mappatura, come un `Box<[u8]>`.
+#[cfg(target_os = "linux")]
+unsafe impl Send for HugePageBuffer {}
+// SAFETY: l'accesso condiviso è in sola lettura tramite `Deref`.
+#[cfg(target_os = "linux")]
+unsafe impl Sync for HugePageBuffer {}
+
+impl HugePageBuffer {
+    /// Mappa `len` byte azzerati su pagine huge da `page_size` byte.
+    ///
+    /// # Ritorna
+    /// Il buffer, oppure `CoreError::UnsupportedPlatform` se il sistema non dispone di pagine huge
+    /// della dimensione richiesta (ad esempio con `vm.nr_hugepages` a zero).
+    #[cfg(target_os = "linux")]
+    fn map(len: usize, page_size: usize) -> Result<Self, CoreError> {
+        // La mappatura deve coprire un numero intero di pagine, almeno una
+        let mapped_len = len.max(1).div_ceil(page_size) * page_size;
+        let flags = libc::MAP_PRIVATE
+            | libc::MAP_ANONYMOUS
+            | libc::MAP_HUGETLB
+            | ((page_size.trailing_zeros() as libc::c_int) << libc::MAP_HUGE_SHIFT);
+        // SAFETY: mappatura anonima senza indirizzo richiesto né file descriptor.
+        let ptr = unsafe {
+            libc::mmap(std::ptr::null_mut(), mapped_len, libc::PROT_READ | libc::PROT_WRITE, flags, -1, 0)
+        };
+        if ptr == libc::MAP_FAILED {
+            return Err(CoreError::UnsupportedPlatform(format!(
+                "Pagine huge da {} byte non disponibili: {}", page_size, io::Error::last_os_error()
+            ), Vec::new()));
+        }
+        let ptr = NonNull::new(ptr as *mut u8).ok_or_else(|| CoreError::UnsupportedPlatform(
+            "mmap ha restituito un indirizzo nullo".to_string(), Vec::new()
+        ))?;
+        // Le mappature anonime sono già azzerate dal kernel
+        Ok(HugePageBuffer { ptr, len, mapped_len, page_size })
+    }
+
+    /// Dimensione in byte delle pagine su cui è allocato il buffer.
+    pub fn page_size(&self) -> usize {
+        self.page_size
+    }
+}
+
+impl Deref for HugePageBuffer {
+    type Target = [u8];
+
+    #[cfg(target_os = "linux")]
+    fn deref(&self) -> &[u8] {
+        // SAFETY: la mappatura è valida per `mapped_len >= len` byte per tutta la vita del buffer.
+        unsafe { std::slice::from_raw_parts(self.ptr.as_ptr(), self.len) }
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    fn deref(&self) -> &[u8] {
+        &self.buffer
+    }
+}
+
+impl DerefMut for HugePageBuffer {
+    #[cfg(target_os = "linux")]
+    fn deref_mut(&mut self) -> &mut [u8] {
+        // SAFETY: come per `deref`, con accesso esclusivo garantito da `&mut self`.
+        unsafe { std::slice::from_raw_parts_mut(self.ptr.as_ptr(), self.len) }
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    fn deref_mut(&mut self) -> &mut [u8] {
+        &mut self.buffer
+    }
+}
+
+#[cfg(target_os = "linux")]
+impl Drop for HugePageBuffer {
+    fn drop(&mut self) {
+        // SAFETY: la mappatura è stata creata da `map` con questa stessa lunghezza.
+        unsafe { libc::munmap(self.ptr.as_ptr() as *mut libc::c_void, self.mapped_len) };
+    }
+}
+
+/// Area contigua da cui vengono ricavati i buffer del pool.
+///
+/// È condivisa dai `ManagedBuffer` che ne fanno parte e torna all'allocatore globale quando
+/// l'ultimo di essi viene rilasciato.
+#[derive(Debug)]
+struct PoolSlab {
+    ptr: NonNull<u8>,
+    layout: Layout,
+}
+
+// SAFETY: la memoria dell'area è accessibile solo tramite i `ManagedBuffer`, che ne occupano porzioni disgiunte.
+unsafe impl Send for PoolSlab {}
+// SAFETY: `PoolSlab` non espone la memoria, si limita a rilasciarla.
+unsafe impl Sync for PoolSlab {}
+
+impl Drop for PoolSlab {
+    fn drop(&mut self) {
+        // SAFETY: la memoria è stata allocata da `alloc_zeroed` con questo stesso layout.
+        unsafe { alloc::dealloc(self.ptr.as_ptr(), self.layout) };
+    }
+}
+
+/// Buffer restituito da `MemoryManager::allocate`. Si usa come uno slice `&[u8]`.
+///
+/// I buffer del pool sono porzioni consecutive di un'unica area contigua, così che gli indirizzi
+/// dei buffer liberi riflettano la disposizione reale del pool (vedi `MemoryManager::fragmentation_ratio`);
+/// gli altri buffer sono allocati singolarmente dall'allocatore globale.
+///
+/// # Nota
+/// Un buffer del pool rilasciato con `drop` invece che con `MemoryManager::deallocate` non torna al pool:
+/// l'area che lo contiene viene liberata solo quando tutti i suoi buffer sono stati rilasciati.
+pub struct ManagedBuffer {
+    storage: BufferStorage,
+}
+
+/// Memoria di un `ManagedBuffer`.
+/// - `Heap`: Buffer allocato singolarmente.
+/// - `Slab`: Porzione di `len` byte di un'area del pool, a partire da `ptr`.
+/// - `Embedded`: Regione del pool statico di `CustomEmbedded`, che vi torna al rilascio.
+enum BufferStorage {
+    Heap(Box<[u8]>),
+    Slab { _slab: Arc<PoolSlab>, ptr: NonNull<u8>, len: usize },
+    #[cfg(feature = "embedded")]
+    Embedded { region: EmbeddedRegion, ptr: NonNull<u8> },
+}
+
+// SAFETY: il buffer è l'unico proprietario della sua porzione di memoria, come un `Box<[u8]>`.
+unsafe impl Send for ManagedBuffer {}
+// SAFETY: l'accesso condiviso è in sola lettura tramite `Deref`.
+unsafe impl Sync for ManagedBuffer {}
+
+impl ManagedBuffer {
+    /// Ricava `count` buffer consecutivi da `buffer_size` byte da un'unica area azzerata.
+    fn carve(buffer_size: usize, count: usize) -> Vec<ManagedBuffer> {
+        if buffer_size == 0 || count == 0 {
+            return (0..count).map(|_| ManagedBuffer::from(Vec::new())).collect();
+        }
+        let layout = buffer_size
+            .checked_mul(count)
+            .and_then(|total| Layout::array::<u8>(total).ok())
+            .expect("Dimensione del pool oltre lo spazio degli indirizzi");
+        // SAFETY: il layout ha dimensione diversa da zero.
+        let ptr = NonNull::new(unsafe { alloc::alloc_zeroed(layout) }).unwrap_or_else(|| alloc::handle_alloc_error(layout));
+        let slab = Arc::new(PoolSlab { ptr, layout });
+        (0..count)
+            .map(|i| {
+                // SAFETY: `i * buffer_size + buffer_size <= layout.size()`, la porzione è interna all'area.
+                let ptr = unsafe { NonNull::new_unchecked(ptr.as_ptr().add(i * buffer_size)) };
+                ManagedBuffer { storage: BufferStorage::Slab { _slab: Arc::clone(&slab), ptr, len: buffer_size } }
+            })
+            .collect()
+    }
+
+    /// Assegna un buffer azzerato di `size` byte dal pool statico di `CustomEmbedded`, senza utilizzare l'heap.
+    #[cfg(feature = "embedded")]
+    fn embedded(size: usize) -> Result<ManagedBuffer, CoreError> {
+        let (region, ptr) = EMBEDDED_POOL.reserve(size)?;
+        Ok(ManagedBuffer { storage: BufferStorage::Embedded { region, ptr } })
+    }
+
+    /// Indica se il buffer è allocato singolarmente e si converte in `Box<[u8]>` senza copia.
+    fn is_heap(&self) -> bool {
+        matches!(self.storage, BufferStorage::Heap(_))
+    }
+
+    /// Indica se il buffer proviene dal pool statico di `CustomEmbedded`.
+    #[cfg(feature = "embedded")]
+    fn is_embedded(&self) -> bool {
+        matches!(self.storage, BufferStorage::Embedded { .. })
+    }
+
+    /// Converte il buffer in un `Box<[u8]>`: i buffer allocati singolarmente non vengono copiati,
+    /// gli altri sì.
+    pub fn into_boxed_slice(mut self) -> Box<[u8]> {
+        match std::mem::replace(&mut self.storage, BufferStorage::Heap(Box::default())) {
+            BufferStorage::Heap(buffer) => buffer,
+            storage => {
+                self.storage = storage;
+                self.to_vec().into_boxed_slice()
+            }
+        }
+    }
+}
+
+/// I buffer di `CustomEmbedded` tornano al pool statico al rilascio, anche senza `MemoryManager::deallocate`.
+#[cfg(feature = "embedded")]
+impl Drop for ManagedBuffer {
+    fn drop(&mut self) {
+        if let BufferStorage::Embedded { region, .. } = self.storage {
+            if let Err(e) = EMBEDDED_POOL.release(region) {
+                warn!("Restituzione della regione embedded fallita: {}", e);
+            }
+        }
+    }
+}
+
+impl Deref for ManagedBuffer {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        match &self.storage {
+            BufferStorage::Heap(buffer) => buffer,
+            // SAFETY: la porzione è valida e inizializzata per `len` byte finché `_slab` è in vita.
+            BufferStorage::Slab { ptr, len, .. } => unsafe { std::slice::from_raw_parts(ptr.as_ptr(), *len) },
+            // SAFETY: la regione del pool statico è valida per `region.len` byte finché non viene rilasciata in `drop`.
+            #[cfg(feature = "embedded")]
+            BufferStorage::Embedded { region, ptr } => unsafe { std::slice::from_raw_parts(ptr.as_ptr(), region.len) },
+        }
+    }
+}
+
+impl DerefMut for ManagedBuffer {
+    fn deref_mut(&mut self) -> &mut [u8] {
+        match &mut self.storage {
+            BufferStorage::Heap(buffer) => buffer,
+            // SAFETY: come per `deref`; nessun altro buffer condivide la porzione e `&mut self` garantisce l'esclusività.
+            BufferStorage::Slab { ptr, len, .. } => unsafe { std::slice::from_raw_parts_mut(ptr.as_ptr(), *len) },
+            // SAFETY: come per `deref`, con accesso esclusivo garantito da `&mut self`.
+            #[cfg(feature = "embedded")]
+            BufferStorage::Embedded { region, ptr } => unsafe { std::slice::from_raw_parts_mut(ptr.as_ptr(), region.len) },
+        }
+    }
+}
+
+impl From<Box<[u8]>> for ManagedBuffer {
+    fn from(buffer: Box<[u8]>) -> Self {
+        ManagedBuffer { storage: BufferStorage::Heap(buffer) }
+    }
+}
+
+impl From<Vec<u8>> for ManagedBuffer {
+    fn from(buffer: Vec<u8>) -> Self {
+        ManagedBuffer::from(buffer.into_boxed_slice())
+    }
+}
+
+impl PartialEq for ManagedBuffer {
+    fn eq(&self, other: &Self) -> bool {
+        **self == **other
+    }
+}
+
+impl Eq for ManagedBuffer {}
+
+impl std::fmt::Debug for ManagedBuffer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ManagedBuffer")
+            .field("len", &self.len())
+            .field("pooled", &matches!(self.storage, BufferStorage::Slab { .. }))
+            .finish()
+    }
+}
+
+/// Stato del pool di un `MemoryManager`, protetto da un `tokio::sync::Mutex` così che
+/// `allocate_async` e `deallocate_async` possano condividerlo tra task senza bloccare il thread.
+///
+/// # Campi
+/// - `pool`: Un pool di buffer pre-allocati (usato solo nelle strategie basate su pool).
+/// - `standby_pool`: Il pool in preparazione per il frame successivo (solo con la strategia `DoubleBuffered`).
+/// - `active_in_use` / `standby_in_use`: Indirizzi dei buffer in uso provenienti dal pool attivo e da quello
+///   in preparazione, così che `deallocate` restituisca ogni buffer al pool da cui è stato allocato.
+/// - `pending_shrink_target`: Capacità del pool richiesta da `resize_pool` e non ancora raggiunta
+///   perché alcuni buffer sono in uso; viene applicata ad ogni `deallocate`.
+/// - `initialized`: Indica se i buffer del pool sono già stati allocati (vedi `lazy_pool`).
+#[derive(Default)]
+struct PoolState {
+    pool: Option<VecDeque<ManagedBuffer>>,
+    standby_pool: Option<VecDeque<ManagedBuffer>>,
+    active_in_use: HashSet<usize>,
+    standby_in_use: HashSet<usize>,
+    pending_shrink_target: Option<usize>,
+    initialized: bool,
+}
+
+impl PoolState {
+    /// Numero di buffer liberi nel pool attivo.
+    fn free_buffers(&self) -> usize {
+        self.pool.as_ref().map_or(0, |p| p.len())
+    }
+}
+
+/// `MemoryManager` gestisce l'allocazione e la deallocazione della memoria in base alla strategia
+/// selezionata dall'applicazione.
+///
+/// # Campi
+/// - `default_allocation_strategy`: La strategia di allocazione utilizzata.
+/// - `state`: Lo stato del pool (vedi `PoolState`), condiviso tra i task da `allocate_async` e `deallocate_async`.
+/// - `memory_config`: Configurazione della memoria di default fornita dall'utente.
+/// - `counters`: Contatori condivisi utilizzati per le statistiche.
+/// - `observers`: Osservatori notificati ad ogni operazione (vedi `MemoryObserver`).
+/// - `lent_buffers` / `borrowed_buffers`: Buffer prestati ad altri gestori o ricevuti in prestito (vedi `lend`).
+/// - `pool_slots`: Permessi rilasciati ad ogni buffer restituito al pool, attesi da `allocate_async`.
+/// - `heap`: Allocatore della strategia `Standard` (solo con la feature `compact-retry`).
+/// - `registry`: Registro delle strutture CRUD su cui operano gli store del gestore (solo con la feature `crud`,
+///   vedi `use_registry`).
+/// - `extensions`: Dati applicativi associati al gestore, uno per tipo (vedi `insert_extension`).
+/// - `pressure_callback` / `under_pressure`: Callback di pressione e stato dell'ultimo controllo,
+///   così che la callback venga invocata solo al superamento della soglia.
+pub struct MemoryManager {
+    pub(crate) default_allocation_strategy: AllocationStrategy,
+    state: AsyncMutex<PoolState>,  // Pool per l'allocazione basata su pool
+    pub(crate) memory_config: MemoryConfig,  // Configurazione della memoria di default 
+    pub(crate) counters: Arc<PoolCounters>,
+    observers: Vec<Arc<dyn MemoryObserver>>,
+    lent_buffers: usize,
+    borrowed_buffers: usize,
+    pool_slots: Arc<Semaphore>,
+    #[cfg(feature = "compact-retry")]
+    heap: Arc<dyn HeapAllocator>,
+    #[cfg(feature = "crud")]
+    registry: Arc<MemoryRegistry>,
+    extensions: HashMap<TypeId, Box<dyn Any + Send + Sync>>,
+    pressure_callback: Option<MemoryPressureCallback>,
+    under_pressure: AtomicBool,
+    #[cfg(feature = "tracking")]
+    allocation_records: Mutex<Vec<AllocationRecord>>,  // Tutte le allocazioni effettuate
+    #[cfg(feature = "tracking")]
+    buffer_events: Mutex<Vec<BufferEvent>>,  // Sequenza di allocazioni e deallocazioni per buffer
+}
+
+impl MemoryManager {
+    /// Crea un nuovo gestore della memoria per l'applicazione specificata.
+    ///
+    /// # Parametri
+    /// - `app_type`: Il tipo di applicazione (ad esempio, `WebApp`, `ApiBackend`, `DesktopApp`, ecc.).
+    /// - `memory_config`: La configurazione della memoria che specifica le dimensioni dei buffer e del pool.
+    ///
+    /// # Ritorna
+    /// Un'istanza di `MemoryManager` o un errore di tipo `CoreError` in caso di fallimento,
+    /// incluso `CoreError::ConfigurationError` se `MemoryConfig::validate` rifiuta la configurazione.
+    pub fn new(app_type: ApplicationType, memory_config: MemoryConfig) -> Result<Self, CoreError> {
+        info!("Inizializzazione del MemoryManager...");
+
+        // Determina la strategia di allocazione in base al tipo di applicazione.
+        let strategy = match app_type {
+            ApplicationType::WebApp | ApplicationType::ApiBackend => AllocationStrategy::PoolBased,
+            ApplicationType::DesktopApp => AllocationStrategy::Standard,
+            ApplicationType::AutomationScript => AllocationStrategy::Standard,
+            ApplicationType::EmbeddedSystem => AllocationStrategy::CustomEmbedded,
+            // wasm-bindgen utilizza l'allocatore standard del modulo
+            ApplicationType::WasmBased => AllocationStrategy::Standard,
+            ApplicationType::GameServer => AllocationStrategy::DoubleBuffered,
+            ApplicationType::MLInference => AllocationStrategy::HugePage { page_size: HUGE_PAGE_SIZE },
+            _ => {
+                return Err(CoreError::ConfigurationError("Tipo di applicazione non supportato considera implementazione".to_string(), Vec::new()).with_context("MemoryManager::new"));
+            },
+        };
+        memory_config.validate(app_type)?;
+
+        Self::with_strategy(strategy, memory_config)
+    }
+
+    /// Crea un nuovo gestore della memoria con la strategia di allocazione specificata,
+    /// ad esempio `GrowingPool` che non è associata ad alcun tipo di applicazione.
+    ///
+    /// # Ritorna
+    /// Un'istanza di `MemoryManager`, oppure `CoreError::ConfigurationError` se `buffer_size` è 0,
+    /// se `pressure_threshold` non è compreso tra 0.0 e 1.0, se i parametri di `GrowingPool` non sono
+    /// validi (`initial_size > max_size` o `growth_factor <= 1.0`) o se `page_size` di `HugePage`
+    /// non è una potenza di due.
+    pub fn with_strategy(strategy: AllocationStrategy, memory_config: MemoryConfig) -> Result<Self, CoreError> {
+        #[cfg(target_arch = "wasm32")]
+        Self::ensure_wasm_supported(&strategy)?;
+        memory_config.validate_common().map_err(|e| e.with_context("MemoryManager::with_strategy"))?;
+        if let AllocationStrategy::HugePage { page_size } = strategy {
+            if !page_size.is_power_of_two() {
+                return Err(CoreError::ConfigurationError(format!(
+                    "Parametri di HugePage non validi: page_size {} non è una potenza di due", page_size
+                ), Vec::new()).with_context("MemoryManager::with_strategy"));
+            }
+        }
+        if let AllocationStrategy::GrowingPool { initial_size, max_size, growth_factor } = strategy {
+            if initial_size > max_size || growth_factor.is_nan() || growth_factor <= 1.0 {
+                return Err(CoreError::ConfigurationError(format!(
+                    "Parametri di GrowingPool non validi: initial_size {}, max_size {}, growth_factor {}", initial_size, max_size, growth_factor
+                ), Vec::new()).with_context("MemoryManager::with_strategy"));
+            }
+        }
+
+        // Inizializza il pool solo per le strategie basate su pool.
+        // Con `lazy_pool` il pool viene creato vuoto e riempito alla prima allocazione.
+        let pool = if strategy.is_pool_based() {
+            if memory_config.lazy_pool {
+                Some(VecDeque::new())
+            } else {
+                Some(Self::build_pool(&memory_config, Self::initial_buffer_count(&strategy, &memory_config)))
+            }
+        } else {
+            None
+        };
+        // Il secondo pool di `DoubleBuffered` segue la stessa inizializzazione del pool attivo
+        let standby_pool = match (&strategy, &pool) {
+            (AllocationStrategy::DoubleBuffered, Some(active)) => Some(Self::build_pool(&memory_config, active.len())),
+            _ => None,
+        };
+        let pool_initialized = !memory_config.lazy_pool;
+
+        let counters = Arc::new(PoolCounters::default());
+        let pool_capacity = pool.as_ref().map_or(0, |p| p.len());
+        counters.pool_capacity.store(pool_capacity, Ordering::Relaxed);
+        counters.free_buffers.store(pool_capacity, Ordering::Relaxed);
+
+        Ok(Self {
+            default_allocation_strategy: strategy,
+            state: AsyncMutex::new(PoolState {
+                pool,
+                standby_pool,
+                initialized: pool_initialized,
+                ..PoolState::default()
+            }),
+            memory_config,
+            counters,
+            observers: Vec::new(),
+            lent_buffers: 0,
+            borrowed_buffers: 0,
+            pool_slots: Arc::new(Semaphore::new(pool_capacity)),
+            pressure_callback: None,
+            under_pressure: AtomicBool::new(false),
+            #[cfg(feature = "compact-retry")]
+            heap: Arc::new(SystemHeap),
+            #[cfg(feature = "crud")]
+            registry: MemoryRegistry::global(),
+            extensions: HashMap::new(),
+            #[cfg(feature = "tracking")]
+            allocation_records: Mutex::new(Vec::new()),
+            #[cfg(feature = "tracking")]
+            buffer_events: Mutex::new(Vec::new()),
+        })
+    }
+
+    /// Verifica che la strategia abbia senso in un modulo WebAssembly: `CustomEmbedded` presuppone
+    /// un buffer statico dedicato all'hardware e non è disponibile.
+    #[cfg(target_arch = "wasm32")]
+    fn ensure_wasm_supported(strategy: &AllocationStrategy) -> Result<(), CoreError> {
+        if let AllocationStrategy::CustomEmbedded = strategy {
+            return Err(CoreError::WasmUnsupported(
+                "La strategia CustomEmbedded non è disponibile nei moduli WebAssembly".to_string(), Vec::new()
+            ));
+        }
+        Ok(())
+    }
+
+    /// Numero di buffer con cui viene creato il pool: `initial_size` per `GrowingPool`, metà dei
+    /// buffer per ciascuno dei due pool di `DoubleBuffered`, altrimenti in base alla dimensione
+    /// totale del pool e del buffer.
+    fn initial_buffer_count(strategy: &AllocationStrategy, memory_config: &MemoryConfig) -> usize {
+        match strategy {
+            AllocationStrategy::GrowingPool { initial_size, .. } => *initial_size,
+            AllocationStrategy::DoubleBuffered => memory_config.pool_size / memory_config.buffer_size / 2,
+            _ => memory_config.pool_size / memory_config.buffer_size,
+        }
+    }
+
+    /// Crea `buffer_count` buffer del pool della dimensione configurata, ricavati da un'unica area contigua.
+    fn build_pool(memory_config: &MemoryConfig, buffer_count: usize) -> VecDeque<ManagedBuffer> {
+        ManagedBuffer::carve(memory_config.buffer_size, buffer_count).into_iter().collect()
+    }
+
+    /// Alloca i buffer del pool se la creazione è stata rimandata con `lazy_pool`.
+    fn ensure_pool_initialized(&mut self) {
+        let mut state = self.lock_state();
+        self.initialize_pool(&mut state);
+    }
+
+    /// Alloca i buffer dello stato del pool indicato se la creazione è stata rimandata con `lazy_pool`.
+    ///
+    /// Lo stato è acquisito dal chiamante, così che l'inizializzazione avvenga una sola volta
+    /// anche quando il `MemoryManager` è condiviso tra task con `allocate_async`.
+    fn initialize_pool(&self, state: &mut PoolState) {
+        if state.initialized {
+            return;
+        }
+        if let Some(ref mut pool) = state.pool {
+            info!("Inizializzazione differita del pool...");
+            let count = Self::initial_buffer_count(&self.default_allocation_strategy, &self.memory_config);
+            let buffers = Self::build_pool(&self.memory_config, count);
+            self.counters.pool_capacity.store(buffers.len(), Ordering::Relaxed);
+            self.pool_slots.add_permits(buffers.len());
+            pool.extend(buffers);
+        }
+        if let Some(ref mut standby) = state.standby_pool {
+            let count = Self::initial_buffer_count(&self.default_allocation_strategy, &self.memory_config);
+            standby.extend(Self::build_pool(&self.memory_config, count));
+        }
+        state.initialized = true;
+    }
+
+    /// Acquisisce lo stato del pool dai metodi sincroni.
+    ///
+    /// `allocate_async` e `deallocate_async` acquisiscono il lock senza accodarsi e lo rilasciano
+    /// prima di ogni punto di attesa: un lock occupato appartiene quindi ad un altro thread in
+    /// esecuzione e viene rilasciato in breve tempo. Con `&mut self` il lock è sempre libero.
+    fn lock_state(&self) -> AsyncMutexGuard<'_, PoolState> {
+        loop {
+            if let Ok(state) = self.state.try_lock() {
+                return state;
+            }
+            std::thread::yield_now();
+        }
+    }
+
+    /// Acquisisce lo stato del pool dai metodi asincroni, cedendo il controllo al runtime finché è occupato.
+    ///
+    /// Il lock non viene atteso in coda con `lock().await`: un permesso assegnato ad un task non ancora
+    /// in esecuzione bloccherebbe `lock_state` sullo stesso thread del runtime.
+    #[cfg(feature = "async")]
+    async fn lock_state_async(&self) -> AsyncMutexGuard<'_, PoolState> {
+        loop {
+            if let Ok(state) = self.state.try_lock() {
+                return state;
+            }
+            tokio::task::yield_now().await;
+        }
+    }
+
+    /// Preleva un buffer libero dal pool, estendendolo prima con la strategia `GrowingPool` se esaurito.
+    ///
+    /// Il buffer viene reso accessibile e azzerato secondo la `ZeroingPolicy`; il permesso di
+    /// `pool_slots` corrispondente resta a carico del chiamante.
+    fn take_pooled(&self, state: &mut PoolState, strategy: &AllocationStrategy) -> Option<ManagedBuffer> {
+        let mut buffer = state.pool.as_mut().and_then(|pool| pool.pop_front());
+        if buffer.is_none() {
+            if let AllocationStrategy::GrowingPool { max_size, growth_factor, .. } = *strategy {
+                if self.grow_pool(state, max_size, growth_factor) > 0 {
+                    buffer = state.pool.as_mut().and_then(|pool| pool.pop_front());
+                }
+            }
+        }
+        let mut buffer = buffer?;
+        if matches!(self.default_allocation_strategy, AllocationStrategy::DoubleBuffered) {
+            state.active_in_use.insert(buffer.as_ptr() as usize);
+        }
+        // Con la protezione dei buffer liberi le pagine protette in `deallocate` vengono rese accessibili e azzerate
+        #[cfg(all(target_os = "linux", feature = "lazy-zero"))]
+        if self.memory_config.zeroing_policy.protect_free_buffers() {
+            lazy_zero::unprotect(&mut buffer);
+        }
+        // I buffer del pool vengono riutilizzati: il contenuto precedente resta se non azzerato
+        if self.memory_config.zeroing_policy.zero_on_allocate() {
+            zero_buffer(&mut buffer);
+        }
+        Some(buffer)
+    }
+
+    /// Restituisce un buffer al pool da cui è stato allocato e applica l'eventuale riduzione in sospeso.
+    fn return_pooled(&self, state: &mut PoolState, mut buffer: ManagedBuffer) -> Result<(), CoreError> {
+        // Con la protezione dei buffer liberi le pagine vengono protette fino alla prossima allocazione
+        #[cfg(all(target_os = "linux", feature = "lazy-zero"))]
+        if self.memory_config.zeroing_policy.protect_free_buffers() {
+            lazy_zero::protect(&mut buffer);
+        }
+        let address = buffer.as_ptr() as usize;
+        // Con `DoubleBuffered` i buffer allocati prima dell'ultimo scambio tornano al pool in preparazione
+        if state.standby_in_use.remove(&address) {
+            state.standby_pool.get_or_insert_with(VecDeque::new).push_back(buffer);
+            Ok(())
+        } else if let Some(ref mut pool) = state.pool {
+            // Restituisce il buffer al pool.
+            state.active_in_use.remove(&address);
+            pool.push_back(buffer);
+            self.pool_slots.add_permits(1);
+            self.apply_pending_shrink(state);
+            Ok(())
+        } else {
+            Err(CoreError::ResourceAllocationError("Pool non disponibile".to_string(), Vec::new()).with_context("MemoryManager::deallocate"))
+        }
+    }
+
+    /// Aggiorna contatori e osservatori dopo l'allocazione di `buffer`.
+    fn finish_allocation(&self, buffer: &ManagedBuffer, strategy: &AllocationStrategy) {
+        self.record_allocation(buffer.len());
+        for observer in self.observers.iter() {
+            observer.on_allocate(buffer.len(), strategy);
+        }
+        #[cfg(feature = "tracking")]
+        self.record_buffer_event(BufferEvent::Allocate(buffer.as_ptr() as usize));
+        self.check_memory_pressure(true);
+    }
+
+    /// Aggiorna contatori e osservatori prima della deallocazione di `buffer`, azzerandolo se richiesto.
+    fn begin_deallocation(&self, buffer: &mut ManagedBuffer) {
+        self.counters.total_deallocations.fetch_add(1, Ordering::Relaxed);
+        for observer in self.observers.iter() {
+            observer.on_deallocate(buffer.len());
+        }
+        #[cfg(feature = "tracking")]
+        self.record_buffer_event(BufferEvent::Deallocate(buffer.as_ptr() as usize));
+        if self.memory_config.zeroing_policy.zero_on_deallocate() {
+            zero_buffer(buffer);
+        }
+    }
+
+    /// Alloca memoria in base alla strategia configurata.
+    ///
+    /// # Parametri
+    /// - `strategy`: La strategia di allocazione opzionale (`AllocationStrategy`). Se `None`, verrà utilizzata la strategia di default.
+    /// - `size`: La quantità di memoria da allocare in byte.
+    ///
+    /// # Ritorna
+    /// Un buffer di memoria (`ManagedBuffer`) o un errore di tipo `CoreError` in caso di fallimento.
+    ///
+    /// # Nota
+    /// - La strategia `Standard` alloca dinamicamente la memoria.
+    /// - La strategia `PoolBased` utilizza buffer pre-allocati dal pool. Se il pool è esaurito, viene effettuata un'allocazione dinamica,
+    ///   oppure restituito `CoreError::PoolExhausted` se `memory_config.dynamic_fallback` è disattivato.
+    /// - La strategia `GrowingPool` a pool esaurito ne aumenta la capacità fino a `max_size` prima di comportarsi come `PoolBased`.
+    /// - La strategia `DoubleBuffered` si comporta come `PoolBased` sul pool attivo (vedi `swap_buffers`).
+    /// - La strategia `HugePage` si comporta come `Standard`: per le pagine huge usare `allocate_huge_page`.
+    /// - La strategia `CustomEmbedded` utilizza una configurazione fissa per i buffer, che è specificata dalla configurazione della memoria (`memory_config`).
+    /// - Le richieste oltre `memory_config.max_allocation_size`, o oltre `buffer_size` con la strategia `CustomEmbedded`,
+    ///   falliscono con `CoreError::AllocationTooLarge` senza toccare il pool né l'allocatore.
+    /// - Le richieste oltre `memory_config.max_single_allocation` falliscono prima di ogni altro controllo con
+    ///   `CoreError::ResourceAllocationError`.
+    pub fn allocate(&mut self, strategy: Option<AllocationStrategy>, size: usize) -> Result<ManagedBuffer, CoreError> {
+        if let Some(max) = self.memory_config.max_single_allocation {
+            if size > max {
+                return Err(CoreError::ResourceAllocationError(
+                    format!("requested {} exceeds max {}", size, max), Vec::new()
+                ).with_context("MemoryManager::allocate"));
+            }
+        }
+        let requested_strategy = strategy.as_ref().unwrap_or(&self.default_allocation_strategy);
+        let max = match requested_strategy {
+            AllocationStrategy::CustomEmbedded => self.memory_config.max_allocation_size_for(requested_strategy).min(self.memory_config.buffer_size),
+            _ => self.memory_config.max_allocation_size_for(requested_strategy),
+        };
+        Self::check_allocation_size(size, max)?;
+        let alloc_strategy = strategy.unwrap_or(self.default_allocation_strategy.clone());
+        #[cfg(target_arch = "wasm32")]
+        Self::ensure_wasm_supported(&alloc_strategy)?;
+    
+        info!("Allocazione di {} byte di memoria con strategia {:?}...", size, alloc_strategy);
+        let result = match alloc_strategy {
+            AllocationStrategy::Standard | AllocationStrategy::HugePage { .. } => self.allocate_standard(size),
+            AllocationStrategy::PoolBased | AllocationStrategy::GrowingPool { .. } | AllocationStrategy::DoubleBuffered => {
+                self.ensure_pool_initialized();
+                let buffer = {
+                    let mut state = self.lock_state();
+                    if state.pool.is_none() {
+                        return Err(CoreError::ResourceAllocationError("Pool non disponibile".to_string(), Vec::new()).with_context("MemoryManager::allocate"));
+                    }
+                    self.take_pooled(&mut state, &alloc_strategy)
+                };
+
+                if let Some(buffer) = buffer {
+                    // Il permesso può mancare se già consumato da un `allocate_async` in attesa
+                    if let Ok(permit) = self.pool_slots.try_acquire() {
+                        permit.forget();
+                    }
+                    Ok(buffer)
+                } else {
+                    for observer in self.observers.iter() {
+                        observer.on_pool_exhaustion();
+                    }
+                    if self.memory_config.dynamic_fallback {
+                        // Pool esaurito, alloca dinamicamente
+                        self.counters.dynamic_fallbacks.fetch_add(1, Ordering::Relaxed);
+                        Ok(ManagedBuffer::from(vec![0u8; size]))
+                    } else {
+                        Err(CoreError::PoolExhausted(
+                            format!("Pool esaurito: {} buffer allocati", self.counters.pool_capacity.load(Ordering::Relaxed)), Vec::new()
+                        ))
+                    }
+                }
+            },
+            AllocationStrategy::CustomEmbedded => {
+                // Usa la dimensione configurata per i buffer negli embedded, dal pool statico senza heap.
+                #[cfg(feature = "embedded")]
+                let buffer = ManagedBuffer::embedded(self.memory_config.buffer_size);
+                #[cfg(not(feature = "embedded"))]
+                let buffer = Ok(ManagedBuffer::from(vec![0u8; self.memory_config.buffer_size]));
+                buffer
+            },
+        };
+
+        if let Ok(buffer) = &result {
+            self.finish_allocation(buffer, &alloc_strategy);
+        }
+        result
+    }
+    
+
+    /// Dealloca memoria precedentemente allocata.
+    ///
+    /// # Parametri
+    /// - `buffer`: Il buffer di memoria da deallocare.
+    ///
+    /// # Ritorna
+    /// `Ok(())` se la deallocazione ha successo, oppure un errore di tipo `CoreError`.
+    ///
+    /// # Nota
+    /// - Nella strategia `Standard`, Rust dealloca automaticamente la memoria.
+    /// - Nella strategia `PoolBased`, il buffer viene restituito al pool.
+    /// - Nella strategia `DoubleBuffered`, il buffer viene restituito al pool da cui è stato allocato, anche se
+    ///   nel frattempo `swap_buffers` lo ha reso il pool in preparazione.
+    /// - I buffer della strategia `CustomEmbedded` tornano al pool statico (con la feature `embedded`),
+    ///   qualunque sia la strategia di default.
+    pub fn deallocate(&mut self, mut buffer: ManagedBuffer) -> Result<(), CoreError> {
+        info!("Deallocazione della memoria...");
+        self.begin_deallocation(&mut buffer);
+        // Il rilascio del buffer lo restituisce al pool statico
+        #[cfg(feature = "embedded")]
+        if buffer.is_embedded() {
+            return Ok(());
+        }
+        let result = match self.default_allocation_strategy {
+            AllocationStrategy::Standard | AllocationStrategy::HugePage { .. } => {
+                // Rust dealloca automaticamente la memoria.
+                Ok(())
+            },
+            AllocationStrategy::PoolBased | AllocationStrategy::GrowingPool { .. } | AllocationStrategy::DoubleBuffered => {
+                let mut state = self.lock_state();
+                self.return_pooled(&mut state, buffer)
+            },
+            AllocationStrategy::CustomEmbedded => {
+                // Gestione personalizzata per sistemi embedded.
+                Ok(())
+            },
+        };
+        self.sync_free_buffers();
+        self.check_memory_pressure(false);
+        result
+    }
+
+    /// Imposta la callback invocata quando l'utilizzo del pool supera `MemoryConfig::pressure_threshold`,
+    /// così che i servizi ad alta frequenza possano applicare backpressure prima dell'esaurimento.
+    ///
+    /// # Parametri
+    /// - `callback`: Riceve le `PoolStats` correnti. Viene invocata dopo l'`allocate` che porta
+    ///   l'utilizzo (buffer in uso / buffer totali) alla soglia o oltre; lo sarà di nuovo solo dopo
+    ///   che le deallocazioni avranno riportato l'utilizzo sotto la soglia.
+    pub fn set_pressure_callback(&mut self, callback: MemoryPressureCallback) {
+        self.pressure_callback = Some(callback);
+        self.under_pressure.store(false, Ordering::Relaxed);
+    }
+
+    /// Aggiorna lo stato di pressione del pool e, se `notify` è attivo, invoca la callback
+    /// al superamento della soglia.
+    fn check_memory_pressure(&self, notify: bool) {
+        if self.pressure_callback.is_none() || !self.default_allocation_strategy.is_pool_based() {
+            return;
+        }
+        let stats = self.stats();
+        if stats.total_buffers == 0 {
+            return;
+        }
+        let in_use = stats.total_buffers.saturating_sub(stats.available_buffers);
+        let utilization = in_use as f32 / stats.total_buffers as f32;
+        let above = utilization >= self.memory_config.pressure_threshold;
+        let was_under_pressure = self.under_pressure.load(Ordering::Relaxed);
+        if above && !was_under_pressure && notify {
+            warn!("Pressione sulla memoria: {:.0}% dei buffer del pool in uso", utilization * 100.0);
+            if let Some(callback) = &self.pressure_callback {
+                callback(stats);
+            }
+        }
+        self.under_pressure.store(above && (was_under_pressure || notify), Ordering::Relaxed);
+    }
+
+    /// Alloca un buffer azzerato di `size` byte il cui indirizzo è allineato a `align` byte,
+    /// per architetture embedded e istruzioni SIMD che richiedono allineamenti a 16, 32 o 64 byte.
+    ///
+    /// # Parametri
+    /// - `size`: La quantità di memoria da allocare in byte.
+    /// - `align`: L'allineamento richiesto, che deve essere una potenza di due.
+    ///
+    /// # Ritorna
+    /// - `Ok(AlignedBuffer)`: Il buffer allocato direttamente dall'allocatore globale.
+    /// - `Err(CoreError::ConfigurationError)`: Se la strategia di default è `CustomEmbedded` e `align`
+    ///   è diverso da `memory_config.required_alignment`.
+    /// - `Err(CoreError::AllocationTooLarge)`: Se `size` supera `memory_config.max_allocation_size`.
+    /// - `Err(CoreError::ResourceAllocationError)`: Se `align` non è valido o se la memoria non è disponibile.
+    ///
+    /// # Nota
+    /// Un `Box<[u8]>` verrebbe rilasciato con l'allineamento di `u8`, per questo il buffer è restituito
+    /// come `AlignedBuffer`, che conserva il layout dell'allocazione.
+    pub fn allocate_aligned(&mut self, size: usize, align: usize) -> Result<AlignedBuffer, CoreError> {
+        if let AllocationStrategy::CustomEmbedded = self.default_allocation_strategy {
+            if let Some(required) = self.memory_config.required_alignment {
+                if align != required {
+                    return Err(CoreError::ConfigurationError(
+                        format!("Allineamento di {} byte richiesto, la configurazione embedded impone {} byte", align, required), Vec::new()
+                    ));
+                }
+            }
+        }
+        Self::check_allocation_size(size, self.max_allocation_size())?;
+        let layout = Layout::from_size_align(size, align).map_err(|e| CoreError::ResourceAllocationError(
+            format!("Layout non valido per {} byte allineati a {}: {}", size, align, e), Vec::new()
+        ).with_context("MemoryManager::allocate_aligned"))?;
+
+        info!("Allocazione di {} byte allineati a {} byte...", size, align);
+        let buffer = AlignedBuffer::zeroed(layout).ok_or_else(|| CoreError::ResourceAllocationError(
+            format!("Memoria insufficiente per allocare {} byte allineati a {}", size, align), Vec::new()
+        ))?;
+
+        self.record_allocation(size);
+        for observer in self.observers.iter() {
+            observer.on_allocate(size, &self.default_allocation_strategy);
+        }
+        Ok(buffer)
+    }
+
+    /// Alloca un buffer azzerato di `size` byte su pagine huge, per ridurre la pressione sul TLB
+    /// nei carichi di inferenza con tensori di grandi dimensioni.
+    ///
+    /// Le pagine sono da `page_size` byte se la strategia di default è `HugePage`, altrimenti da
+    /// `HUGE_PAGE_SIZE`. Sulle piattaforme diverse da Linux il buffer viene allocato come `Standard`.
+    ///
+    /// # Ritorna
+    /// - `Ok(HugePageBuffer)`: Il buffer allocato.
+    /// - `Err(CoreError::AllocationTooLarge)`: Se `size` supera `memory_config.max_allocation_size`.
+    /// - `Err(CoreError::UnsupportedPlatform)`: Se su Linux le pagine huge non sono disponibili.
+    pub fn allocate_huge_page(&mut self, size: usize) -> Result<HugePageBuffer, CoreError> {
+        Self::check_allocation_size(size, self.max_allocation_size())?;
+        let page_size = match self.default_allocation_strategy {
+            AllocationStrategy::HugePage { page_size } => page_size,
+            _ => HUGE_PAGE_SIZE,
+        };
+
+        info!("Allocazione di {} byte su pagine huge da {} byte...", size, page_size);
+        #[cfg(target_os = "linux")]
+        let buffer = HugePageBuffer::map(size, page_size)?;
+        #[cfg(not(target_os = "linux"))]
+        let buffer = HugePageBuffer { buffer: self.allocate_standard(size)?.into_boxed_slice(), page_size };
+
+        self.record_allocation(size);
+        for observer in self.observers.iter() {
+            observer.on_allocate(size, &self.default_allocation_strategy);
+        }
+        Ok(buffer)
+    }
+
+    /// Verifica che una richiesta di `size` byte non superi il limite `max`.
+    ///
+    /// # Ritorna
+    /// `Ok(())`, oppure `CoreError::AllocationTooLarge` se `size > max`.
+    fn check_allocation_size(size: usize, max: usize) -> Result<(), CoreError> {
+        if size > max {
+            return Err(CoreError::AllocationTooLarge { requested: size, max, context: Vec::new() });
+        }
+        Ok(())
+    }
+
+    /// Limite di una singola allocazione per la strategia di default (vedi `MemoryConfig::max_allocation_size_for`).
+    fn max_allocation_size(&self) -> usize {
+        self.memory_config.max_allocation_size_for(&self.default_allocation_strategy)
+    }
+
+    /// Indica se la strategia è `GrowingPool` e il pool non ha ancora raggiunto `max_size` buffer.
+    fn can_grow(&self) -> bool {
+        match self.default_allocation_strategy {
+            AllocationStrategy::GrowingPool { max_size, .. } => self.counters.pool_capacity.load(Ordering::Relaxed) < max_size,
+            _ => false,
+        }
+    }
+
+    /// Aumenta la capacità del pool moltiplicandola per `growth_factor`, fino a `max_size` buffer.
+    ///
+    /// # Ritorna
+    /// Il numero di buffer aggiunti (0 se il pool ha già raggiunto `max_size`).
+    fn grow_pool(&self, state: &mut PoolState, max_size: usize, growth_factor: f32) -> usize {
+        let capacity = self.counters.pool_capacity.load(Ordering::Relaxed);
+        let grown = ((capacity as f64 * growth_factor as f64).ceil() as usize).max(capacity + 1);
+        let added = grown.min(max_size).saturating_sub(capacity);
+        if added == 0 {
+            return 0;
+        }
+        let buffers = Self::build_pool(&self.memory_config, added);
+        if let Some(ref mut pool) = state.pool {
+            pool.extend(buffers);
+        }
+        self.counters.pool_capacity.fetch_add(added, Ordering::Relaxed);
+        self.pool_slots.add_permits(added);
+        info!("Pool esteso da {} a {} buffer", capacity, capacity + added);
+        added
+    }
+
+    /// Alloca dinamicamente un buffer azzerato per la strategia `Standard`.
+    #[cfg(not(feature = "compact-retry"))]
+    fn allocate_standard(&self, size: usize) -> Result<ManagedBuffer, CoreError> {
+        Ok(ManagedBuffer::from(vec![0u8; size]))
+    }
+
+    /// Alloca dinamicamente un buffer azzerato per la strategia `Standard` tramite `HeapAllocator`.
+    ///
+    /// Se l'allocazione fallisce e `compact_and_retry` è attivo, la memoria viene compattata con
+    /// `defragment` e l'allocazione viene ritentata una sola volta.
+    #[cfg(feature = "compact-retry")]
+    fn allocate_standard(&mut self, size: usize) -> Result<ManagedBuffer, CoreError> {
+        if let Some(buffer) = self.heap.try_allocate(size) {
+            return Ok(ManagedBuffer::from(buffer));
+        }
+        if self.memory_config.compact_and_retry {
+            info!("Allocazione di {} byte fallita, compattazione della memoria e nuovo tentativo...", size);
+            self.defragment()?;
+            if let Some(buffer) = self.heap.try_allocate(size) {
+                return Ok(ManagedBuffer::from(buffer));
+            }
+        }
+        Err(CoreError::ResourceAllocationError(format!("Memoria insufficiente per allocare {} byte", size), Vec::new()).with_context("MemoryManager::allocate_standard"))
+    }
+
+    /// Variante asincrona di `allocate` per le strategie basate su pool che, a pool esaurito,
+    /// sospende il task chiamante finché un buffer non viene restituito, invece di allocare dinamicamente.
+    ///
+    /// Il pool è protetto dal `tokio::sync::Mutex` interno al gestore, che può quindi essere condiviso
+    /// tra i task (ad esempio dietro un `Arc`) senza un lock bloccante: l'attesa avviene sul semaforo
+    /// `pool_slots` e non blocca il thread del runtime. I metodi sincroni restano disponibili per i
+    /// target embedded che non utilizzano Tokio.
+    ///
+    /// # Ritorna
+    /// - `Ok(ManagedBuffer)`: Un buffer del pool, di `memory_config.buffer_size` byte.
+    /// - `Err(CoreError::UnsupportedOperationError)`: Se la strategia di default non è basata su pool.
+    /// - `Err(CoreError::AllocationTooLarge)`: Se `buffer_size` supera `memory_config.max_allocation_size`,
+    ///   senza attendere che un buffer si liberi.
+    ///
+    /// # Nota
+    /// Un permesso ricevuto quando il pool è già stato svuotato da un'allocazione sincrona
+    /// provoca solo un nuovo tentativo.
+    #[cfg(feature = "async")]
+    pub async fn allocate_async(&self) -> Result<ManagedBuffer, CoreError> {
+        let context = "MemoryManager::allocate_async";
+        if !self.default_allocation_strategy.is_pool_based() {
+            return Err(CoreError::UnsupportedOperationError(
+                "Allocazione asincrona disponibile solo per le strategie basate su pool".to_string(), Vec::new()
+            ).with_context(context));
+        }
+        Self::check_allocation_size(self.memory_config.buffer_size, self.max_allocation_size())?;
+
+        let mut permit_taken = false;
+        loop {
+            let buffer = {
+                let mut state = self.lock_state_async().await;
+                self.initialize_pool(&mut state);
+                if state.pool.is_none() {
+                    return Err(CoreError::ResourceAllocationError("Pool non disponibile".to_string(), Vec::new()).with_context(context));
+                }
+                self.take_pooled(&mut state, &self.default_allocation_strategy)
+            };
+            if let Some(buffer) = buffer {
+                // Il permesso ottenuto in attesa corrisponde già al buffer prelevato
+                if !permit_taken {
+                    if let Ok(permit) = self.pool_slots.try_acquire() {
+                        permit.forget();
+                    }
+                }
+                self.finish_allocation(&buffer, &self.default_allocation_strategy);
+                return Ok(buffer);
+            }
+            // Pool vuoto: si attende la restituzione di un buffer e si ritenta
+            self.pool_slots
+                .acquire()
+                .await
+                .map_err(|e| CoreError::ResourceAllocationError(e.to_string(), Vec::new()).with_context(context))?
+                .forget();
+            permit_taken = true;
+        }
+    }
+
+    /// Variante asincrona di `deallocate`: restituisce il buffer al pool e risveglia un task
+    /// in attesa in `allocate_async`.
+    ///
+    /// # Ritorna
+    /// `Ok(())`, oppure `CoreError::ResourceAllocationError` se il pool non è disponibile.
+    #[cfg(feature = "async")]
+    pub async fn deallocate_async(&self, mut buffer: ManagedBuffer) -> Result<(), CoreError> {
+        info!("Deallocazione asincrona della memoria...");
+        self.begin_deallocation(&mut buffer);
+        // Il rilascio del buffer lo restituisce al pool statico
+        #[cfg(feature = "embedded")]
+        if buffer.is_embedded() {
+            return Ok(());
+        }
+        if !self.default_allocation_strategy.is_pool_based() {
+            // Con le strategie senza pool Rust dealloca automaticamente la memoria.
+            return Ok(());
+        }
+        let result = {
+            let mut state = self.lock_state_async().await;
+            self.return_pooled(&mut state, buffer)
+        };
+        self.sync_free_buffers();
+        self.check_memory_pressure(false);
+        result
+    }
+
+    /// Numero totale di byte liberi gestiti dalla strategia corrente.
+    ///
+    /// - `PoolBased`: byte dei buffer liberi nel pool.
+    /// - `CustomEmbedded`: byte liberi del buffer statico condiviso dai gestori (solo con la feature `embedded`).
+    /// - `Standard`: sempre 0, la memoria è gestita dall'allocatore di sistema.
+    pub fn total_free_bytes(&self) -> usize {
+        match self.default_allocation_strategy {
+            AllocationStrategy::PoolBased | AllocationStrategy::GrowingPool { .. } | AllocationStrategy::DoubleBuffered => {
+                self.lock_state().free_buffers() * self.memory_config.buffer_size
+            },
+            #[cfg(feature = "embedded")]
+            AllocationStrategy::CustomEmbedded => EMBEDDED_POOL.total_free_bytes(),
+            _ => 0,
+        }
+    }
+
+    /// Dimensione in byte della più grande area libera contigua della strategia corrente.
+    ///
+    /// Nelle strategie a pool è la più lunga sequenza di buffer liberi i cui indirizzi sono
+    /// adiacenti in memoria, indipendentemente dalla loro posizione nel pool.
+    pub fn largest_free_contiguous_bytes(&self) -> usize {
+        match self.default_allocation_strategy {
+            AllocationStrategy::PoolBased | AllocationStrategy::GrowingPool { .. } | AllocationStrategy::DoubleBuffered => {
+                let buffer_size = self.memory_config.buffer_size;
+                let mut areas: Vec<(usize, usize)> = self.lock_state().pool
+                    .iter()
+                    .flatten()
+                    .map(|buffer| (buffer.as_ptr() as usize, buffer_size))
+                    .collect();
+                areas.sort_unstable();
+                buddy_allocator::largest_contiguous_run(&areas)
+            },
+            #[cfg(feature = "embedded")]
+            AllocationStrategy::CustomEmbedded => EMBEDDED_POOL.largest_free_contiguous_bytes(),
+            _ => 0,
+        }
+    }
+
+    /// Frammentazione della memoria libera: `1.0 - (largest_free_contiguous_bytes / total_free_bytes)`.
+    ///
+    /// - `PoolBased`: `1.0 - (buffer liberi contigui più lunga sequenza / buffer liberi)`, vedi
+    ///   `largest_free_contiguous_bytes`. I buffer del pool sono ricavati da aree contigue, pertanto un pool
+    ///   interamente libero non è frammentato mentre buffer liberi alternati a buffer in uso lo sono.
+    /// - `CustomEmbedded`: calcolata sulle regioni libere del buffer statico.
+    /// - `Standard`: sempre `0.0`, la frammentazione dell'allocatore di sistema non è nota.
+    ///
+    /// Per un allocatore buddy system vedi `BuddyAllocator::fragmentation_ratio`.
+    pub fn fragmentation_ratio(&self) -> f64 {
+        buddy_allocator::fragmentation_ratio(self.largest_free_contiguous_bytes(), self.total_free_bytes())
+    }
+
+    /// Indica se il chiamante dovrebbe cedere il controllo al runtime asincrono perché il pool è sotto pressione.
+    ///
+    /// Pensato per i percorsi che allocano in un ciclo stretto: quando restituisce `true` il chiamante
+    /// dovrebbe eseguire `tokio::task::yield_now().await`, così da non affamare gli altri task cooperativi.
+    ///
+    /// # Parametri
+    /// - `threshold`: Frazione di buffer liberi (tra `0.0` e `1.0`) sotto la quale il pool è considerato sotto pressione.
+    ///
+    /// # Ritorna
+    /// `true` se la frazione di buffer liberi del pool è inferiore a `threshold`; sempre `false`
+    /// per le strategie diverse da `PoolBased` o se il pool non è ancora stato inizializzato.
+    pub fn yield_if_under_pressure(&self, threshold: f64) -> bool {
+        if !self.default_allocation_strategy.is_pool_based() {
+            return false;
+        }
+        let capacity = self.counters.pool_capacity.load(Ordering::Relaxed);
+        if capacity == 0 {
+            return false;
+        }
+        let free = self.counters.free_buffers.load(Ordering::Relaxed);
+        (free as f64 / capacity as f64) < threshold
+    }
+
+    /// Presta temporaneamente fino a `count` buffer liberi del pool al gestore `recipient`.
+    ///
+    /// I buffer vengono spostati nel pool del destinatario, la cui capacità aumenta di conseguenza,
+    /// e possono essere restituiti con `recall`.
+    ///
+    /// # Ritorna
+    /// - `Ok(usize)`: Il numero di buffer effettivamente prestati (limitato dai buffer liberi).
+    /// - `Err(CoreError)`: Se uno dei due gestori non usa la strategia `PoolBased` o la dimensione dei buffer differisce.
+    pub fn lend(&mut self, count: usize, recipient: &mut MemoryManager) -> Result<usize, CoreError> {
+        Self::check_lending(self, recipient)?;
+        let moved = Self::move_buffers(self, recipient, count);
+        self.lent_buffers += moved;
+        recipient.borrowed_buffers += moved;
+        info!("Prestati {} buffer del pool", moved);
+        Ok(moved)
+    }
+
+    /// Restituisce al gestore `lender` fino a `count` buffer ricevuti in prestito con `lend`.
+    ///
+    /// Vengono restituiti solo buffer liberi: quelli ancora allocati restano al gestore corrente
+    /// finché non vengono deallocati.
+    ///
+    /// # Ritorna
+    /// - `Ok(usize)`: Il numero di buffer effettivamente restituiti.
+    /// - `Err(CoreError)`: Se uno dei due gestori non usa la strategia `PoolBased` o la dimensione dei buffer differisce.
+    pub fn recall(&mut self, lender: &mut MemoryManager, count: usize) -> Result<usize, CoreError> {
+        Self::check_lending(lender, self)?;
+        let count = count.min(self.borrowed_buffers).min(lender.lent_buffers);
+        let moved = Self::move_buffers(self, lender, count);
+        self.borrowed_buffers -= moved;
+        lender.lent_buffers -= moved;
+        info!("Restituiti {} buffer del pool", moved);
+        Ok(moved)
+    }
+
+    /// Verifica che due gestori possano scambiarsi buffer del pool.
+    fn check_lending(lender: &mut MemoryManager, recipient: &mut MemoryManager) -> Result<(), CoreError> {
+        let pool_based = |m: &mut MemoryManager| m.default_allocation_strategy.is_pool_based() && m.state.get_mut().pool.is_some();
+        if !pool_based(&mut *lender) || !pool_based(&mut *recipient) {
+            return Err(CoreError::UnsupportedOperationError("Prestito disponibile solo per la strategia PoolBased".to_string(), Vec::new()).with_context("MemoryManager::check_lending"));
+        }
+        if lender.memory_config.buffer_size != recipient.memory_config.buffer_size {
+            return Err(CoreError::ConfigurationError(format!(
+                "Dimensione dei buffer incompatibile: {} e {}", lender.memory_config.buffer_size, recipient.memory_config.buffer_size
+            ), Vec::new()).with_context("MemoryManager::check_lending"));
+        }
+        // Con `lazy_pool` i buffer vanno creati prima dello scambio, altrimenti verrebbero duplicati
+        lender.ensure_pool_initialized();
+        recipient.ensure_pool_initialized();
+        Ok(())
+    }
+
+    /// Sposta fino a `count` buffer liberi da `from` a `to`, aggiornando capacità e contatori.
+    fn move_buffers(from: &mut MemoryManager, to: &mut MemoryManager, count: usize) -> usize {
+        let (source, target) = match (from.state.get_mut().pool.as_mut(), to.state.get_mut().pool.as_mut()) {
+            (Some(source), Some(target)) => (source, target),
+            _ => return 0,
+        };
+        let moved = count.min(source.len());
+        // Il gestore di destinazione può non proteggere i buffer liberi: le pagine vanno rese accessibili
+        #[cfg(all(target_os = "linux", feature = "lazy-zero"))]
+        if from.memory_config.zeroing_policy.protect_free_buffers() {
+            source.iter_mut().take(moved).for_each(|buffer| lazy_zero::unprotect(buffer));
+        }
+        target.extend(source.drain(..moved));
+        to.pool_slots.add_permits(moved);
+
+        from.counters.pool_capacity.fetch_sub(moved, Ordering::Relaxed);
+        to.counters.pool_capacity.fetch_add(moved, Ordering::Relaxed);
+        from.sync_free_buffers();
+        to.sync_free_buffers();
+        moved
+    }
+
+    /// Trasferisce un buffer allocato da `source` al gestore `dest`.
+    ///
+    /// Se i due gestori usano la stessa `buffer_size` e il buffer ha esattamente quella dimensione,
+    /// il buffer passa a `dest` senza copia: per `source` conta come deallocato e per `dest` come allocato,
+    /// e la capacità dei pool (inclusi i permessi di `allocate_async`) segue il buffer, che alla
+    /// deallocazione tornerà nel pool di `dest`. Altrimenti, con
+    /// `MemoryConfig::copy_on_transfer` di `dest` attivo, il contenuto viene copiato in un nuovo
+    /// buffer di `dest` e il buffer originale restituito a `source`.
+    ///
+    /// # Parametri
+    /// - `buffer`: Il buffer da trasferire, allocato da `source`.
+    /// - `source`: Il gestore che ha allocato il buffer.
+    /// - `dest`: Il gestore che ne diventa proprietario.
+    ///
+    /// # Ritorna
+    /// - `Ok(ManagedBuffer)`: Il buffer di `dest`, con lo stesso contenuto di `buffer`.
+    /// - `Err(CoreError::TransferSizeMismatch)`: Se le dimensioni differiscono e `dest` non ammette la copia;
+    ///   l'errore contiene il buffer, intatto e ancora allocato da `source`.
+    /// - `Err(CoreError)`: Se l'allocazione in `dest` o la deallocazione da `source` falliscono.
+    ///
+    /// # Nota
+    /// Il buffer è un `ManagedBuffer`, come quello restituito da `allocate`, così che un buffer del pool
+    /// possa passare a `dest` senza copia; per i buffer gestiti come `Box<[u8]>` è disponibile `transfer_boxed`.
+    pub fn transfer(buffer: ManagedBuffer, source: &mut MemoryManager, dest: &mut MemoryManager) -> Result<ManagedBuffer, CoreError> {
+        let (src, dst) = (source.memory_config.buffer_size, dest.memory_config.buffer_size);
+        // Solo un buffer della dimensione dei pool può entrare nel pool di `dest` senza copia
+        if src != dst || buffer.len() != dst {
+            if !dest.memory_config.copy_on_transfer {
+                return Err(CoreError::TransferSizeMismatch { src: buffer.len(), dst, buffer, context: Vec::new() }
+                    .with_context("MemoryManager::transfer"));
+            }
+            // I buffer del pool di `dest` potrebbero non contenere i dati: si ricorre all'allocatore di sistema
+            let strategy = if dest.default_allocation_strategy.is_pool_based() && buffer.len() > dst {
+                Some(AllocationStrategy::Standard)
+            } else {
+                None
+            };
+            let mut copy = dest.allocate(strategy, buffer.len())?;
+            let len = buffer.len().min(copy.len());
+            copy[..len].copy_from_slice(&buffer[..len]);
+            source.deallocate(buffer)?;
+            info!("Trasferiti {} byte con copia tra buffer da {} e {} byte", len, src, dst);
+            return Ok(copy);
+        }
+
+        source.counters.total_deallocations.fetch_add(1, Ordering::Relaxed);
+        for observer in source.observers.iter() {
+            observer.on_deallocate(buffer.len());
+        }
+        #[cfg(feature = "tracking")]
+        source.record_buffer_event(BufferEvent::Deallocate(buffer.as_ptr() as usize));
+        if source.default_allocation_strategy.is_pool_based() {
+            source.counters.pool_capacity.fetch_sub(1, Ordering::Relaxed);
+            let address = buffer.as_ptr() as usize;
+            let state = source.state.get_mut();
+            state.active_in_use.remove(&address);
+            state.standby_in_use.remove(&address);
+        }
+        if dest.default_allocation_strategy.is_pool_based() {
+            dest.ensure_pool_initialized();
+            dest.counters.pool_capacity.fetch_add(1, Ordering::Relaxed);
+            dest.sync_pool_slots();
+        }
+        if source.default_allocation_strategy.is_pool_based() {
+            source.sync_pool_slots();
+        }
+        source.sync_free_buffers();
+        source.check_memory_pressure(false);
+
+        dest.finish_allocation(&buffer, &dest.default_allocation_strategy);
+        info!("Trasferito senza copia un buffer da {} byte", buffer.len());
+        Ok(buffer)
+    }
+
+    /// Variante di `transfer` per i buffer gestiti come `Box<[u8]>`.
+    ///
+    /// Il `Box<[u8]>` non appartiene ai pool: senza copia passa a `dest` e viene restituito così com'è,
+    /// aggiornando solo contatori e osservatori dei due gestori. Con la copia il contenuto viene copiato
+    /// in un buffer di `dest` da `buffer.len()` byte; se questo proviene dal pool di `dest` viene a sua
+    /// volta copiato in un `Box<[u8]>` e torna al pool.
+    ///
+    /// # Ritorna
+    /// - `Ok(Box<[u8]>)`: Il buffer di `dest`, con lo stesso contenuto di `buffer`.
+    /// - `Err(CoreError::TransferSizeMismatch)`: Se le dimensioni differiscono e `dest` non ammette la copia.
+    /// - `Err(CoreError)`: Se l'allocazione in `dest` fallisce.
+    pub fn transfer_boxed(buffer: Box<[u8]>, source: &mut MemoryManager, dest: &mut MemoryManager) -> Result<Box<[u8]>, CoreError> {
+        let dst = dest.memory_config.buffer_size;
+        if source.memory_config.buffer_size != dst || buffer.len() != dst {
+            if !dest.memory_config.copy_on_transfer {
+                return Err(CoreError::TransferSizeMismatch { src: buffer.len(), dst, buffer: ManagedBuffer::from(buffer), context: Vec::new() }
+                    .with_context("MemoryManager::transfer_boxed"));
+            }
+            let copy = dest.resized_copy(&buffer, buffer.len())?;
+            source.release_boxed(&buffer);
+            info!("Trasferiti {} byte con copia tra buffer da {} e {} byte", buffer.len(), source.memory_config.buffer_size, dst);
+            return dest.boxed_copy(copy);
+        }
+
+        source.release_boxed(&buffer);
+        let buffer = ManagedBuffer::from(buffer);
+        dest.finish_allocation(&buffer, &dest.default_allocation_strategy);
+        info!("Trasferito senza copia un buffer da {} byte", buffer.len());
+        Ok(buffer.into_boxed_slice())
+    }
+
+    /// Salva su file il contenuto dei buffer liberi del pool.
+    ///
+    /// Il formato è composto dall'intestazione `ARXP`, dalla dimensione dei buffer e dal loro numero
+    /// (entrambi `u64` little endian), seguiti dal contenuto dei buffer nell'ordine del pool.
+    ///
+    /// # Ritorna
+    /// `Ok(())` oppure `CoreError::UnsupportedOperationError` se la strategia non è `PoolBased`.
+    pub fn freeze(&self, path: &Path) -> Result<(), CoreError> {
+        let context = "MemoryManager::freeze";
+        let file = File::create(path)
+            .map_err(|e| CoreError::GenericError(format!("Creazione di {:?} fallita: {}", path, e), Vec::new()).with_context(context))?;
+        let mut writer = io::BufWriter::new(file);
+        self.write_pool(&mut writer).map_err(|e| e.with_context(context))?;
+        writer.flush().map_err(|e| CoreError::GenericError(e.to_string(), Vec::new()).with_context(context))
+    }
+
+    /// Ripristina i buffer del pool da un file prodotto da `freeze`, sostituendo quelli liberi.
+    pub fn thaw(&mut self, path: &Path) -> Result<(), CoreError> {
+        let context = "MemoryManager::thaw";
+        let file = File::open(path)
+            .map_err(|e| CoreError::GenericError(format!("Apertura di {:?} fallita: {}", path, e), Vec::new()).with_context(context))?;
+        self.read_pool(&mut io::BufReader::new(file)).map_err(|e| e.with_context(context))
+    }
+
+    /// Come `freeze`, ma comprime i dati con zstd al livello indicato.
+    #[cfg(feature = "compression")]
+    pub fn freeze_compressed(&self, path: &Path, level: i32) -> Result<(), CoreError> {
+        let context = "MemoryManager::freeze_compressed";
+        let file = File::create(path)
+            .map_err(|e| CoreError::GenericError(format!("Creazione di {:?} fallita: {}", path, e), Vec::new()).with_context(context))?;
+        let mut encoder = zstd::Encoder::new(file, level)
+            .map_err(|e| CoreError::GenericError(e.to_string(), Vec::new()).with_context(context))?;
+        self.write_pool(&mut encoder).map_err(|e| e.with_context(context))?;
+        encoder.finish().map_err(|e| CoreError::GenericError(e.to_string(), Vec::new()).with_context(context))?;
+        Ok(())
+    }
+
+    /// Ripristina i buffer del pool da un file prodotto da `freeze_compressed`.
+    #[cfg(feature = "compression")]
+    pub fn thaw_compressed(&mut self, path: &Path) -> Result<(), CoreError> {
+        let context = "MemoryManager::thaw_compressed";
+        let file = File::open(path)
+            .map_err(|e| CoreError::GenericError(format!("Apertura di {:?} fallita: {}", path, e), Vec::new()).with_context(context))?;
+        let mut decoder = zstd::Decoder::new(file)
+            .map_err(|e| CoreError::GenericError(e.to_string(), Vec::new()).with_context(context))?;
+        self.read_pool(&mut decoder).map_err(|e| e.with_context(context))
+    }
+
+    /// Produce un'istantanea in memoria della strategia, della configurazione e dei buffer liberi del pool.
+    ///
+    /// # Nota
+    /// Le allocazioni in corso, non ancora restituite con `deallocate`, non vengono incluse.
+    pub fn snapshot(&self) -> MemorySnapshot {
+        let state = self.lock_state();
+        let buffers = match &state.pool {
+            // Con la protezione dei buffer liberi i buffer liberi sono protetti e verranno azzerati all'allocazione
+            Some(pool) if self.default_allocation_strategy.is_pool_based() => pool
+                .iter()
+                .map(|buffer| if self.memory_config.zeroing_policy.protect_free_buffers() { vec![0u8; buffer.len()] } else { buffer.to_vec() })
+                .collect(),
+            _ => Vec::new(),
+        };
+        info!("Istantanea del MemoryManager con {} buffer liberi", buffers.len());
+        MemorySnapshot {
+            strategy: self.default_allocation_strategy.clone(),
+            buffers,
+            config: self.memory_config.clone(),
+        }
+    }
+
+    /// Crea un gestore della memoria a partire da un'istantanea prodotta da `snapshot`.
+    ///
+    /// Il pool del gestore ripristinato contiene esattamente i buffer dell'istantanea, con il loro contenuto.
+    ///
+    /// # Ritorna
+    /// Il `MemoryManager` ripristinato, oppure `CoreError::ConfigurationError` se la strategia non è
+    /// valida o un buffer ha una dimensione diversa da `config.buffer_size`.
+    pub fn restore_from_snapshot(snapshot: MemorySnapshot) -> Result<Self, CoreError> {
+        let MemorySnapshot { strategy, buffers, mut config } = snapshot;
+        if let Some(buffer) = buffers.iter().find(|buffer| buffer.len() != config.buffer_size) {
+            return Err(CoreError::ConfigurationError(format!(
+                "Dimensione di un buffer dell'istantanea ({}) diversa da quella configurata ({})", buffer.len(), config.buffer_size
+            ), Vec::new()).with_context("MemoryManager::restore_from_snapshot"));
+        }
+        // Il pool viene sostituito dai buffer dell'istantanea: non serve allocarlo
+        let lazy_pool = config.lazy_pool;
+        config.lazy_pool = true;
+        let mut manager = Self::with_strategy(strategy, config)?;
+        manager.memory_config.lazy_pool = lazy_pool;
+
+        if manager.default_allocation_strategy.is_pool_based() {
+            let count = buffers.len();
+            let mut pool = Self::build_pool(&manager.memory_config, count);
+            for (buffer, contents) in pool.iter_mut().zip(buffers) {
+                buffer.copy_from_slice(&contents);
+            }
+            manager.state.get_mut().pool = Some(pool);
+            manager.pool_slots = Arc::new(Semaphore::new(count));
+            manager.counters.pool_capacity.store(count, Ordering::Relaxed);
+            manager.sync_free_buffers();
+            info!("Ripristinati {} buffer dall'istantanea", count);
+        }
+        manager.state.get_mut().initialized = true;
+        Ok(manager)
+    }
+
+    /// Scrive i buffer liberi del pool nel formato di `freeze`.
+    fn write_pool<W: Write>(&self, writer: &mut W) -> Result<(), CoreError> {
+        let state = self.lock_state();
+        let pool = match (&self.default_allocation_strategy, &state.pool) {
+            (strategy, Some(pool)) if strategy.is_pool_based() => pool,
+            _ => return Err(CoreError::UnsupportedOperationError("Istantanea disponibile solo per la strategia PoolBased".to_string(), Vec::new()).with_context("MemoryManager::write_pool")),
+        };
+        let io_error = |e: io::Error| CoreError::GenericError(format!("Scrittura del pool fallita: {}", e), Vec::new()).with_context("write_pool");
+
+        writer.write_all(FREEZE_MAGIC).map_err(io_error)?;
+        writer.write_all(&(self.memory_config.buffer_size as u64).to_le_bytes()).map_err(io_error)?;
+        writer.write_all(&(pool.len() as u64).to_le_bytes()).map_err(io_error)?;
+        let protected_zeroes = vec![0u8; self.memory_config.buffer_size];
+        for buffer in pool.iter() {
+            // Con la protezione dei buffer liberi i buffer liberi sono protetti e verranno azzerati all'allocazione
+            let contents: &[u8] = if self.memory_config.zeroing_policy.protect_free_buffers() { &protected_zeroes[..buffer.len()] } else { &buffer[..] };
+            writer.write_all(contents).map_err(io_error)?;
+        }
+        info!("Salvati {} buffer del pool", pool.len());
+        Ok(())
+    }
+
+    /// Legge i buffer nel formato di `freeze` sostituendo quelli liberi del pool.
+    fn read_pool<R: Read>(&mut self, reader: &mut R) -> Result<(), CoreError> {
+        if !self.default_allocation_strategy.is_pool_based() {
+            return Err(CoreError::UnsupportedOperationError("Istantanea disponibile solo per la strategia PoolBased".to_string(), Vec::new()).with_context("MemoryManager::read_pool"));
+        }
+        let io_error = |e: io::Error| CoreError::GenericError(format!("Lettura del pool fallita: {}", e), Vec::new()).with_context("read_pool");
+
+        let mut magic = [0u8; 4];
+        reader.read_exact(&mut magic).map_err(io_error)?;
+        if &magic != FREEZE_MAGIC {
+            return Err(CoreError::GenericError("Formato dell'istantanea del pool non valido".to_string(), Vec::new()).with_context("MemoryManager::read_pool"));
+        }
+        let mut word = [0u8; 8];
+        reader.read_exact(&mut word).map_err(io_error)?;
+        let buffer_size = u64::from_le_bytes(word) as usize;
+        reader.read_exact(&mut word).map_err(io_error)?;
+        let count = u64::from_le_bytes(word) as usize;
+        if buffer_size != self.memory_config.buffer_size {
+            return Err(CoreError::ConfigurationError(format!(
+                "Dimensione dei buffer dell'istantanea ({}) diversa da quella configurata ({})", buffer_size, self.memory_config.buffer_size
+            ), Vec::new()).with_context("MemoryManager::read_pool"));
+        }
+
+        // Il numero di buffer dell'intestazione non è attendibile: il contenuto viene letto man mano
+        // che arriva e il pool viene creato solo dopo averne verificato la lunghezza
+        let total = match count.checked_mul(buffer_size) {
+            Some(total) if buffer_size > 0 => total,
+            _ => return Err(CoreError::GenericError(format!(
+                "Numero di buffer dell'istantanea non valido: {}", count
+            ), Vec::new()).with_context("MemoryManager::read_pool")),
+        };
+        let mut contents = Vec::new();
+        reader.by_ref().take(total as u64).read_to_end(&mut contents).map_err(io_error)?;
+        if contents.len() != total {
+            return Err(CoreError::GenericError(format!(
+                "Istantanea del pool troncata: letti {} byte su {}", contents.len(), total
+            ), Vec::new()).with_context("MemoryManager::read_pool"));
+        }
+        let mut buffers = Self::build_pool(&self.memory_config, count);
+        for (buffer, chunk) in buffers.iter_mut().zip(contents.chunks_exact(buffer_size)) {
+            buffer.copy_from_slice(chunk);
+        }
+
+        // I buffer sostituiti tornano all'allocatore di sistema: le loro pagine vanno rese di nuovo accessibili
+        #[cfg(all(target_os = "linux", feature = "lazy-zero"))]
+        self.release_protected_pages();
+
+        // I buffer in uso restano del gestore e, alla deallocazione, entrano nel nuovo pool
+        let state = self.state.get_mut();
+        let in_use = self.counters.pool_capacity.load(Ordering::Relaxed).saturating_sub(state.free_buffers());
+        state.pool = Some(buffers);
+        state.initialized = true;
+        // Come in `restore_from_snapshot` il semaforo viene sostituito: un permesso per ogni buffer libero
+        self.pool_slots = Arc::new(Semaphore::new(count));
+        self.counters.pool_capacity.store(count + in_use, Ordering::Relaxed);
+        self.sync_free_buffers();
+        info!("Ripristinati {} buffer del pool", count);
+        Ok(())
+    }
+
+    /// Suggerisce al sistema operativo di caricare in anticipo le pagine dei buffer indicati.
+    ///
+    /// Per la strategia `PoolBased` gli ID corrispondono alla posizione dei buffer liberi nel pool
+    /// (il prossimo buffer restituito da `allocate` ha ID 0) e per ciascuno viene chiamata
+    /// `madvise(MADV_WILLNEED)` sulle pagine che lo contengono. Per le altre strategie non ha effetto.
+    ///
+    /// # Ritorna
+    /// `Ok(())` oppure `CoreError::ResourceAllocationError` se un ID non corrisponde ad un buffer
+    /// del pool o la chiamata di sistema fallisce.
+    #[cfg(target_os = "linux")]
+    pub fn prefetch(&self, buffer_ids: &[u64]) -> Result<(), CoreError> {
+        let state = self.lock_state();
+        let pool = match (&self.default_allocation_strategy, &state.pool) {
+            (strategy, Some(pool)) if strategy.is_pool_based() => pool,
+            _ => return Ok(()),
+        };
+
+        // SAFETY: sysconf non ha precondizioni.
+        let page_size = unsafe { libc::sysconf(libc::_SC_PAGESIZE) } as usize;
+        for id in buffer_ids {
+            let buffer = pool
+                .get(*id as usize)
+                .ok_or_else(|| CoreError::ResourceAllocationError(format!("Buffer {} non presente nel pool", id), Vec::new()).with_context("MemoryManager::prefetch"))?;
+            if buffer.is_empty() {
+                continue;
+            }
+            // madvise richiede un indirizzo allineato alla pagina
+            let start = buffer.as_ptr() as usize;
+            let aligned = start - start % page_size;
+            let len = start + buffer.len() - aligned;
+            // SAFETY: l'intervallo copre solo pagine che contengono il buffer, di proprietà del pool;
+            // MADV_WILLNEED è un suggerimento e non modifica il contenuto della memoria.
+            let result = unsafe { libc::madvise(aligned as *mut libc::c_void, len, libc::MADV_WILLNEED) };
+            if result != 0 {
+                return Err(CoreError::ResourceAllocationError(format!(
+                    "madvise fallita per il buffer {}: {}", id, io::Error::last_os_error()
+                ), Vec::new()).with_context("MemoryManager::prefetch"));
+            }
+        }
+        Ok(())
+    }
+
+    /// Imposta la politica di azzeramento dei buffer.
+    ///
+    /// Le nuove allocazioni dinamiche sono sempre azzerate per non esporre memoria non inizializzata;
+    /// la politica controlla l'azzeramento dei buffer riutilizzati dal pool e di quelli deallocati.
+    pub fn zeroing_policy(&mut self, policy: ZeroingPolicy) {
+        info!("Politica di azzeramento impostata a {:?}", policy);
+        // Senza protezione dei buffer liberi `allocate` non rimuove più la protezione dei buffer liberi
+        #[cfg(all(target_os = "linux", feature = "lazy-zero"))]
+        if self.memory_config.zeroing_policy.protect_free_buffers() && !policy.protect_free_buffers() {
+            self.release_protected_pages();
+        }
+        self.memory_config.zeroing_policy = policy;
+    }
+
+    /// Sostituisce l'allocatore dell'heap utilizzato dalla strategia `Standard`.
+    #[cfg(feature = "compact-retry")]
+    pub fn set_heap_allocator(&mut self, allocator: Arc<dyn HeapAllocator>) {
+        self.heap = allocator;
+    }
+
+    /// Assegna al gestore un proprio `MemoryRegistry`, al posto del registro predefinito.
+    ///
+    /// Gli store creati con `with_registry(manager.registry())` operano solo sulle mappe di questo registro.
+    #[cfg(feature = "crud")]
+    pub fn use_registry(&mut self, registry: Arc<MemoryRegistry>) {
+        self.registry = registry;
+    }
+
+    /// Registro delle strutture CRUD del gestore: `DEFAULT_REGISTRY` se non ne è stato assegnato uno con `use_registry`.
+    #[cfg(feature = "crud")]
+    pub fn registry(&self) -> Arc<MemoryRegistry> {
+        Arc::clone(&self.registry)
+    }
+
+    /// Associa al gestore un dato applicativo, indicizzato per tipo, senza dover estendere la struttura.
+    ///
+    /// # Ritorna
+    /// Il valore dello stesso tipo precedentemente associato, se presente.
+    ///
+    /// # Nota
+    /// Il dato deve essere `Sync`, così che il gestore possa essere condiviso tra i task con `allocate_async`.
+    pub fn insert_extension<T: 'static + Send + Sync>(&mut self, value: T) -> Option<T> {
+        self.extensions
+            .insert(TypeId::of::<T>(), Box::new(value))
+            .and_then(|previous| previous.downcast::<T>().ok())
+            .map(|previous| *previous)
+    }
+
+    /// Restituisce il dato applicativo di tipo `T` associato con `insert_extension`, se presente.
+    pub fn get_extension<T: 'static>(&self) -> Option<&T> {
+        self.extensions.get(&TypeId::of::<T>()).and_then(|value| value.downcast_ref::<T>())
+    }
+
+    /// Variante mutabile di `get_extension`.
+    pub fn get_extension_mut<T: 'static>(&mut self) -> Option<&mut T> {
+        self.extensions.get_mut(&TypeId::of::<T>()).and_then(|value| value.downcast_mut::<T>())
+    }
+
+    /// Registra un osservatore delle operazioni del `MemoryManager`.
+    ///
+    /// È possibile registrare più osservatori, notificati nell'ordine di registrazione.
+    pub fn observe(&mut self, observer: Arc<dyn MemoryObserver>) {
+        self.observers.push(observer);
+    }
+
+    /// Ridimensiona un buffer mantenendone il contenuto.
+    ///
+    /// Alloca un nuovo buffer di `new_size` byte, copia `min(old_size, new_size)` byte dal buffer
+    /// originale e lo dealloca. I byte oltre la dimensione originale restano azzerati.
+    ///
+    /// # Parametri
+    /// - `old_buf`: Il buffer da ridimensionare.
+    /// - `new_size`: La nuova dimensione in byte.
+    ///
+    /// # Ritorna
+    /// Il nuovo buffer oppure un errore di tipo `CoreError` in caso di fallimento.
+    ///
+    /// # Nota
+    /// - Nella strategia `PoolBased` il nuovo buffer proviene dal pool solo se `new_size`
+    ///   coincide con `buffer_size`, altrimenti viene allocato dinamicamente.
+    /// - Il buffer è un `ManagedBuffer`, come quello restituito da `allocate`; per i buffer
+    ///   gestiti come `Box<[u8]>` è disponibile `resize_boxed_buffer`.
+    pub fn resize_buffer(&mut self, old_buf: ManagedBuffer, new_size: usize) -> Result<ManagedBuffer, CoreError> {
+        info!("Ridimensionamento di un buffer da {} a {} byte...", old_buf.len(), new_size);
+        let new_buf = self.resized_copy(&old_buf, new_size)?;
+        self.deallocate(old_buf)?;
+        Ok(new_buf)
+    }
+
+    /// Variante di `resize_buffer` per i buffer gestiti come `Box<[u8]>`.
+    ///
+    /// # Ritorna
+    /// Il nuovo buffer oppure un errore di tipo `CoreError` in caso di fallimento.
+    ///
+    /// # Nota
+    /// Il buffer originale non appartiene al pool e viene rilasciato senza restituirlo. Se il nuovo
+    /// buffer proviene dal pool il suo contenuto viene copiato in un `Box<[u8]>` e il buffer torna al pool.
+    pub fn resize_boxed_buffer(&mut self, old_buf: Box<[u8]>, new_size: usize) -> Result<Box<[u8]>, CoreError> {
+        info!("Ridimensionamento di un buffer da {} a {} byte...", old_buf.len(), new_size);
+        let new_buf = self.resized_copy(&old_buf, new_size)?;
+        self.release_boxed(&old_buf);
+        self.boxed_copy(new_buf)
+    }
+
+    /// Alloca un buffer di `new_size` byte azzerato e vi copia `min(old_buf.len(), new_size)` byte di `old_buf`.
+    ///
+    /// Con le strategie basate su pool il buffer proviene dal pool solo se `new_size` coincide con `buffer_size`.
+    fn resized_copy(&mut self, old_buf: &[u8], new_size: usize) -> Result<ManagedBuffer, CoreError> {
+        let mut new_buf = if self.default_allocation_strategy.is_pool_based() && new_size != self.memory_config.buffer_size {
+            self.allocate(Some(AllocationStrategy::Standard), new_size)?
+        } else {
+            self.allocate(None, new_size)?
+        };
+        // I buffer del pool vengono riutilizzati: azzera il contenuto prima della copia
+        new_buf.fill(0);
+
+        let copied = old_buf.len().min(new_buf.len());
+        new_buf[..copied].copy_from_slice(&old_buf[..copied]);
+        Ok(new_buf)
+    }
+
+    /// Conta come deallocato un `Box<[u8]>` che non appartiene al pool, senza modificarne il contenuto.
+    fn release_boxed(&self, buffer: &[u8]) {
+        self.counters.total_deallocations.fetch_add(1, Ordering::Relaxed);
+        for observer in self.observers.iter() {
+            observer.on_deallocate(buffer.len());
+        }
+        #[cfg(feature = "tracking")]
+        self.record_buffer_event(BufferEvent::Deallocate(buffer.as_ptr() as usize));
+    }
+
+    /// Converte un buffer del gestore in `Box<[u8]>`: i buffer allocati singolarmente passano senza copia,
+    /// gli altri vengono copiati e restituiti al gestore.
+    fn boxed_copy(&mut self, buffer: ManagedBuffer) -> Result<Box<[u8]>, CoreError> {
+        if buffer.is_heap() {
+            return Ok(buffer.into_boxed_slice());
+        }
+        let boxed = buffer.to_vec().into_boxed_slice();
+        self.deallocate(buffer)?;
+        Ok(boxed)
+    }
 
+    /// Aggiorna i contatori dopo un'allocazione riuscita di `size` byte.
+    fn record_allocation(&self, size: usize) {
+        self.counters.total_allocations.fetch_add(1, Ordering::Relaxed);
+        self.sync_free_buffers();
+        let in_use = self.counters.pool_capacity.load(Ordering::Relaxed)
+            .saturating_sub(self.counters.free_buffers.load(Ordering::Relaxed));
+        self.counters.peak_usage.fetch_max(in_use, Ordering::Relaxed);
+        #[cfg(feature = "tracking")]
+        if let Ok(mut records) = self.allocation_records.lock() {
+            records.push(AllocationRecord { size, context: Self::current_context() });
+        }
+        #[cfg(not(feature = "tracking"))]
+        let _ = size;
+    }
 
-/// Enum per rappresentare le diverse strategie di allocazione della memoria.
-#[derive(Debug,Clone)]
-pub enum AllocationStrategy {
-    Standard,
-    PoolBased,
-    CustomEmbedded,
-}
+    /// Registra un evento sul buffer per la visualizzazione con `to_dot_graph`.
+    #[cfg(feature = "tracking")]
+    fn record_buffer_event(&self, event: BufferEvent) {
+        if let Ok(mut events) = self.buffer_events.lock() {
+            events.push(event);
+        }
+    }
 
-/// `MemoryManager` gestisce l'allocazione e la deallocazione della memoria in base alla strategia
-/// selezionata dall'applicazione.
-///
-/// # Campi
-/// - `default_allocation_strategy`: La strategia di allocazione utilizzata.
-/// - `pool`: Un pool di buffer pre-allocati (usato solo nella strategia `PoolBased`).
-/// - `memory_config`: Configurazione della memoria di default fornita dall'utente.
-pub struct MemoryManager {
-    default_allocation_strategy: AllocationStrategy,
-    pool: Option<VecDeque<Box<[u8]>>>, // Pool per l'allocazione basata su pool
-    memory_config: MemoryConfig,  // Configurazione della memoria di default 
-}
+    /// Aggiorna il contatore condiviso dei buffer liberi in base allo stato del pool.
+    fn sync_free_buffers(&self) {
+        let free = self.lock_state().free_buffers();
+        self.counters.free_buffers.store(free, Ordering::Relaxed);
+    }
 
-impl MemoryManager {
-    /// Crea un nuovo gestore della memoria per l'applicazione specificata.
+    /// Entra in un nuovo contesto di allocazione per il thread corrente.
     ///
-    /// # Parametri
-    /// - `app_type`: Il tipo di applicazione (ad esempio, `WebApp`, `ApiBackend`, `DesktopApp`, ecc.).
-    /// - `memory_config`: La configurazione della memoria che specifica le dimensioni dei buffer e del pool.
+    /// Con la feature `tracking` ogni allocazione registra il percorso dei contesti attivi,
+    /// così da attribuire la memoria al sottosistema che l'ha richiesta.
+    pub fn push_context(label: &str) {
+        ALLOCATION_CONTEXT.with(|stack| stack.borrow_mut().push(label.to_string()));
+    }
+
+    /// Esce dall'ultimo contesto di allocazione aperto nel thread corrente.
     ///
     /// # Ritorna
-    /// Un'istanza di `MemoryManager` o un errore di tipo `CoreError` in caso di fallimento.
-    pub fn new(app_type: ApplicationType, memory_config: MemoryConfig) -> Result<Self, CoreError> {
-        info!("Inizializzazione del MemoryManager...");
+    /// L'etichetta del contesto rimosso, oppure `None` se la pila è vuota.
+    pub fn pop_context() -> Option<String> {
+        ALLOCATION_CONTEXT.with(|stack| stack.borrow_mut().pop())
+    }
 
-        // Determina la strategia di allocazione in base al tipo di applicazione.
-        let strategy = match app_type {
-            ApplicationType::WebApp | ApplicationType::ApiBackend => AllocationStrategy::PoolBased,
-            ApplicationType::DesktopApp => AllocationStrategy::Standard,
-            ApplicationType::AutomationScript => AllocationStrategy::Standard,
-            ApplicationType::EmbeddedSystem => AllocationStrategy::CustomEmbedded,
-            _ => {
-                return Err(CoreError::ConfigurationError("Tipo di applicazione non supportato considera implementazione".to_string()));
-            },
+    /// Restituisce il percorso dei contesti attivi nel thread corrente, separati da `" > "`.
+    pub fn current_context() -> String {
+        ALLOCATION_CONTEXT.with(|stack| stack.borrow().join(CONTEXT_SEPARATOR))
+    }
+
+    /// Restituisce una copia di tutte le allocazioni registrate.
+    #[cfg(feature = "tracking")]
+    pub fn allocation_records(&self) -> Vec<AllocationRecord> {
+        match self.allocation_records.lock() {
+            Ok(records) => records.clone(),
+            Err(_) => Vec::new(),
+        }
+    }
+
+    /// Calcola la distribuzione delle dimensioni di tutte le allocazioni effettuate.
+    #[cfg(feature = "tracking")]
+    pub fn histogram(&self) -> AllocationHistogram {
+        let sizes: Vec<usize> = self.allocation_records().iter().map(|r| r.size).collect();
+        AllocationHistogram::from_sizes(&sizes)
+    }
+
+    /// Restituisce la dimensione di allocazione più frequente, oppure 0 se non ci sono allocazioni.
+    #[cfg(feature = "tracking")]
+    pub fn most_common_size(&self) -> usize {
+        let mut frequencies: BTreeMap<usize, u64> = BTreeMap::new();
+        for record in self.allocation_records().iter() {
+            *frequencies.entry(record.size).or_insert(0) += 1;
+        }
+        frequencies.into_iter().max_by_key(|(_, count)| *count).map_or(0, |(size, _)| size)
+    }
+
+    /// Genera un grafo orientato in formato Graphviz DOT con lo stato delle allocazioni.
+    ///
+    /// I nodi rappresentano i buffer, identificati dal loro indirizzo, e gli archi gli eventi
+    /// di `allocate` (dal pool al buffer) e `deallocate` (dal buffer al pool).
+    /// I buffer liberi nel pool sono colorati in verde, quelli in uso in rosso e quelli
+    /// restituiti al sistema in grigio.
+    #[cfg(feature = "tracking")]
+    pub fn to_dot_graph(&self) -> String {
+        let events = match self.buffer_events.lock() {
+            Ok(events) => events.clone(),
+            Err(_) => Vec::new(),
+        };
+        let free: Vec<usize> = self.lock_state().pool.as_ref().map_or(Vec::new(), |p| p.iter().map(|b| b.as_ptr() as usize).collect());
+
+        // Ultimo evento di ciascun buffer, in ordine di prima apparizione
+        let mut last_event: BTreeMap<usize, BufferEvent> = BTreeMap::new();
+        let mut order: Vec<usize> = free.clone();
+        for event in events.iter() {
+            let (BufferEvent::Allocate(id) | BufferEvent::Deallocate(id)) = *event;
+            if !order.contains(&id) {
+                order.push(id);
+            }
+            last_event.insert(id, *event);
+        }
+
+        let mut dot = String::from("digraph memory {\n    pool [shape=box];\n");
+        for id in order.iter() {
+            let color = if free.contains(id) {
+                "green"
+            } else if let Some(BufferEvent::Allocate(_)) = last_event.get(id) {
+                "red"
+            } else {
+                "gray"
+            };
+            dot.push_str(&format!("    buf_{:x} [style=filled, color={}];\n", id, color));
+        }
+        for event in events.iter() {
+            match event {
+                BufferEvent::Allocate(id) => dot.push_str(&format!("    pool -> buf_{:x} [label=\"allocate\"];\n", id)),
+                BufferEvent::Deallocate(id) => dot.push_str(&format!("    buf_{:x} -> pool [label=\"deallocate\"];\n", id)),
+            }
+        }
+        dot.push_str("}\n");
+        dot
+    }
+
+    /// Restituisce un'istantanea delle statistiche correnti del `MemoryManager`.
+    pub fn memory_stats(&self) -> MemoryStats {
+        MemoryStats::from_counters(&self.default_allocation_strategy, self.memory_config.buffer_size, &self.counters)
+    }
+
+    /// Punteggio sintetico della salute della memoria, tra `0.0` (critica) e `1.0` (ottimale), utile per gli allarmi.
+    ///
+    /// È la media pesata di:
+    /// - frazione di buffer liberi del pool (peso 0.5, `1.0` per le strategie senza pool);
+    /// - `1 - allocazioni dinamiche a pool esaurito / allocazioni totali` (peso 0.3);
+    /// - `1 - fragmentation_ratio()` (peso 0.2).
+    ///
+    /// # Nota
+    /// Un punteggio inferiore a 0.5 viene segnalato con `log::warn!`.
+    pub fn health_score(&self) -> f64 {
+        let capacity = self.counters.pool_capacity.load(Ordering::Relaxed);
+        let pool_free_fraction = if capacity == 0 {
+            1.0
+        } else {
+            // I buffer dinamici deallocati entrano nel pool e possono superarne la capacità
+            (self.counters.free_buffers.load(Ordering::Relaxed) as f64 / capacity as f64).min(1.0)
         };
 
-        // Inizializza il pool solo se la strategia è `PoolBased`, utilizzando il `pool_size` configurato.
-        let pool = if let AllocationStrategy::PoolBased = strategy {
-            // Calcola quanti buffer servono in base alla dimensione totale del pool e del buffer
-            let buffer_count = memory_config.pool_size / memory_config.buffer_size;
-            let buffers = (0..buffer_count)
-                .map(|_| vec![0u8; memory_config.buffer_size].into_boxed_slice())
-                .collect::<VecDeque<_>>();
-            Some(buffers)
+        let total = self.counters.total_allocations.load(Ordering::Relaxed);
+        let fallback_fraction = if total == 0 {
+            0.0
         } else {
-            None
+            (self.counters.dynamic_fallbacks.load(Ordering::Relaxed) as f64 / total as f64).min(1.0)
         };
 
-        Ok(Self { default_allocation_strategy: strategy, pool, memory_config })
+        let fragmentation = self.fragmentation_ratio();
+
+        let score = HEALTH_WEIGHT_POOL_FREE * pool_free_fraction
+            + HEALTH_WEIGHT_FALLBACK * (1.0 - fallback_fraction)
+            + HEALTH_WEIGHT_FRAGMENTATION * (1.0 - fragmentation);
+        if score < HEALTH_WARNING_THRESHOLD {
+            warn!("Salute della memoria critica: punteggio {:.2}", score);
+        }
+        score
     }
 
-    /// Alloca memoria in base alla strategia configurata.
+    /// Restituisce le metriche di utilizzo del pool.
     ///
-    /// # Parametri
-    /// - `strategy`: La strategia di allocazione opzionale (`AllocationStrategy`). Se `None`, verrà utilizzata la strategia di default.
-    /// - `size`: La quantità di memoria da allocare in byte.
+    /// I contatori sono atomici e condivisi, pertanto la lettura non richiede l'accesso esclusivo al gestore.
+    pub fn stats(&self) -> PoolStats {
+        PoolStats {
+            total_buffers: self.counters.pool_capacity.load(Ordering::Relaxed),
+            available_buffers: self.counters.free_buffers.load(Ordering::Relaxed),
+            dynamic_fallback_count: self.counters.dynamic_fallbacks.load(Ordering::Relaxed),
+            peak_usage: self.counters.peak_usage.load(Ordering::Relaxed),
+        }
+    }
+
+    /// Restituisce le statistiche correnti di jemalloc per l'intero processo.
+    ///
+    /// # Nota
+    /// jemalloc aggiorna le statistiche solo all'avanzamento dell'epoca, eseguito ad ogni chiamata.
+    /// Un valore non leggibile viene registrato con `log::warn!` e restituito come `0`.
+    #[cfg(feature = "jemalloc")]
+    pub fn jemalloc_stats(&self) -> JemallocStats {
+        use tikv_jemalloc_ctl::{epoch, stats};
+
+        if let Err(e) = epoch::advance() {
+            warn!("Aggiornamento delle statistiche di jemalloc fallito: {}", e);
+        }
+        let read = |name: &str, value: tikv_jemalloc_ctl::Result<usize>| {
+            value.unwrap_or_else(|e| {
+                warn!("Lettura della statistica jemalloc {} fallita: {}", name, e);
+                0
+            })
+        };
+        JemallocStats {
+            active_bytes: read("active", stats::active::read()),
+            allocated_bytes: read("allocated", stats::allocated::read()),
+            metadata_bytes: read("metadata", stats::metadata::read()),
+        }
+    }
+
+    /// Azzera i contatori delle allocazioni, delle deallocazioni, delle allocazioni dinamiche a pool esaurito
+    /// e il picco di utilizzo del pool. I buffer del pool non vengono modificati.
+    pub fn reset_stats(&self) {
+        self.counters.total_allocations.store(0, Ordering::Relaxed);
+        self.counters.total_deallocations.store(0, Ordering::Relaxed);
+        self.counters.dynamic_fallbacks.store(0, Ordering::Relaxed);
+        self.counters.peak_usage.store(0, Ordering::Relaxed);
+        info!("Statistiche del MemoryManager azzerate");
+    }
+
+    /// Restituisce all'allocatore di sistema tutti i buffer liberi del pool, riducendone la capacità.
+    ///
+    /// I buffer attualmente allocati non sono interessati e, se deallocati, tornano nel pool.
+    ///
+    /// # Ritorna
+    /// Il numero di buffer rilasciati (sempre 0 per le strategie diverse da `PoolBased`).
+    pub fn drain_pool(&mut self) -> usize {
+        #[cfg(all(target_os = "linux", feature = "lazy-zero"))]
+        self.release_protected_pages();
+        let drained = match self.state.get_mut().pool {
+            Some(ref mut pool) => pool.drain(..).count(),
+            None => return 0,
+        };
+        self.counters.pool_capacity.fetch_sub(drained, Ordering::Relaxed);
+        self.sync_free_buffers();
+        info!("Rilasciati {} buffer liberi del pool", drained);
+        drained
+    }
+
+    /// Scambia il pool attivo con quello in preparazione della strategia `DoubleBuffered`.
+    ///
+    /// Va chiamata alla fine di ogni frame: le allocazioni del frame successivo usano il pool
+    /// preparato, mentre il pool appena concluso diventa quello in preparazione. L'accesso
+    /// esclusivo (`&mut self`) garantisce che nessuna allocazione osservi uno scambio parziale.
     ///
     /// # Ritorna
-    /// Un buffer di memoria (`Box<[u8]>`) o un errore di tipo `CoreError` in caso di fallimento.
+    /// `Ok(())`, oppure `CoreError::InvalidOperation` se la strategia di default non è `DoubleBuffered`.
     ///
     /// # Nota
-    /// - La strategia `Standard` alloca dinamicamente la memoria.
-    /// - La strategia `PoolBased` utilizza buffer pre-allocati dal pool. Se il pool è esaurito, viene effettuata un'allocazione dinamica.
-    /// - La strategia `CustomEmbedded` utilizza una configurazione fissa per i buffer, che è specificata dalla configurazione della memoria (`memory_config`).
-    pub fn allocate(&mut self, strategy: Option<AllocationStrategy>, size: usize) -> Result<Box<[u8]>, CoreError> {
-        let alloc_strategy = strategy.unwrap_or(self.default_allocation_strategy.clone());
-    
-        info!("Allocazione di {} byte di memoria con strategia {:?}...", size, alloc_strategy);
-        match alloc_strategy {
-            AllocationStrategy::Standard => {
-                let buffer = vec![0u8; size].into_boxed_slice();
-                Ok(buffer)
-            },
-            AllocationStrategy::PoolBased => {
-                if let Some(ref mut pool) = self.pool {
-                    if let Some(buffer) = pool.pop_front() {
-                        Ok(buffer)
-                    } else {
-                        // Pool esaurito, alloca dinamicamente
-                        let buffer = vec![0u8; size].into_boxed_slice();
-                        Ok(buffer)
-                    }
-                } else {
-                    Err(CoreError::ResourceAllocationError("Pool non disponibile".to_string()))
-                }
-            },
-            AllocationStrategy::CustomEmbedded => {
-                // Usa la dimensione configurata per i buffer negli embedded.
-                let buffer = vec![0u8; self.memory_config.buffer_size].into_boxed_slice();
-                Ok(buffer)
-            },
+    /// I buffer ancora in uso al momento dello scambio appartengono al pool appena concluso e vengono
+    /// restituiti da `deallocate` al pool in preparazione, così che ciascun pool mantenga i propri buffer.
+    pub fn swap_buffers(&mut self) -> Result<(), CoreError> {
+        if !matches!(self.default_allocation_strategy, AllocationStrategy::DoubleBuffered) {
+            return Err(CoreError::InvalidOperation(format!(
+                "Scambio dei pool non disponibile per la strategia {:?}", self.default_allocation_strategy
+            ), Vec::new()).with_context("MemoryManager::swap_buffers"));
         }
+        self.ensure_pool_initialized();
+        let state = self.state.get_mut();
+        std::mem::swap(&mut state.pool, &mut state.standby_pool);
+        std::mem::swap(&mut state.active_in_use, &mut state.standby_in_use);
+        let free = state.free_buffers();
+
+        // I permessi di `allocate_async` devono corrispondere ai buffer liberi del nuovo pool attivo
+        self.sync_pool_slots();
+        self.sync_free_buffers();
+        info!("Pool del frame scambiati: {} buffer liberi nel pool attivo", free);
+        Ok(())
     }
-    
 
-    /// Dealloca memoria precedentemente allocata.
+    /// Allinea i permessi di `pool_slots` al numero di buffer liberi del pool attivo.
+    fn sync_pool_slots(&self) {
+        let free = self.lock_state().free_buffers();
+        let available = self.pool_slots.available_permits();
+        if free > available {
+            self.pool_slots.add_permits(free - available);
+        } else if let Ok(permits) = self.pool_slots.try_acquire_many((available - free) as u32) {
+            permits.forget();
+        }
+    }
+
+    /// Riordina i buffer liberi del pool per indirizzo crescente, così che le allocazioni
+    /// successive utilizzino buffer contigui nello spazio degli indirizzi.
     ///
-    /// # Parametri
-    /// - `buffer`: Il buffer di memoria da deallocare.
+    /// Con la feature `compact-retry` compatta anche l'heap tramite `HeapAllocator::defragment`.
     ///
     /// # Ritorna
-    /// `Ok(())` se la deallocazione ha successo, oppure un errore di tipo `CoreError`.
+    /// - `Ok(usize)`: Il numero di buffer che hanno cambiato posizione nel pool (0 per le strategie
+    ///   senza pool con la feature `compact-retry`).
+    /// - `Err(CoreError::InvalidOperation)`: Per le strategie senza pool (`Standard`, `CustomEmbedded`),
+    ///   senza la feature `compact-retry`.
     ///
     /// # Nota
-    /// - Nella strategia `Standard`, Rust dealloca automaticamente la memoria.
-    /// - Nella strategia `PoolBased`, il buffer viene restituito al pool.
-    /// - Nella strategia `CustomEmbedded`, non è richiesta alcuna azione specifica.
-    pub fn deallocate(&mut self, buffer: Box<[u8]>) -> Result<(), CoreError> {
-        info!("Deallocazione della memoria...");
-        match self.default_allocation_strategy {
-            AllocationStrategy::Standard => {
-                // Rust dealloca automaticamente la memoria.
-                Ok(())
-            },
-            AllocationStrategy::PoolBased => {
-                // Restituisce il buffer al pool.
-                if let Some(ref mut pool) = self.pool {
-                    pool.push_back(buffer);
-                    Ok(())
-                } else {
-                    Err(CoreError::ResourceAllocationError("Pool non disponibile".to_string()))
+    /// Va chiamata solo quando non ci sono allocazioni in corso: i buffer in uso non vengono
+    /// riordinati e, una volta restituiti, tornano in coda al pool.
+    pub fn defragment(&mut self) -> Result<usize, CoreError> {
+        #[cfg(feature = "compact-retry")]
+        self.heap.defragment();
+        let pool = match (&self.default_allocation_strategy, self.state.get_mut().pool.as_mut()) {
+            (strategy, Some(pool)) if strategy.is_pool_based() => pool,
+            #[cfg(feature = "compact-retry")]
+            _ => return Ok(0),
+            #[cfg(not(feature = "compact-retry"))]
+            (strategy, _) => {
+                return Err(CoreError::InvalidOperation(
+                    format!("Deframmentazione non disponibile per la strategia {:?}", strategy), Vec::new()
+                ));
+            }
+        };
+        let before: Vec<usize> = pool.iter().map(|buffer| buffer.as_ptr() as usize).collect();
+        pool.make_contiguous().sort_unstable_by_key(|buffer| buffer.as_ptr() as usize);
+        let reordered = pool.iter().zip(before).filter(|(buffer, address)| buffer.as_ptr() as usize != *address).count();
+        info!("Deframmentazione del pool: {} buffer riordinati", reordered);
+        Ok(reordered)
+    }
+
+    /// Modifica a runtime il numero di buffer del pool, senza ricreare il `MemoryManager`.
+    ///
+    /// Se `new_buffer_count` è maggiore della capacità attuale vengono allocati i buffer mancanti;
+    /// se è minore vengono rilasciati i buffer liberi in eccesso. Quando i buffer liberi non bastano,
+    /// perché alcuni sono in uso, la capacità richiesta viene memorizzata e raggiunta man mano che
+    /// i buffer vengono restituiti con `deallocate`. Una nuova chiamata sostituisce la richiesta in sospeso.
+    ///
+    /// # Parametri
+    /// - `new_buffer_count`: Il numero di buffer desiderato.
+    ///
+    /// # Ritorna
+    /// `Ok(())`, oppure `CoreError::InvalidOperation` se la strategia di default non è basata su pool.
+    pub fn resize_pool(&mut self, new_buffer_count: usize) -> Result<(), CoreError> {
+        if !self.default_allocation_strategy.is_pool_based() {
+            return Err(CoreError::InvalidOperation(format!(
+                "Ridimensionamento del pool non disponibile per la strategia {:?}", self.default_allocation_strategy
+            ), Vec::new()).with_context("MemoryManager::resize_pool"));
+        }
+        self.ensure_pool_initialized();
+        let state = self.state.get_mut();
+        state.pending_shrink_target = None;
+
+        let capacity = self.counters.pool_capacity.load(Ordering::Relaxed);
+        if new_buffer_count > capacity {
+            let added = new_buffer_count - capacity;
+            let buffers = Self::build_pool(&self.memory_config, added);
+            if let Some(ref mut pool) = state.pool {
+                pool.extend(buffers);
+            }
+            self.counters.pool_capacity.fetch_add(added, Ordering::Relaxed);
+            self.pool_slots.add_permits(added);
+            info!("Pool ridimensionato da {} a {} buffer", capacity, new_buffer_count);
+        } else if new_buffer_count < capacity {
+            state.pending_shrink_target = Some(new_buffer_count);
+            let mut state = self.lock_state();
+            self.apply_pending_shrink(&mut state);
+        }
+        self.sync_free_buffers();
+        Ok(())
+    }
+
+    /// Rilascia i buffer liberi del pool finché la capacità non raggiunge `pending_shrink_target`.
+    fn apply_pending_shrink(&self, state: &mut PoolState) {
+        let Some(target) = state.pending_shrink_target else {
+            return;
+        };
+        let capacity = self.counters.pool_capacity.load(Ordering::Relaxed);
+        let mut released = 0;
+        if let Some(ref mut pool) = state.pool {
+            while capacity - released > target {
+                let Some(_buffer) = pool.pop_back() else {
+                    break;
+                };
+                #[cfg(all(target_os = "linux", feature = "lazy-zero"))]
+                {
+                    let mut buffer = _buffer;
+                    lazy_zero::unprotect(&mut buffer);
                 }
-            },
-            AllocationStrategy::CustomEmbedded => {
-                // Gestione personalizzata per sistemi embedded.
-                Ok(())
-            },
+                // Il permesso del buffer rilasciato non deve più essere disponibile per `allocate_async`
+                if let Ok(permit) = self.pool_slots.try_acquire() {
+                    permit.forget();
+                }
+                released += 1;
+            }
+        }
+        self.counters.pool_capacity.fetch_sub(released, Ordering::Relaxed);
+        if capacity - released <= target {
+            state.pending_shrink_target = None;
+            info!("Pool ridimensionato da {} a {} buffer", capacity, target);
+        } else {
+            info!("Pool in riduzione a {} buffer: {} buffer ancora in uso", target, capacity - released - target);
+        }
+    }
+
+    /// Numero di buffer del pool richiesto da `resize_pool` e non ancora raggiunto, se presente.
+    pub fn pending_shrink_target(&self) -> Option<usize> {
+        self.lock_state().pending_shrink_target
+    }
+
+    /// Riporta la capacità del pool a quella configurata in `memory_config`, allocando i buffer mancanti.
+    ///
+    /// # Ritorna
+    /// Il numero di buffer aggiunti al pool (sempre 0 per le strategie diverse da `PoolBased`).
+    pub fn refill_pool(&mut self) -> usize {
+        self.ensure_pool_initialized();
+        let target = Self::initial_buffer_count(&self.default_allocation_strategy, &self.memory_config);
+        let capacity = self.counters.pool_capacity.load(Ordering::Relaxed);
+        let missing = target.saturating_sub(capacity);
+        match self.state.get_mut().pool {
+            Some(ref mut pool) => pool.extend(Self::build_pool(&self.memory_config, missing)),
+            None => return 0,
+        }
+        self.counters.pool_capacity.fetch_add(missing, Ordering::Relaxed);
+        self.pool_slots.add_permits(missing);
+        self.sync_free_buffers();
+        info!("Aggiunti {} buffer al pool", missing);
+        missing
+    }
+
+    /// Alloca `size` byte dal buffer statico della strategia `CustomEmbedded` senza utilizzare l'heap,
+    /// indipendentemente da `buffer_size`.
+    ///
+    /// # Ritorna
+    /// Il buffer, che torna al buffer statico con `deallocate` o al rilascio, oppure `CoreError::AllocationTooLarge`
+    /// se `size` supera `memory_config.max_allocation_size` e `CoreError::ResourceAllocationError`
+    /// se la capacità definita da `MemoryConfig::embedded_capacity()` è esaurita.
+    #[cfg(feature = "embedded")]
+    pub fn allocate_embedded(&mut self, size: usize) -> Result<ManagedBuffer, CoreError> {
+        info!("Allocazione embedded di {} byte...", size);
+        Self::check_allocation_size(size, self.max_allocation_size())?;
+        ManagedBuffer::embedded(size).map_err(|e| e.with_context("MemoryManager::allocate_embedded"))
+    }
+}
+
+
+#[cfg(all(target_os = "linux", feature = "lazy-zero"))]
+impl MemoryManager {
+    /// Rende di nuovo accessibili, azzerandoli, i buffer liberi del pool protetti da `ZeroingPolicy::ProtectAndZeroOnReuse`.
+    fn release_protected_pages(&mut self) {
+        if !self.memory_config.zeroing_policy.protect_free_buffers() {
+            return;
+        }
+        let state = self.state.get_mut();
+        for pool in [&mut state.pool, &mut state.standby_pool].into_iter().flatten() {
+            for buffer in pool.iter_mut() {
+                lazy_zero::unprotect(buffer);
+            }
+        }
+    }
+}
+
+/// I buffer liberi del pool tornano all'allocatore di sistema al rilascio del gestore:
+/// con la protezione dei buffer liberi le loro pagine vanno prima rese di nuovo accessibili.
+impl Drop for MemoryManager {
+    fn drop(&mut self) {
+        #[cfg(all(target_os = "linux", feature = "lazy-zero"))]
+        self.release_protected_pages();
+    }
+}
+
+
+/// Handle condivisibile tra thread di un `MemoryManager`.
+///
+/// `allocate` e `deallocate` acquisiscono il lock, delegano al gestore e lo rilasciano subito,
+/// così che più thread possano usare lo stesso gestore senza sincronizzazione esterna.
+/// `clone_handle` restituisce un nuovo handle verso lo stesso gestore.
+#[derive(Clone)]
+pub struct SharedMemoryManager(Arc<Mutex<MemoryManager>>);
+
+impl SharedMemoryManager {
+    /// Rende condivisibile il gestore della memoria indicato.
+    pub fn new(manager: MemoryManager) -> Self {
+        SharedMemoryManager(Arc::new(Mutex::new(manager)))
+    }
+
+    /// Restituisce un nuovo handle verso lo stesso gestore, clonando solo l'`Arc`.
+    pub fn clone_handle(&self) -> SharedMemoryManager {
+        SharedMemoryManager(Arc::clone(&self.0))
+    }
+
+    /// Acquisisce il lock sul gestore, per le operazioni non esposte direttamente dall'handle.
+    ///
+    /// # Ritorna
+    /// Il guard del gestore, oppure `CoreError::GenericError` se il lock è avvelenato.
+    pub fn lock(&self) -> Result<MutexGuard<'_, MemoryManager>, CoreError> {
+        self.0.lock().map_err(|e| CoreError::GenericError(e.to_string(), Vec::new()).with_context("SharedMemoryManager::lock"))
+    }
+
+    /// Alloca memoria con `MemoryManager::allocate`, mantenendo il lock solo per la durata della chiamata.
+    pub fn allocate(&self, strategy: Option<AllocationStrategy>, size: usize) -> Result<ManagedBuffer, CoreError> {
+        self.lock()?.allocate(strategy, size)
+    }
+
+    /// Dealloca memoria con `MemoryManager::deallocate`, mantenendo il lock solo per la durata della chiamata.
+    pub fn deallocate(&self, buffer: ManagedBuffer) -> Result<(), CoreError> {
+        self.lock()?.deallocate(buffer)
+    }
+}
+
+impl From<Arc<Mutex<MemoryManager>>> for SharedMemoryManager {
+    fn from(manager: Arc<Mutex<MemoryManager>>) -> Self {
+        SharedMemoryManager(manager)
+    }
+}
+
+/// Allocatore a incremento (bump) per l'elaborazione di richieste di breve durata.
+///
+/// Ogni `alloc` avanza il cursore all'interno di un unico buffer ottenuto dal `MemoryManager`:
+/// le allocazioni non vengono mai liberate singolarmente ma tutte insieme con `reset`.
+///
+/// # Nota
+/// - L'arena prende in prestito il gestore per la lifetime `'m`, così che il gestore non possa
+///   essere usato né rilasciato mentre l'arena è in uso.
+/// - `reset` non azzera il contenuto: le allocazioni successive possono contenere i dati precedenti.
+/// - Al rilascio dell'arena il buffer viene restituito al gestore con `MemoryManager::deallocate`.
+pub struct MemoryArena<'m> {
+    manager: &'m mut MemoryManager,
+    backing: ManagedBuffer,
+    cursor: usize,
+}
+
+impl<'m> MemoryArena<'m> {
+    /// Crea un'arena su un buffer da `buffer_size` byte ottenuto dal gestore con la sua strategia predefinita.
+    ///
+    /// # Ritorna
+    /// L'arena, oppure l'errore di `MemoryManager::allocate`.
+    pub fn from_manager(mgr: &'m mut MemoryManager) -> Result<Self, CoreError> {
+        let size = mgr.memory_config.buffer_size;
+        let backing = mgr.allocate(None, size)?;
+        info!("Arena creata su un buffer da {} byte", backing.len());
+        Ok(MemoryArena { manager: mgr, backing, cursor: 0 })
+    }
+
+    /// Riserva `size` byte contigui avanzando il cursore.
+    ///
+    /// # Ritorna
+    /// Lo slice riservato, oppure `CoreError::ResourceAllocationError` se l'arena non ha spazio sufficiente.
+    pub fn alloc(&mut self, size: usize) -> Result<&mut [u8], CoreError> {
+        let end = match self.cursor.checked_add(size) {
+            Some(end) if end <= self.backing.len() => end,
+            _ => return Err(CoreError::ResourceAllocationError(format!(
+                "Arena esaurita: richiesti {} byte, disponibili {}", size, self.remaining()
+            ), Vec::new()).with_context("MemoryArena::alloc")),
+        };
+        let start = std::mem::replace(&mut self.cursor, end);
+        Ok(&mut self.backing[start..end])
+    }
+
+    /// Riporta il cursore all'inizio dell'arena, rendendo di nuovo disponibile l'intero buffer.
+    pub fn reset(&mut self) {
+        self.cursor = 0;
+    }
+
+    /// Dimensione in byte del buffer dell'arena.
+    pub fn capacity(&self) -> usize {
+        self.backing.len()
+    }
+
+    /// Byte riservati dall'ultimo `reset`.
+    pub fn used(&self) -> usize {
+        self.cursor
+    }
+
+    /// Byte ancora disponibili per `alloc`.
+    pub fn remaining(&self) -> usize {
+        self.backing.len() - self.cursor
+    }
+}
+
+impl Drop for MemoryArena<'_> {
+    fn drop(&mut self) {
+        let backing = std::mem::replace(&mut self.backing, ManagedBuffer::from(Vec::new()));
+        if let Err(e) = self.manager.deallocate(backing) {
+            warn!("Restituzione del buffer dell'arena fallita: {}", e);
         }
     }
 }
 
 
+/// Azzera il contenuto del buffer con una scrittura esplicita.
+///
+/// Il `compiler_fence` impedisce al compilatore di eliminare l'azzeramento
+/// quando il buffer viene rilasciato subito dopo.
+fn zero_buffer(buffer: &mut [u8]) {
+    // SAFETY: il puntatore e la lunghezza provengono da uno slice valido e mutabile.
+    unsafe {
+        std::ptr::write_bytes(buffer.as_mut_ptr(), 0, buffer.len());
+    }
+    std::sync::atomic::compiler_fence(Ordering::SeqCst);
+}
+
+/// Modalità di risoluzione dei valori di configurazione che eccedono il limite consentito
+/// in `define_buffer_size` e `define_pool_size`.
+///
+/// - `Interactive`: Chiede all'utente su stdin se assegnare il valore massimo o inserirne uno nuovo.
+/// - `Clamp`: Riduce il valore al massimo consentito senza alcuna richiesta, adatta a CI,
+///   server e processi demone privi di terminale.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfigResolutionMode {
+    Interactive,
+    Clamp,
+}
+
+/// Risolve un valore che eccede `usize::MAX / 2` secondo la modalità indicata.
+fn resolve_usize_overflow(var_name: &str, mode: ConfigResolutionMode) -> usize {
+    match mode {
+        ConfigResolutionMode::Interactive => usize_max_value(var_name),
+        ConfigResolutionMode::Clamp => {
+            warn!("Il valore di {} eccede il limite massimo: ridotto a {}", var_name, usize::MAX / 2);
+            usize::MAX / 2
+        }
+    }
+}
+
 fn usize_max_value(var_name: &str) -> usize {
     println!("Il valore di {} eccede il limite massimo di usize.\n\
     Vuoi assegnare il valore massimo consentito ({} /2)? [y/n]", var_name, usize::MAX );
@@ -223,10 +3430,10 @@ fn usize_max_value(var_name: &str) -> usize {
 }
 
 // Calcola il buffer_size
-pub fn define_buffer_size(app_type: ApplicationType, buffer_size: usize) -> usize {
+pub fn define_buffer_size(app_type: ApplicationType, buffer_size: usize, mode: ConfigResolutionMode) -> usize {
 
     if buffer_size > usize::MAX/2 {
-        return usize_max_value("buffer_size");
+        return resolve_usize_overflow("buffer_size", mode);
     } else if buffer_size != 0 {
         return buffer_size;
     }
@@ -237,15 +3444,18 @@ pub fn define_buffer_size(app_type: ApplicationType, buffer_size: usize) -> usiz
         ApplicationType::DesktopApp => 4 * 1024 * 1024, // 4 MB
         ApplicationType::AutomationScript => 2 * 1024 * 1024, // 2 MB
         ApplicationType::EmbeddedSystem => 512 * 1024, // 512 KB
+        ApplicationType::WasmBased => 64 * 1024, // 64 KB, una pagina di memoria WebAssembly
+        ApplicationType::GameServer => 32 * 1024 * 1024, // 32 MB
+        ApplicationType::MLInference => 128 * 1024 * 1024, // 128 MB
         _ => 0,
     }
 }
 
 // Calcola il pool_size
-pub fn define_pool_size(app_type: ApplicationType, pool_size: usize) -> usize {
+pub fn define_pool_size(app_type: ApplicationType, pool_size: usize, mode: ConfigResolutionMode) -> usize {
 
     if pool_size > usize::MAX/2 {
-        return usize_max_value("pool_size");
+        return resolve_usize_overflow("pool_size", mode);
     } else if pool_size != 0 {
         return pool_size;
     }
@@ -256,41 +3466,16 @@ pub fn define_pool_size(app_type: ApplicationType, pool_size: usize) -> usize {
         ApplicationType::DesktopApp => 50 * 1024 * 1024, // 50 MB
         ApplicationType::AutomationScript => 30 * 1024 * 1024, // 30 MB
         ApplicationType::EmbeddedSystem => 5 * 1024 * 1024, // 5 MB
+        ApplicationType::WasmBased => 512 * 1024, // 512 KB
+        ApplicationType::GameServer => 256 * 1024 * 1024, // 256 MB
+        ApplicationType::MLInference => 1024 * 1024 * 1024, // 1 GB
         _ => 0,
     }
 }
 
+// Calcola il memory_scale: un `u8` non può eccedere il limite, quindi non serve una `ConfigResolutionMode`
 pub fn define_multiplier(app_type: ApplicationType, memory_scale: u8) -> u8 {
-    if memory_scale > u8::MAX {
-        println!("Il valore di memory_scale eccede il limite massimo di u8.\n\
-        Vuoi assegnare il valore massimo consentito ({})? [y/n]", u8::MAX);
-
-        let mut input = String::new();
-        io::stdout().flush().unwrap(); // Assicura che il prompt venga mostrato
-        io::stdin().read_line(&mut input).expect("Errore nella lettura dell'input");
-
-        match input.trim().to_lowercase().as_str() {
-            "y" => return u8::MAX,
-            "n" => {
-                println!("Inserisci un nuovo valore:");
-                input.clear();
-                io::stdin().read_line(&mut input).expect("Errore nella lettura dell'input");
-                
-                return match input.trim().parse::<u8>() {
-                    Ok(val) => val,
-                    Err(_) => {
-                        println!("Input non valido. Riprova.");
-                        return define_multiplier(app_type, memory_scale);
-                    }
-                };
-            }
-            _ => {
-                println!("Input non valido. Digita 'y' per accettare il valore \
-                massimo o 'n' per inserire un nuovo valore.");
-                return define_multiplier(app_type, memory_scale);
-            }
-        }
-    } else if memory_scale != 0 {
+    if memory_scale != 0 {
         return memory_scale;
     }
 
@@ -300,6 +3485,9 @@ pub fn define_multiplier(app_type: ApplicationType, memory_scale: u8) -> u8 {
         ApplicationType::DesktopApp => 1,
         ApplicationType::AutomationScript => 1,
         ApplicationType::EmbeddedSystem => 1,
+        ApplicationType::WasmBased => 1,
+        ApplicationType::GameServer => 1,
+        ApplicationType::MLInference => 1,
         _ => 0,
     }
 }
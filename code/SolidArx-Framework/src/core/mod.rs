@@ -1,2 +1,6 @@
 pub mod system_core;
-pub mod memory_management;
\ No newline at end of file
+pub mod memory_management;
+pub mod buddy_allocator;
+pub mod evicting_map;
+#[cfg(all(target_os = "linux", feature = "lazy-zero"))]
+pub(crate) mod lazy_zero;
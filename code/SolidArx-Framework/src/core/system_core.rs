@@ -18,7 +18,7 @@ use crate::config::{
     database_config::DatabaseType,
 };
 
-use crate::core::memory_management::MemoryManager;
+use crate::core::memory_management::{ManagedBuffer, MemoryManager};
 use crate::monitoring::logger;
 use log::{info, warn ,error};
 
@@ -50,23 +50,94 @@ use crate::frontend;
 
 
 /// Definizione degli errori principali che possono verificarsi nel sistema core.
-#[derive(Debug)]
+///
+/// Ogni variante contiene il messaggio dell'errore e la catena dei contesti aggiunti con
+/// `with_context` durante la propagazione, dal più interno al più esterno.
+///
+/// Implementa `std::error::Error`, quindi si converte in `Box<dyn std::error::Error>` con `?`
+/// tramite l'implementazione generica della libreria standard. Due errori sono uguali se
+/// coincidono variante, messaggio e catena dei contesti.
+///
+/// `TransferSizeMismatch` restituisce al chiamante il buffer non trasferito, che appartiene ancora
+/// al gestore di origine.
+#[derive(Debug, PartialEq)]
 pub enum CoreError {
-    InitializationError(String),
-    ResourceAllocationError(String),
-    ConfigurationError(String),
-    UnsupportedOperationError(String),
-    GenericError(String),
+    InitializationError(String, Vec<String>),
+    ResourceAllocationError(String, Vec<String>),
+    ConfigurationError(String, Vec<String>),
+    UnsupportedOperationError(String, Vec<String>),
+    GenericError(String, Vec<String>),
+    PoolExhausted(String, Vec<String>),
+    InvalidOperation(String, Vec<String>),
+    WasmUnsupported(String, Vec<String>),
+    UnsupportedPlatform(String, Vec<String>),
+    TransferSizeMismatch { src: usize, dst: usize, buffer: ManagedBuffer, context: Vec<String> },
+    AllocationTooLarge { requested: usize, max: usize, context: Vec<String> },
 }
 
+impl CoreError {
+    /// Aggiunge un contesto alla catena dell'errore, utile per tracciarne il percorso di propagazione.
+    pub fn with_context(mut self, msg: &str) -> Self {
+        self.context_mut().push(msg.to_string());
+        self
+    }
+
+    /// Catena dei contesti dell'errore, dal più interno al più esterno.
+    pub fn context(&self) -> &[String] {
+        match self {
+            CoreError::InitializationError(_, context)
+            | CoreError::ResourceAllocationError(_, context)
+            | CoreError::ConfigurationError(_, context)
+            | CoreError::UnsupportedOperationError(_, context)
+            | CoreError::GenericError(_, context)
+            | CoreError::PoolExhausted(_, context)
+            | CoreError::InvalidOperation(_, context)
+            | CoreError::WasmUnsupported(_, context)
+            | CoreError::UnsupportedPlatform(_, context)
+            | CoreError::TransferSizeMismatch { context, .. }
+            | CoreError::AllocationTooLarge { context, .. } => context,
+        }
+    }
+
+    fn context_mut(&mut self) -> &mut Vec<String> {
+        match self {
+            CoreError::InitializationError(_, context)
+            | CoreError::ResourceAllocationError(_, context)
+            | CoreError::ConfigurationError(_, context)
+            | CoreError::UnsupportedOperationError(_, context)
+            | CoreError::GenericError(_, context)
+            | CoreError::PoolExhausted(_, context)
+            | CoreError::InvalidOperation(_, context)
+            | CoreError::WasmUnsupported(_, context)
+            | CoreError::UnsupportedPlatform(_, context)
+            | CoreError::TransferSizeMismatch { context, .. }
+            | CoreError::AllocationTooLarge { context, .. } => context,
+        }
+    }
+}
+
+/// Formato: `[ctx1 > ctx2]: errore`, oppure solo l'errore se non sono presenti contesti.
 impl std::fmt::Display for CoreError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if !self.context().is_empty() {
+            write!(f, "[{}]: ", self.context().join(" > "))?;
+        }
         match self {
-            CoreError::InitializationError(msg) => write!(f, "InitializationError: {}", msg),
-            CoreError::ResourceAllocationError(msg) => write!(f, "ResourceAllocationError: {}", msg),
-            CoreError::ConfigurationError(msg) => write!(f, "ConfigurationError: {}", msg),
-            CoreError::UnsupportedOperationError(msg) => write!(f, "UnsupportedOperationError: {}", msg),
-            CoreError::GenericError(msg) => write!(f, "Error: {}", msg),
+            CoreError::InitializationError(msg, _) => write!(f, "InitializationError: {}", msg),
+            CoreError::ResourceAllocationError(msg, _) => write!(f, "ResourceAllocationError: {}", msg),
+            CoreError::ConfigurationError(msg, _) => write!(f, "ConfigurationError: {}", msg),
+            CoreError::UnsupportedOperationError(msg, _) => write!(f, "UnsupportedOperationError: {}", msg),
+            CoreError::GenericError(msg, _) => write!(f, "Error: {}", msg),
+            CoreError::PoolExhausted(msg, _) => write!(f, "PoolExhausted: {}", msg),
+            CoreError::InvalidOperation(msg, _) => write!(f, "InvalidOperation: {}", msg),
+            CoreError::WasmUnsupported(msg, _) => write!(f, "WasmUnsupported: {}", msg),
+            CoreError::UnsupportedPlatform(msg, _) => write!(f, "UnsupportedPlatform: {}", msg),
+            CoreError::TransferSizeMismatch { src, dst, .. } => write!(
+                f, "TransferSizeMismatch: buffer da {} byte non trasferibili senza copia in buffer da {} byte", src, dst
+            ),
+            CoreError::AllocationTooLarge { requested, max, .. } => write!(
+                f, "AllocationTooLarge: richiesti {} byte, oltre il massimo di {} byte", requested, max
+            ),
         }
     }
 }
@@ -91,7 +162,7 @@ macro_rules! init_module {
             info!("Inizializzazione del modulo {}", $module_name);
             if let Err(e) = $init_func() {
                 error!("Errore nell'inizializzazione del modulo {}: {}", $module_name, e);
-                return Err(CoreError::InitializationError(format!("{} initialization failed: {}", $module_name, e)));
+                return Err(CoreError::InitializationError(format!("{} initialization failed: {}", $module_name, e), Vec::new()));
             }
             logger::monitor_module_status($module_name, None);
             Ok(())
@@ -115,7 +186,7 @@ impl CoreSystem {
         let app_type = &config.app_type;
         let memory_manager = MemoryManager::new(config.app_type.clone(), memory_config).map_err(|e| {
             error!("Errore nell'inizializzazione del MemoryManager: {}", e);
-            CoreError::InitializationError(e.to_string())
+            CoreError::InitializationError(e.to_string(), Vec::new()).with_context("CoreSystem::new")
         })?;
         let connection_manager = match database_config.clone() {
             DatabaseType::None => {
@@ -125,7 +196,7 @@ impl CoreSystem {
             _ => Some({
                     ConnectionManager::new(database_config).map_err(|e| {
                         error!("Errore nell'inizializzazione del ConnectionManager: {}", e);
-                        CoreError::InitializationError(e.to_string())
+                        CoreError::InitializationError(e.to_string(), Vec::new()).with_context("ConnectionManager::new")
                     })?;
                 })
         }
@@ -155,7 +226,9 @@ impl CoreSystem {
             Some(cm) => {
                 // Inizializzazione della connessione al database
                 info!("Inizializzazione della connessione al database...");
-                cm.initialize_connection().await?;
+                cm.initialize_connection()
+                    .await
+                    .map_err(|e| CoreError::InitializationError(format!("Connessione al database fallita: {}", e), Vec::new()).with_context("ConnectionManager::initialize_connection"))?;
 
                 let default_path = "src/crud/models/default";
                 let dev_path = "src/crud/models/dev";   
@@ -186,13 +259,13 @@ impl CoreSystem {
                 init_module!("Frontend", || frontend::initialize())?;
 
                 #[cfg(not(feature = "auth"))]
-                return Err(CoreError::UnsupportedOperationError("Authentication module is required for WebApp".to_string()));
+                return Err(CoreError::UnsupportedOperationError("Authentication module is required for WebApp".to_string(), Vec::new()));
                 #[cfg(not(feature = "crud"))]
-                return Err(CoreError::UnsupportedOperationError("CRUD module is required for WebApp".to_string()));
+                return Err(CoreError::UnsupportedOperationError("CRUD module is required for WebApp".to_string(), Vec::new()));
                 #[cfg(not(feature = "api"))]
-                return Err(CoreError::UnsupportedOperationError("API module is required for WebApp".to_string()));
+                return Err(CoreError::UnsupportedOperationError("API module is required for WebApp".to_string(), Vec::new()));
                 #[cfg(not(feature = "frontend"))]
-                return Err(CoreError::UnsupportedOperationError("Frontend module is required for WebApp".to_string()));
+                return Err(CoreError::UnsupportedOperationError("Frontend module is required for WebApp".to_string(), Vec::new()));
                 
             }
 
@@ -207,11 +280,11 @@ impl CoreSystem {
 
 
                 #[cfg(not(feature = "auth"))]
-                return Err(CoreError::UnsupportedOperationError("Authentication module is required for API Backend".to_string()));
+                return Err(CoreError::UnsupportedOperationError("Authentication module is required for API Backend".to_string(), Vec::new()));
                 #[cfg(not(feature = "crud"))]
-                return Err(CoreError::UnsupportedOperationError("CRUD module is required for API Backend".to_string()));
+                return Err(CoreError::UnsupportedOperationError("CRUD module is required for API Backend".to_string(), Vec::new()));
                 #[cfg(not(feature = "api"))]
-                return Err(CoreError::UnsupportedOperationError("API module is required for API Backend".to_string()));
+                return Err(CoreError::UnsupportedOperationError("API module is required for API Backend".to_string(), Vec::new()));
 
             }
 
@@ -227,13 +300,13 @@ impl CoreSystem {
                 init_module!("Frontend", || frontend::initialize())?;
 
                 #[cfg(not(feature = "auth"))]
-                return Err(CoreError::UnsupportedOperationError("Authentication module is required for Desktop App".to_string()));
+                return Err(CoreError::UnsupportedOperationError("Authentication module is required for Desktop App".to_string(), Vec::new()));
                 #[cfg(not(feature = "crud"))]
-                return Err(CoreError::UnsupportedOperationError("CRUD module is required for Desktop App".to_string()));
+                return Err(CoreError::UnsupportedOperationError("CRUD module is required for Desktop App".to_string(), Vec::new()));
                 #[cfg(not(feature = "file_management"))]
-                return Err(CoreError::UnsupportedOperationError("File Management module is required for Desktop App".to_string()));
+                return Err(CoreError::UnsupportedOperationError("File Management module is required for Desktop App".to_string(), Vec::new()));
                 #[cfg(not(feature = "frontend"))]
-                return Err(CoreError::UnsupportedOperationError("Frontend module is required for Desktop App".to_string()));
+                return Err(CoreError::UnsupportedOperationError("Frontend module is required for Desktop App".to_string(), Vec::new()));
             }
 
             ApplicationType::AutomationScript => {
@@ -244,9 +317,9 @@ impl CoreSystem {
                 init_module!("File Management", || file_management::initialize())?;
 
                 #[cfg(not(feature = "task_automation"))]
-                return Err(CoreError::UnsupportedOperationError("Task Automation module is required for Automation Script".to_string()));
+                return Err(CoreError::UnsupportedOperationError("Task Automation module is required for Automation Script".to_string(), Vec::new()));
                 #[cfg(not(feature = "file_management"))]
-                return Err(CoreError::UnsupportedOperationError("File Management module is required for Automation Script".to_string()));
+                return Err(CoreError::UnsupportedOperationError("File Management module is required for Automation Script".to_string(), Vec::new()));
             }
 
             ApplicationType::EmbeddedSystem => {
@@ -254,8 +327,23 @@ impl CoreSystem {
                 // Inizializzazione di eventuali moduli specifici per sistemi embedded.
             }
 
+            ApplicationType::WasmBased => {
+                info!("Configurazione per moduli WebAssembly");
+                // I moduli WebAssembly utilizzano solo core e monitoring.
+            }
+
+            ApplicationType::GameServer => {
+                info!("Configurazione per Game Server");
+                // Il ciclo di gioco scambia i pool del MemoryManager con `swap_buffers` ad ogni frame.
+            }
+
+            ApplicationType::MLInference => {
+                info!("Configurazione per inferenza di Machine Learning");
+                // I tensori vengono allocati su pagine huge con `MemoryManager::allocate_huge_page`.
+            }
+
             _ => {
-                return Err(CoreError::ConfigurationError("Tipo di applicazione non supportato considera implementazione".to_string()));
+                return Err(CoreError::ConfigurationError("Tipo di applicazione non supportato considera implementazione".to_string(), Vec::new()));
             }
         }
 
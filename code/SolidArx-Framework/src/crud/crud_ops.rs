@@ -32,6 +32,8 @@ cfg_if! {
 }
 
 use crate::core::memory_management::{AllocationStrategy, MemoryManager};
+#[cfg(feature = "async")]
+use crate::core::memory_management::CRUD_MEMORY;
 use crate::config::memory_config::MemoryConfig;
 
 //DATABASE
@@ -53,12 +55,40 @@ use crate::core::memory_management::{
     COMMANDS_IN_MEMORY
 };
 
-#[derive(Debug,Clone)]
+/// Enum per rappresentare gli errori delle operazioni sugli store CRUD.
+#[derive(Debug)]
+pub enum CrudError {
+    NotFound(u32),
+    ValidationFailed(String),
+    LockError(String),
+    Timeout,
+    QueueFull,
+    Conflict(u32),
+    ExecutionFailed(String),
+}
+
+impl std::fmt::Display for CrudError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CrudError::NotFound(id) => write!(f, "Elemento con ID {} non trovato", id),
+            CrudError::ValidationFailed(msg) => write!(f, "Validazione fallita: {}", msg),
+            CrudError::LockError(msg) => write!(f, "Errore di lock sul mutex: {}", msg),
+            CrudError::Timeout => write!(f, "Tempo di attesa scaduto"),
+            CrudError::QueueFull => write!(f, "Coda piena: numero massimo di elementi in attesa raggiunto"),
+            CrudError::Conflict(id) => write!(f, "Conflitto: l'elemento con ID {} è già bloccato", id),
+            CrudError::ExecutionFailed(msg) => write!(f, "Esecuzione fallita: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for CrudError {}
+
+#[derive(Debug,Clone,PartialEq,Eq)]
 pub enum AllocType {
     InMemory,
     Database,
 }
-#[derive(Debug,Clone)]
+#[derive(Debug,Clone,PartialEq,Eq)]
 pub struct CrudOperations {
     pub create: bool,
     pub read: bool,
@@ -155,6 +185,9 @@ macro_rules! impl_crud_ops {
                         let size: usize = (1024 * memory_scale).into();
                 
                         // Allocazione della memoria per il Task
+                        #[cfg(feature = "async")]
+                        let task_memory = CRUD_MEMORY.allocate(Some(AllocationStrategy::Standard), size).map_err(|e| e.to_string())?.into_boxed_slice();
+                        #[cfg(not(feature = "async"))]
                         let task_memory = MemoryManager::allocate(,Some(AllocationStrategy::Standard), size);
                 
                         // Creazione del Task con i dati ricevuti
@@ -192,6 +225,9 @@ macro_rules! impl_crud_ops {
                     _ => {
                         info!("Allocazione in memoria di Default per modello non gestito specificamente");
                         let size = (1024 * memory_scale).into(); // Size per modelli non gestiti dedicati 1024 byte per range applicativo di media 
+                        #[cfg(feature = "async")]
+                        CRUD_MEMORY.allocate(None, size).map_err(|e| e.to_string())?;
+                        #[cfg(not(feature = "async"))]
                         MemoryManager::allocate(,None, size)?;
                         return Ok(item);
                     }
@@ -215,7 +251,7 @@ macro_rules! impl_crud_ops {
                 match std::any::type_name::<$model>() {
                     // Task (InMemory)
                     "modules::default::task_model::Task" => {
-                        let tasks = TASKS_IN_MEMORY.lock().map_err(|e| format!("Errore di lock sul mutex: {}", e))?;
+                        let mut tasks = TASKS_IN_MEMORY.lock().map_err(|e| format!("Errore di lock sul mutex: {}", e))?;
                         if let Some(task) = tasks.get(&id) {
                             return Ok(task.clone());
                         } else {
@@ -1,5 +1,6 @@
 pub mod crud_ops;
 pub mod models;
+pub mod stores;
 
 pub fn initialize() -> Result<(), String> {
     // Logica di inizializzazione per CRUD
@@ -13,6 +13,28 @@ pub mod model {
 
             use crate::crud::crud_ops::AllocType;
             use crate::crud::crud_ops::CrudOperations;
+
+            /// Enum per la priorità di esecuzione dei comandi, dalla più alta alla più bassa
+            #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+            pub enum CommandPriority {
+                Critical,
+                High,
+                #[default]
+                Normal,
+                Low,
+            }
+
+            impl CommandPriority {
+                /// Rango della priorità: un valore maggiore indica un comando da eseguire prima
+                pub fn rank(self) -> u8 {
+                    match self {
+                        CommandPriority::Critical => 3,
+                        CommandPriority::High => 2,
+                        CommandPriority::Normal => 1,
+                        CommandPriority::Low => 0,
+                    }
+                }
+            }
             
             #[derive(Debug, Clone)]
             pub struct Command {
@@ -20,6 +42,16 @@ pub mod model {
                 pub device_id: u32,
                 pub command_type: String,
                 pub issued_at: String,
+                pub priority: CommandPriority,  // Ordine di estrazione da `COMMANDS_IN_MEMORY`
+
+                // Campi specifici per `webhooks`
+                #[cfg(feature = "webhooks")]
+                pub webhook_url: Option<String>,  // URL a cui inviare il payload JSON del comando
+                #[cfg(feature = "webhooks")]
+                pub max_webhook_retries: u32,  // Numero massimo di tentativi in caso di risposta non 2xx
+                #[cfg(feature = "webhooks")]
+                pub timeout: u64,  // Tempo massimo di attesa (in secondi) per la risposta del webhook
+
                 pub store: AllocType,
                 pub memory: Box<[u8]>,
                 pub ops: CrudOperations,
@@ -39,6 +71,13 @@ pub mod model {
                         device_id,
                         command_type,
                         issued_at,
+                        priority: CommandPriority::Normal,
+                        #[cfg(feature = "webhooks")]
+                        webhook_url: None,
+                        #[cfg(feature = "webhooks")]
+                        max_webhook_retries: 0,
+                        #[cfg(feature = "webhooks")]
+                        timeout: 30,
                         store: AllocType::InMemory,
                         memory,
                         ops: CrudOperations{
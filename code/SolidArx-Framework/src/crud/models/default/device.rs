@@ -14,11 +14,13 @@ pub mod model {
             use crate::crud::crud_ops::AllocType;
             use crate::crud::crud_ops::CrudOperations;
 
-            #[derive(Debug, Clone)]
+            // `Eq` è usato da `upsert_devices` per riconoscere i dispositivi invariati
+            #[derive(Debug, Clone, PartialEq, Eq)]
             pub struct Device {
                 pub id: u32,
                 pub name: String,
                 pub device_type: String,
+                pub firmware_version: String,  // Versione semantica del firmware installato
                 pub store: AllocType,
                 pub memory: Box<[u8]>,
                 pub ops: CrudOperations,
@@ -30,6 +32,7 @@ pub mod model {
                         id, 
                         name,
                         device_type: "default".to_string(),
+                        firmware_version: "0.0.0".to_string(),
                         store: AllocType::InMemory,
                         memory,
                         ops: CrudOperations{
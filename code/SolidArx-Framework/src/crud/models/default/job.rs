@@ -13,11 +13,25 @@ pub mod model {
             use crate::crud::crud_ops::AllocType;
             use crate::crud::crud_ops::CrudOperations;
 
+            /// Enum per lo stato di esecuzione dei job
+            #[derive(Debug, Clone, PartialEq)]
+            pub enum JobStatus {
+                Pending,
+                Running,
+                Completed,
+                Failed,
+            }
+
             #[derive(Debug, Clone)]
             pub struct Job {
                 pub id: u32,
                 pub name: String,
                 pub description: String,
+                pub status: JobStatus,                    // Stato di esecuzione del job
+                pub output: Option<serde_json::Value>,    // Risultato prodotto dal job al completamento
+                pub started_at: Option<i64>,              // Istante di avvio (Unix millisecondi)
+                pub completed_at: Option<i64>,            // Istante di completamento (Unix millisecondi)
+                pub dependencies: Vec<u32>,               // ID dei job che devono concludersi prima di questo
                 pub store: AllocType,
                 pub memory: Box<[u8]>,
                 pub ops: CrudOperations,
@@ -34,6 +48,11 @@ pub mod model {
                         id,
                         name,
                         description,
+                        status: JobStatus::Pending,
+                        output: None,
+                        started_at: None,
+                        completed_at: None,
+                        dependencies: Vec::new(),
                         store: AllocType::InMemory,
                         memory,
                         ops: CrudOperations{
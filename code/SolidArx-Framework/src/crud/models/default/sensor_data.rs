@@ -20,6 +20,8 @@ pub mod model {
                 pub device_id: u32,
                 pub timestamp: String,
                 pub data: String,  // Puoi specificare il formato dei dati se necessario
+                pub sensor_id: u32,  // Sensore che ha prodotto la lettura, di default coincide con il dispositivo
+                pub value: f64,  // Valore numerico della lettura, `NaN` se i dati grezzi non sono numerici
                 pub store: AllocType,
                 pub memory: Box<[u8]>,
                 pub ops: CrudOperations,
@@ -33,11 +35,16 @@ pub mod model {
                     data: String,
                     memory: Box<[u8]>,
                 ) -> Self {
+                    // Il valore numerico viene ricavato dai dati grezzi quando possibile: i dati non numerici
+                    // danno `NaN`, così che soglie e medie li ignorino invece di trattarli come zero
+                    let value = data.trim().parse::<f64>().ok().filter(|value| value.is_finite()).unwrap_or(f64::NAN);
                     SensorData {
                         id,
                         device_id,
                         timestamp,
                         data,
+                        sensor_id: device_id,
+                        value,
                         store: AllocType::InMemory,
                         memory,
                         ops:CrudOperations{
@@ -14,6 +14,15 @@ pub mod model {
             use crate::crud::crud_ops::AllocType;
             use crate::crud::crud_ops::CrudOperations;
 
+            /// Enum per lo stato di esecuzione dei task
+            #[derive(Debug, Clone, PartialEq)]
+            pub enum TaskStatus {
+                Pending,
+                Running,
+                Completed,
+                Failed,
+            }
+
             #[derive(Debug, Clone)]
             pub struct Task {
                 pub id: u32,
@@ -31,6 +40,8 @@ pub mod model {
                 #[cfg(feature = "embedded")]
                 pub device_id: Option<u32>,  // Associa il task a un dispositivo
 
+                pub status: TaskStatus,               // Stato di esecuzione del task
+                pub completed_at: Option<i64>,        // Istante di completamento (Unix millisecondi)
                 pub store: AllocType,
                 pub memory: Box<[u8]>,
                 pub ops: CrudOperations,
@@ -55,6 +66,8 @@ pub mod model {
                         completed,
                         #[cfg(feature = "embedded")]
                         device_id,
+                        status: TaskStatus::Pending,
+                        completed_at: None,
                         store: AllocType::InMemory,
                         memory,
                         ops:CrudOperations{
@@ -1,4 +1,5 @@
 use crate::network::connection_management::{
+    ConnectionErrors,
     ConnectionManager, 
     DbConnection, 
     DatabaseConnection
@@ -8,20 +9,22 @@ use diesel::{
     sqlite::SqliteConnection,
     RunQueryDsl,
 };
+#[cfg(feature = "mysql")]
+use diesel::mysql::MysqlConnection;
 use mongodb::bson::Bson;
 use std::collections::HashMap;
 use log::{info};
 
 // Definisce un errore personalizzato per la generazione delle tabelle
 enum TableGeneratorError {
-    DieselConnectionError(diesel::ConnectionError),
+    ConnectionError(ConnectionErrors),
     DieselError(diesel::result::Error),
     MongoError(mongodb::error::Error),
     UnknownError(String),
 }
-impl From<diesel::ConnectionError> for TableGeneratorError {
-    fn from(err: diesel::ConnectionError) -> Self {
-        TableGeneratorError::DieselConnectionError(err)
+impl From<ConnectionErrors> for TableGeneratorError {
+    fn from(err: ConnectionErrors) -> Self {
+        TableGeneratorError::ConnectionError(err)
     }
 }
 impl From<diesel::result::Error> for TableGeneratorError {
@@ -104,6 +107,27 @@ fn create_sqlite_table(
     Ok(())
 }
 
+/// Crea una tabella in MySQL/MariaDB se non esiste già.
+///
+/// # Parametri
+/// - `connection`: Connessione a MySQL.
+/// - `table_name`: Nome della tabella da creare.
+/// - `fields`: Mappa dei campi della tabella con i relativi tipi.
+#[cfg(feature = "mysql")]
+fn create_mysql_table(
+    connection: &mut MysqlConnection, 
+    table_name: &str, 
+    fields: &HashMap<&str, &str>
+) -> Result<(), TableGeneratorError> {
+    create_table(
+        |query| diesel::sql_query(query).execute(connection), 
+        table_name, 
+        fields
+    );
+    info!("Tabella MySQL {} creata", table_name);
+    Ok(())
+}
+
 /// Mappa il tipo Rust in una rappresentazione BSON di MongoDB
 /// 
 /// # Parametri
@@ -135,6 +159,7 @@ fn map_to_bson(type_name: &str) -> Bson {
         "Box<[u8]>" =>  Bson::String(String::new()),
         "ExeLogStatus" | "MacroStatus" | "ProjectStatus" => Bson::String(String::new()),
         "ExecutionFrequency" => Bson::String(String::new()),
+        "CommandPriority" => Bson::String(String::new()),
         "Option<ProjectMetadata>" => Bson::String(String::new()),
         "chrono::NaiveDateTime" => Bson::String(String::new()),
         _ => Bson::String(String::new()), // Default per tipi sconosciuti
@@ -267,6 +292,29 @@ pub async fn generate_tables(
                 info!("Tabella {:?} creata su SQLite", table_name_str);
             }
         }
+        #[cfg(feature = "mysql")]
+        DbConnection::MySQL(mut mysql_conn) => {
+            for struct_info in structs_converted {
+                let table_name = struct_info.get("name");
+                let table_name_str = match table_name {
+                    Some(table) => *table.get("name").unwrap_or(&"default_table"),
+                    None => "default_table",
+                };
+                // Estrai i campi dalla mappa
+                let fields = struct_info
+                    .get("fields")
+                    .cloned()
+                    .unwrap_or_else(HashMap::new);
+                // Crea la tabella in MySQL
+                create_mysql_table(&mut mysql_conn, table_name_str, &fields)?;
+                info!("Tabella {:?} creata su MySQL", table_name_str);
+            }
+        }
+        // Redis non ha tabelle: le chiavi vengono create alla prima scrittura
+        #[cfg(feature = "redis")]
+        DbConnection::Redis(_) => {
+            info!("Redis non richiede la creazione di tabelle: {} modelli ignorati", structs_converted.len());
+        }
         DbConnection::MongoDB(mut mongo_client) => {
             for struct_info in structs_converted {
                 let collection_name = struct_info.get("name");
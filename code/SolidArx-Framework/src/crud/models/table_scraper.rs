@@ -66,6 +66,7 @@ fn map_to_sql(type_name: &str) -> &str {
         "Box<[u8]>" => "BYTEA", // Tipico per dati binari
         "ExeLogStatus" | "MacroStatus" | "ProjectStatus" => "TEXT CHECK (value IN ('Active', 'Disabled', 'Completed'))", // Enum con vincoli
         "ExecutionFrequency" => "TEXT", // Frequenze come stringhe
+        "CommandPriority" => "TEXT CHECK (value IN ('Critical', 'High', 'Normal', 'Low'))",
         "Option<ProjectMetadata>" => "JSON NULL", // Serializzato come JSON
         "chrono::NaiveDateTime" => "TIMESTAMP", // Data e ora
         _ => "TEXT", // Default per tipi sconosciuti
@@ -101,6 +102,7 @@ fn map_to_mongo(type_name: &str) -> &str {
         "Box<[u8]>" => "binData",
         "ExeLogStatus" | "MacroStatus" | "ProjectStatus" => "string",
         "ExecutionFrequency" => "string",
+        "CommandPriority" => "string",
         "Option<ProjectMetadata>" => "object",
         "chrono::NaiveDateTime" => "date",
         _ => "string",
@@ -214,6 +216,11 @@ pub fn scrape(directory: &str, db_type: DatabaseType) -> Result<Vec<HashMap<Stri
                 let field_type = match db_type {
                     DatabaseType::PostgreSQL(_) => map_to_sql(&field_type_str),
                     DatabaseType::SQLite(_) => map_to_sql(&field_type_str),
+                    #[cfg(feature = "mysql")]
+                    DatabaseType::MySQL(_) => map_to_sql(&field_type_str),
+                    // Redis non ha uno schema: si usano i tipi generici come per MongoDB
+                    #[cfg(feature = "redis")]
+                    DatabaseType::Redis(_) => map_to_mongo(&field_type_str),
                     DatabaseType::MongoDB(_) => map_to_mongo(&field_type_str),
                     DatabaseType::None => panic!("Operazione impossibile, Database non configurato"),
                 };
@@ -0,0 +1,264 @@
+//! Modulo per l'esecuzione dei `Command` mantenuti in `COMMANDS_IN_MEMORY` o nel `MemoryRegistry` indicato.
+//!
+//! Il `CommandExecutor` associa ad ogni `command_type` un handler registrato dall'applicazione.
+//! Con la feature `webhooks` attiva, i comandi che definiscono un `webhook_url` vengono inoltre
+//! inviati come payload JSON al servizio esterno indicato.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use lazy_static::lazy_static;
+use log::{info, error};
+#[cfg(feature = "webhooks")]
+use log::warn;
+
+use crate::core::memory_management::MemoryRegistry;
+use crate::crud::models::default::command::model::Command;
+
+/// Attesa prima del primo nuovo tentativo di invio del webhook, raddoppiata ad ogni tentativo successivo.
+#[cfg(feature = "webhooks")]
+const WEBHOOK_BACKOFF_BASE: Duration = Duration::from_millis(100);
+/// Attesa massima tra due tentativi di invio del webhook.
+#[cfg(feature = "webhooks")]
+const WEBHOOK_BACKOFF_CAP: Duration = Duration::from_secs(5);
+
+/// Enum per rappresentare gli errori di esecuzione di un `Command`.
+#[derive(Debug)]
+pub enum CommandError {
+    NotFound(u32),
+    HandlerMissing(String),
+    HandlerFailed(String),
+    LockError(String),
+    WebhookFailed { status: u16, body: String },
+    WebhookTransport(String),
+}
+
+impl std::fmt::Display for CommandError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CommandError::NotFound(id) => write!(f, "Command con ID {} non trovato", id),
+            CommandError::HandlerMissing(command_type) => write!(f, "Nessun handler registrato per il tipo di comando '{}'", command_type),
+            CommandError::HandlerFailed(msg) => write!(f, "Errore nell'esecuzione del comando: {}", msg),
+            CommandError::LockError(msg) => write!(f, "Errore di lock sul mutex: {}", msg),
+            CommandError::WebhookFailed { status, body } => write!(f, "Webhook fallito con stato {}: {}", status, body),
+            CommandError::WebhookTransport(msg) => write!(f, "Invio del webhook fallito: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for CommandError {}
+
+/// Funzione handler associata ad un tipo di comando.
+pub type CommandHandler = Box<dyn Fn(&Command) -> Result<(), String> + Send + Sync>;
+
+/// Funzione handler che esegue un sotto-processo e ne restituisce l'output.
+pub type OutputHandler = Box<dyn Fn(&Command) -> Result<std::process::Output, String> + Send + Sync>;
+
+/// Output catturato dall'esecuzione di un comando.
+/// - `stdout`/`stderr`: Output del sotto-processo, convertiti in UTF-8.
+/// - `exit_code`: Codice di uscita, `-1` se il processo è stato terminato da un segnale.
+/// - `duration`: Durata dell'esecuzione dell'handler.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CommandOutput {
+    pub stdout: String,
+    pub stderr: String,
+    pub exit_code: i32,
+    pub duration: Duration,
+}
+
+// Ultimo output catturato per ciascun Command
+lazy_static! {
+    pub static ref COMMAND_OUTPUTS: Mutex<HashMap<u32, CommandOutput>> = Mutex::new(HashMap::new());
+}
+
+/// Struttura `CommandExecutor`
+///
+/// Mantiene la mappa `command_type -> handler` utilizzata per eseguire i comandi in memoria.
+pub struct CommandExecutor {
+    handlers: HashMap<String, CommandHandler>,
+    output_handlers: HashMap<String, OutputHandler>,
+    registry: Arc<MemoryRegistry>,  // Registro da cui leggere la coda dei comandi
+    #[cfg(feature = "webhooks")]
+    client: reqwest::Client,
+}
+
+impl Default for CommandExecutor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CommandExecutor {
+    /// Crea un nuovo `CommandExecutor` senza handler registrati, sulla coda del registro predefinito.
+    pub fn new() -> Self {
+        Self::with_registry(MemoryRegistry::global())
+    }
+
+    /// Crea un nuovo `CommandExecutor` senza handler registrati, sulla coda del registro indicato.
+    ///
+    /// # Parametri
+    /// - `registry`: Il `MemoryRegistry` da cui leggere i comandi da eseguire.
+    pub fn with_registry(registry: Arc<MemoryRegistry>) -> Self {
+        CommandExecutor {
+            handlers: HashMap::new(),
+            output_handlers: HashMap::new(),
+            registry,
+            #[cfg(feature = "webhooks")]
+            client: reqwest::Client::new(),
+        }
+    }
+
+    /// Registra l'handler da utilizzare per i comandi del tipo indicato.
+    ///
+    /// # Parametri
+    /// - `command_type`: Il tipo di comando (`Command::command_type`) gestito dall'handler.
+    /// - `handler`: La funzione da eseguire per il comando.
+    pub fn register_handler(&mut self, command_type: &str, handler: CommandHandler) {
+        self.handlers.insert(command_type.to_string(), handler);
+    }
+
+    /// Registra l'handler con output da utilizzare in `execute_capturing_output` per il tipo indicato.
+    pub fn register_output_handler(&mut self, command_type: &str, handler: OutputHandler) {
+        self.output_handlers.insert(command_type.to_string(), handler);
+    }
+
+    /// Esegue il comando con l'ID specificato catturandone stdout, stderr e codice di uscita.
+    ///
+    /// L'output viene salvato in `COMMAND_OUTPUTS`, sostituendo quello dell'esecuzione precedente.
+    ///
+    /// # Parametri
+    /// - `id`: L'ID del comando da eseguire.
+    /// - `role`: Il ruolo del chiamante, registrato nel log dell'esecuzione.
+    ///
+    /// # Ritorna
+    /// - `Ok(CommandOutput)` con l'output catturato, anche se il processo termina con un codice diverso da 0.
+    /// - `Err(CommandError)` se il comando non esiste, manca l'handler o il processo non può essere avviato.
+    pub fn execute_capturing_output(&self, id: u32, role: &str) -> Result<CommandOutput, CommandError> {
+        let command = queued_command(&self.registry, id)?;
+        let handler = self.output_handlers
+            .get(&command.command_type)
+            .ok_or_else(|| CommandError::HandlerMissing(command.command_type.clone()))?;
+
+        info!("Esecuzione del comando {} di tipo '{}' con cattura dell'output (ruolo '{}')", command.id, command.command_type, role);
+        let started = Instant::now();
+        let output = handler(&command).map_err(|e| {
+            error!("Errore nell'esecuzione del comando {}: {}", command.id, e);
+            CommandError::HandlerFailed(e)
+        })?;
+
+        let captured = CommandOutput {
+            stdout: String::from_utf8_lossy(&output.stdout).into_owned(),
+            stderr: String::from_utf8_lossy(&output.stderr).into_owned(),
+            exit_code: output.status.code().unwrap_or(-1),
+            duration: started.elapsed(),
+        };
+        COMMAND_OUTPUTS
+            .lock()
+            .map_err(|e| CommandError::LockError(e.to_string()))?
+            .insert(id, captured.clone());
+        Ok(captured)
+    }
+
+    /// Esegue il comando con l'ID specificato.
+    ///
+    /// Se è registrato un handler per il `command_type` viene eseguito, dopodiché, se il comando
+    /// definisce un `webhook_url`, il suo payload JSON viene inviato al webhook.
+    ///
+    /// # Ritorna
+    /// - `Ok(())` se il comando è stato eseguito con successo.
+    /// - `Err(CommandError)` se il comando non esiste, l'handler fallisce o il webhook risponde con un errore.
+    pub async fn execute(&self, id: u32) -> Result<(), CommandError> {
+        let command = queued_command(&self.registry, id)?;
+
+        info!("Esecuzione del comando {} di tipo '{}'", command.id, command.command_type);
+
+        match self.handlers.get(&command.command_type) {
+            Some(handler) => handler(&command).map_err(|e| {
+                error!("Errore nell'esecuzione del comando {}: {}", command.id, e);
+                CommandError::HandlerFailed(e)
+            })?,
+            None => {
+                // Un comando senza handler è comunque valido se deve solo essere inoltrato al webhook
+                #[cfg(feature = "webhooks")]
+                let forwarded = command.webhook_url.is_some();
+                #[cfg(not(feature = "webhooks"))]
+                let forwarded = false;
+
+                if !forwarded {
+                    return Err(CommandError::HandlerMissing(command.command_type.clone()));
+                }
+            }
+        }
+
+        #[cfg(feature = "webhooks")]
+        if let Some(url) = command.webhook_url.as_deref() {
+            self.dispatch_webhook(&command, url).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Invia il payload JSON del comando al webhook, ritentando in caso di risposta non 2xx o di
+    /// errore di trasporto fino a `max_webhook_retries` volte.
+    ///
+    /// Prima del tentativo `n` (da 1) si attende `WEBHOOK_BACKOFF_BASE * 2^(n-1)`, con un massimo
+    /// di `WEBHOOK_BACKOFF_CAP`.
+    ///
+    /// # Ritorna
+    /// - `Err(CommandError::WebhookFailed)`: Se l'ultimo tentativo riceve una risposta non 2xx.
+    /// - `Err(CommandError::WebhookTransport)`: Se l'ultimo tentativo non riceve risposta (timeout, connessione rifiutata).
+    #[cfg(feature = "webhooks")]
+    async fn dispatch_webhook(&self, command: &Command, url: &str) -> Result<(), CommandError> {
+        let payload = serde_json::json!({
+            "id": command.id,
+            "device_id": command.device_id,
+            "command_type": command.command_type,
+            "issued_at": command.issued_at,
+        });
+
+        let mut attempts = 0;
+        loop {
+            let result = self.client
+                .post(url)
+                .timeout(std::time::Duration::from_secs(command.timeout))
+                .json(&payload)
+                .send()
+                .await;
+
+            let failure = match result {
+                Ok(response) if response.status().is_success() => {
+                    info!("Webhook del comando {} inviato con successo", command.id);
+                    return Ok(());
+                }
+                Ok(response) => {
+                    let status = response.status().as_u16();
+                    CommandError::WebhookFailed { status, body: response.text().await.unwrap_or_default() }
+                }
+                // Errori di trasporto (timeout, connessione rifiutata) senza uno stato HTTP
+                Err(e) => CommandError::WebhookTransport(e.to_string()),
+            };
+
+            if attempts >= command.max_webhook_retries {
+                error!("Webhook del comando {} fallito dopo {} tentativi: {}", command.id, attempts + 1, failure);
+                return Err(failure);
+            }
+
+            let delay = WEBHOOK_BACKOFF_BASE
+                .saturating_mul(2u32.saturating_pow(attempts))
+                .min(WEBHOOK_BACKOFF_CAP);
+            attempts += 1;
+            warn!("Webhook del comando {} fallito ({}), tentativo {} tra {} ms", command.id, failure, attempts, delay.as_millis());
+            tokio::time::sleep(delay).await;
+        }
+    }
+}
+
+/// Restituisce una copia del comando in coda con l'ID specificato, senza estrarlo.
+fn queued_command(registry: &MemoryRegistry, id: u32) -> Result<Command, CommandError> {
+    let commands = registry.commands().lock().map_err(|e| CommandError::LockError(e.to_string()))?;
+    commands
+        .iter()
+        .find(|entry| entry.command.id == id)
+        .map(|entry| entry.command.clone())
+        .ok_or(CommandError::NotFound(id))
+}
@@ -0,0 +1,165 @@
+//! Modulo per la gestione delle `Configuration` mantenute in `CONFIGURATIONS_IN_MEMORY` o nel `MemoryRegistry` indicato con `with_registry`.
+//!
+//! Il `ConfigurationStore` espone le operazioni specifiche delle configurazioni, come
+//! l'esportazione e l'importazione nel formato `.env` utilizzato dagli strumenti di deployment.
+
+use std::sync::Arc;
+use crate::core::memory_management::MemoryRegistry;
+use crate::crud::crud_ops::CrudError;
+use crate::crud::models::default::configuration::model::Configuration;
+
+/// Verifica se il testo va racchiuso tra doppi apici per essere rappresentato in `.env`.
+fn needs_quotes(text: &str) -> bool {
+    text.is_empty() || text.chars().any(|c| c.is_whitespace() || matches!(c, '=' | '"' | '\'' | '#' | '\\'))
+}
+
+/// Formatta una chiave o un valore per il formato `.env`, con gli escape `\\`, `\"`, `\n`, `\r`, `\t`.
+fn dotenv_token(text: &str) -> String {
+    if !needs_quotes(text) {
+        return text.to_string();
+    }
+    let mut quoted = String::with_capacity(text.len() + 2);
+    quoted.push('"');
+    for c in text.chars() {
+        match c {
+            '\\' => quoted.push_str("\\\\"),
+            '"' => quoted.push_str("\\\""),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            _ => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+/// Legge un token tra doppi apici a partire dall'apice iniziale, restituendo il testo e il resto della riga.
+fn parse_quoted(line: &str) -> Result<(String, &str), CrudError> {
+    let mut text = String::new();
+    let mut chars = line.char_indices().skip(1);
+    while let Some((index, c)) = chars.next() {
+        match c {
+            '"' => return Ok((text, &line[index + 1..])),
+            '\\' => match chars.next() {
+                Some((_, 'n')) => text.push('\n'),
+                Some((_, 'r')) => text.push('\r'),
+                Some((_, 't')) => text.push('\t'),
+                Some((_, escaped)) => text.push(escaped),
+                None => break,
+            },
+            _ => text.push(c),
+        }
+    }
+    Err(CrudError::ValidationFailed(format!("Apice di chiusura mancante in {:?}", line)))
+}
+
+/// Interpreta una riga `KEY=value` del formato `.env`.
+fn parse_entry(line: &str) -> Result<(String, String), CrudError> {
+    let line = line.trim_start().strip_prefix("export ").unwrap_or(line.trim_start());
+    let (key, rest) = if line.starts_with('"') {
+        parse_quoted(line)?
+    } else {
+        let end = line.find('=').unwrap_or(line.len());
+        (line[..end].trim_end().to_string(), &line[end..])
+    };
+    let rest = rest
+        .trim_start()
+        .strip_prefix('=')
+        .ok_or_else(|| CrudError::ValidationFailed(format!("Separatore `=` mancante nella voce {:?}", line)))?
+        .trim_start();
+
+    let value = if rest.starts_with('"') {
+        let (value, trailing) = parse_quoted(rest)?;
+        let trailing = trailing.trim();
+        if !trailing.is_empty() && !trailing.starts_with('#') {
+            return Err(CrudError::ValidationFailed(format!("Testo inatteso dopo il valore: {:?}", trailing)));
+        }
+        value
+    } else {
+        rest.trim_end().to_string()
+    };
+    if key.is_empty() {
+        return Err(CrudError::ValidationFailed("Chiave vuota nella voce `.env`".to_string()));
+    }
+    Ok((key, value))
+}
+
+/// Struttura `ConfigurationStore`
+///
+/// Punto di accesso alle operazioni specifiche delle `Configuration` in memoria.
+#[derive(Debug, Clone)]
+pub struct ConfigurationStore {
+    registry: Arc<MemoryRegistry>,
+}
+
+impl Default for ConfigurationStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ConfigurationStore {
+    /// Crea un nuovo `ConfigurationStore` che opera sul registro predefinito (`MemoryRegistry::global`).
+    pub fn new() -> Self {
+        Self::with_registry(MemoryRegistry::global())
+    }
+
+    /// Crea un nuovo `ConfigurationStore` che opera sulle mappe del registro indicato.
+    pub fn with_registry(registry: Arc<MemoryRegistry>) -> Self {
+        ConfigurationStore { registry }
+    }
+
+    /// Esporta la configurazione con l'ID specificato nel formato `.env` (`KEY=value\n`).
+    ///
+    /// Chiavi e valori che contengono spazi, newline, `=`, apici, `#` o `\` vengono racchiusi
+    /// tra doppi apici con i caratteri speciali preceduti da `\`, così da poter essere riletti
+    /// esattamente con `from_dotenv`.
+    ///
+    /// # Ritorna
+    /// La voce `.env` della configurazione, oppure `CrudError::NotFound` se non esiste.
+    pub fn to_dotenv(&self, id: u32) -> Result<String, CrudError> {
+        let mut configurations = self.registry.configurations().lock().map_err(|e| CrudError::LockError(e.to_string()))?;
+        let configuration = configurations.get(&id).ok_or(CrudError::NotFound(id))?;
+        Ok(format!("{}={}\n", dotenv_token(&configuration.key), dotenv_token(&configuration.value)))
+    }
+
+    /// Crea una `Configuration` a partire da una voce nel formato `.env`.
+    ///
+    /// Le righe vuote e i commenti (`#`) vengono ignorati; è ammesso il prefisso `export`.
+    ///
+    /// # Ritorna
+    /// - `Ok(Configuration)`: La configurazione con il primo ID libero in `CONFIGURATIONS_IN_MEMORY`.
+    /// - `Err(CrudError::ValidationFailed)`: Se il contenuto non contiene esattamente una voce valida.
+    ///
+    /// # Nota
+    /// La configurazione restituita non viene inserita in memoria.
+    pub fn from_dotenv(&self, content: &str) -> Result<Configuration, CrudError> {
+        let mut entries = Vec::new();
+        for line in content.lines() {
+            let trimmed = line.trim();
+            if trimmed.is_empty() || trimmed.starts_with('#') {
+                continue;
+            }
+            entries.push(parse_entry(line)?);
+        }
+        if entries.len() != 1 {
+            return Err(CrudError::ValidationFailed(format!(
+                "Attesa una sola voce `.env` per configurazione, trovate {}", entries.len()
+            )));
+        }
+        let (key, value) = entries.remove(0);
+
+        let id = {
+            let configurations = self.registry.configurations().lock().map_err(|e| CrudError::LockError(e.to_string()))?;
+            configurations.keys().max().map_or(1, |max| max + 1)
+        };
+        Ok(Configuration::new(
+            id,
+            #[cfg(feature = "embedded")] None,
+            key,
+            value,
+            Vec::new().into_boxed_slice(),
+        ))
+    }
+}
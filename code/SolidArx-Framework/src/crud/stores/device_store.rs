@@ -0,0 +1,272 @@
+//! Modulo per la gestione dei `Device` mantenuti in `DEVICES_IN_MEMORY` o nel `MemoryRegistry` indicato con `with_registry`.
+//!
+//! Il `DeviceStore` espone le operazioni specifiche dei dispositivi, come la coda FIFO
+//! dei comandi indirizzati a ciascun dispositivo, gli aggiornamenti del firmware e
+//! l'acquisizione della telemetria esportata in CSV dai gateway.
+
+use std::collections::{HashMap, VecDeque};
+use std::io::Read;
+use std::sync::{Arc, Mutex};
+use lazy_static::lazy_static;
+use log::{info, warn, error};
+use semver::Version;
+
+use crate::core::memory_management::MemoryRegistry;
+use crate::crud::crud_ops::CrudError;
+use crate::crud::models::default::command::model::Command;
+use crate::crud::models::default::sensor_data::model::SensorData;
+use crate::crud::stores::now_millis;
+use crate::crud::stores::sensor_data_store::SensorDataStore;
+
+/// Enum per lo stato di un aggiornamento firmware
+#[derive(Debug, Clone, PartialEq)]
+pub enum UpdateStatus {
+    Pending,
+    InProgress,
+    Completed,
+    Failed,
+}
+
+/// Aggiornamento firmware pianificato per un dispositivo.
+/// - `url`: Indirizzo da cui scaricare il firmware.
+/// - `target_version`: Versione del firmware dopo l'aggiornamento.
+/// - `scheduled_at`: Istante di pianificazione (Unix millisecondi).
+/// - `completed_at`: Istante di completamento (Unix millisecondi), se concluso.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FirmwareUpdate {
+    pub id: u32,
+    pub device_id: u32,
+    pub url: String,
+    pub target_version: Version,
+    pub status: UpdateStatus,
+    pub scheduled_at: i64,
+    pub completed_at: Option<i64>,
+}
+
+// Aggiornamenti firmware pianificati per i Device
+lazy_static! {
+    pub static ref FIRMWARE_UPDATES: Mutex<HashMap<u32, FirmwareUpdate>> = Mutex::new(HashMap::new());
+}
+
+/// Trait per il download del firmware, implementato dall'applicazione (o da un mock nei test).
+pub trait FirmwareDownloader {
+    /// Scarica l'immagine del firmware dall'indirizzo indicato.
+    fn download(&self, url: &str) -> Result<Vec<u8>, String>;
+}
+
+/// Struttura `DeviceStore`
+///
+/// Punto di accesso alle operazioni specifiche dei `Device` in memoria.
+#[derive(Debug, Clone)]
+pub struct DeviceStore {
+    registry: Arc<MemoryRegistry>,
+}
+
+impl Default for DeviceStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DeviceStore {
+    /// Crea un nuovo `DeviceStore` che opera sul registro predefinito (`MemoryRegistry::global`).
+    pub fn new() -> Self {
+        Self::with_registry(MemoryRegistry::global())
+    }
+
+    /// Crea un nuovo `DeviceStore` che opera sulle mappe del registro indicato.
+    pub fn with_registry(registry: Arc<MemoryRegistry>) -> Self {
+        DeviceStore { registry }
+    }
+
+    /// Verifica che il dispositivo con l'ID specificato sia presente in memoria.
+    fn ensure_device(&self, device_id: u32) -> Result<(), CrudError> {
+        let devices = self.registry.devices().lock().map_err(|e| CrudError::LockError(e.to_string()))?;
+        if devices.contains_key(&device_id) {
+            Ok(())
+        } else {
+            Err(CrudError::NotFound(device_id))
+        }
+    }
+
+    /// Accoda un comando per il dispositivo specificato.
+    ///
+    /// # Ritorna
+    /// `Ok(())` se il comando è stato accodato, `CrudError::NotFound` se il dispositivo non esiste.
+    pub fn enqueue_command(&self, device_id: u32, cmd: Command) -> Result<(), CrudError> {
+        self.ensure_device(device_id)?;
+        let mut queues = self.registry.device_command_queues().lock().map_err(|e| CrudError::LockError(e.to_string()))?;
+        info!("Comando {} accodato per il dispositivo {}", cmd.id, device_id);
+        queues.entry(device_id).or_insert_with(VecDeque::new).push_back(cmd);
+        Ok(())
+    }
+
+    /// Estrae il prossimo comando in coda per il dispositivo specificato.
+    ///
+    /// # Ritorna
+    /// `Ok(Some(Command))` se presente, `Ok(None)` se la coda è vuota, `CrudError::NotFound` se il dispositivo non esiste.
+    pub fn dequeue_command(&self, device_id: u32) -> Result<Option<Command>, CrudError> {
+        self.ensure_device(device_id)?;
+        let mut queues = self.registry.device_command_queues().lock().map_err(|e| CrudError::LockError(e.to_string()))?;
+        Ok(queues.get_mut(&device_id).and_then(|queue| queue.pop_front()))
+    }
+
+    /// Restituisce il numero di comandi in attesa per il dispositivo specificato.
+    pub fn pending_command_count(&self, device_id: u32) -> usize {
+        match self.registry.device_command_queues().lock() {
+            Ok(queues) => queues.get(&device_id).map_or(0, |queue| queue.len()),
+            Err(_) => 0,
+        }
+    }
+
+    /// Acquisisce la telemetria di un dispositivo da un file CSV con le colonne
+    /// `timestamp_epoch, value, unit` (la prima riga è l'intestazione).
+    ///
+    /// Ogni riga valida diventa un `SensorData` del dispositivo con timestamp ISO 8601 e dati
+    /// `"<value> <unit>"`; le letture vengono inserite con `SensorDataStore::insert_batch_with_new_ids`.
+    /// Le righe malformate (campi mancanti, timestamp non valido, valore non finito o unità vuota)
+    /// vengono scartate con un avviso nel log.
+    ///
+    /// # Ritorna
+    /// - `Ok(usize)`: Il numero di righe acquisite.
+    /// - `Err(CrudError::NotFound)`: Se il dispositivo non esiste.
+    /// - `Err(CrudError::ValidationFailed)`: Se la lettura del CSV fallisce.
+    pub fn ingest_csv_telemetry(&self, device_id: u32, reader: impl Read) -> Result<usize, CrudError> {
+        self.ensure_device(device_id)?;
+        let mut csv_reader = csv::ReaderBuilder::new().trim(csv::Trim::All).from_reader(reader);
+
+        let mut rows = Vec::new();
+        for (index, record) in csv_reader.records().enumerate() {
+            let line = index + 2;  // L'intestazione occupa la prima riga
+            let record = match record {
+                Ok(record) => record,
+                Err(e) if e.is_io_error() => {
+                    return Err(CrudError::ValidationFailed(format!("Lettura del CSV di telemetria fallita: {}", e)));
+                }
+                Err(e) => {
+                    warn!("Riga {} del CSV di telemetria scartata: {}", line, e);
+                    continue;
+                }
+            };
+            match parse_telemetry_row(&record) {
+                Ok(row) => rows.push(row),
+                Err(reason) => warn!("Riga {} del CSV di telemetria scartata: {}", line, reason),
+            }
+        }
+
+        // Gli ID vengono assegnati da `insert_batch_with_new_ids`, proseguendo da quello massimo già presente
+        let batch: Vec<SensorData> = rows
+            .into_iter()
+            .map(|(timestamp, value, unit)| {
+                let mut data = SensorData::new(
+                    0,
+                    device_id,
+                    timestamp,
+                    format!("{} {}", value, unit),
+                    Vec::new().into_boxed_slice(),
+                );
+                data.value = value;
+                data
+            })
+            .collect();
+
+        let ingested = SensorDataStore::with_registry(Arc::clone(&self.registry)).insert_batch_with_new_ids(batch)?;
+        info!("Acquisite {} letture di telemetria per il dispositivo {}", ingested, device_id);
+        Ok(ingested)
+    }
+
+    /// Pianifica l'aggiornamento del firmware di un dispositivo.
+    ///
+    /// # Ritorna
+    /// L'ID dell'aggiornamento in stato `Pending`, oppure `CrudError::NotFound` se il dispositivo non esiste.
+    pub fn schedule_update(&self, device_id: u32, firmware_url: &str, target_version: Version) -> Result<u32, CrudError> {
+        self.ensure_device(device_id)?;
+        let mut updates = FIRMWARE_UPDATES.lock().map_err(|e| CrudError::LockError(e.to_string()))?;
+        let id = updates.keys().max().map_or(1, |max| max + 1);
+        updates.insert(id, FirmwareUpdate {
+            id,
+            device_id,
+            url: firmware_url.to_string(),
+            target_version: target_version.clone(),
+            status: UpdateStatus::Pending,
+            scheduled_at: now_millis(),
+            completed_at: None,
+        });
+        info!("Aggiornamento {} alla versione {} pianificato per il dispositivo {}", id, target_version, device_id);
+        Ok(id)
+    }
+
+    /// Applica un aggiornamento pianificato scaricando il firmware con `downloader`.
+    ///
+    /// Al termine aggiorna `Device::firmware_version` e segna l'aggiornamento come `Completed`.
+    /// Se il download fallisce l'aggiornamento viene segnato come `Failed`.
+    ///
+    /// # Ritorna
+    /// - `Ok(())`: Se l'aggiornamento è stato applicato.
+    /// - `Err(CrudError::NotFound)`: Se l'aggiornamento o il dispositivo non esistono.
+    /// - `Err(CrudError::ValidationFailed)`: Se l'aggiornamento non è in attesa o il download fallisce.
+    pub fn apply_update(&self, update_id: u32, downloader: &dyn FirmwareDownloader) -> Result<(), CrudError> {
+        let update = {
+            let mut updates = FIRMWARE_UPDATES.lock().map_err(|e| CrudError::LockError(e.to_string()))?;
+            let update = updates.get_mut(&update_id).ok_or(CrudError::NotFound(update_id))?;
+            if update.status != UpdateStatus::Pending {
+                return Err(CrudError::ValidationFailed(format!("Aggiornamento {} non in attesa: {:?}", update_id, update.status)));
+            }
+            update.status = UpdateStatus::InProgress;
+            update.clone()
+        };
+
+        let result = downloader
+            .download(&update.url)
+            .and_then(|image| if image.is_empty() { Err("Immagine del firmware vuota".to_string()) } else { Ok(()) })
+            .map_err(CrudError::ValidationFailed)
+            .and_then(|_| {
+                let mut devices = self.registry.devices().lock().map_err(|e| CrudError::LockError(e.to_string()))?;
+                let device = devices.get_mut(&update.device_id).ok_or(CrudError::NotFound(update.device_id))?;
+                device.firmware_version = update.target_version.to_string();
+                Ok(())
+            });
+
+        let mut updates = FIRMWARE_UPDATES.lock().map_err(|e| CrudError::LockError(e.to_string()))?;
+        if let Some(stored) = updates.get_mut(&update_id) {
+            match &result {
+                Ok(()) => {
+                    stored.status = UpdateStatus::Completed;
+                    stored.completed_at = Some(now_millis());
+                    info!("Firmware del dispositivo {} aggiornato alla versione {}", update.device_id, update.target_version);
+                }
+                Err(e) => {
+                    stored.status = UpdateStatus::Failed;
+                    error!("Aggiornamento {} fallito: {}", update_id, e);
+                }
+            }
+        }
+        result
+    }
+
+    /// Restituisce una copia dell'aggiornamento con l'ID specificato.
+    pub fn firmware_update(&self, update_id: u32) -> Option<FirmwareUpdate> {
+        FIRMWARE_UPDATES.lock().ok().and_then(|updates| updates.get(&update_id).cloned())
+    }
+}
+
+/// Valida una riga del CSV di telemetria restituendo timestamp ISO 8601, valore e unità.
+fn parse_telemetry_row(record: &csv::StringRecord) -> Result<(String, f64, String), String> {
+    if record.len() != 3 {
+        return Err(format!("attese 3 colonne, trovate {}", record.len()));
+    }
+    let epoch: i64 = record[0].parse().map_err(|_| format!("timestamp non valido: {:?}", &record[0]))?;
+    let timestamp = chrono::DateTime::from_timestamp(epoch, 0)
+        .ok_or_else(|| format!("timestamp fuori intervallo: {}", epoch))?
+        .format("%Y-%m-%dT%H:%M:%S")
+        .to_string();
+    let value: f64 = record[1].parse().map_err(|_| format!("valore non valido: {:?}", &record[1]))?;
+    if !value.is_finite() {
+        return Err(format!("valore non finito: {}", value));
+    }
+    let unit = record[2].to_string();
+    if unit.is_empty() {
+        return Err("unità di misura mancante".to_string());
+    }
+    Ok((timestamp, value, unit))
+}
@@ -0,0 +1,185 @@
+//! Modulo per la gestione dei `Job` mantenuti in `JOBS_IN_MEMORY` o nel `MemoryRegistry` indicato con `with_registry`.
+//!
+//! Il `JobStore` espone le operazioni sul ciclo di vita dei job, la raccolta dei loro risultati,
+//! i lock che impediscono a due worker di eseguire lo stesso job e l'esportazione delle
+//! tempistiche per i diagrammi di Gantt.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+use serde_json::{json, Map, Value};
+use lazy_static::lazy_static;
+use log::{info, warn};
+use tokio::time::Instant;
+
+use crate::core::memory_management::MemoryRegistry;
+use crate::crud::crud_ops::CrudError;
+use crate::crud::stores::now_millis;
+use crate::crud::models::default::job::model::JobStatus;
+
+/// Identificativo progressivo dei lock acquisiti, per distinguere un lock scaduto da quello che lo ha sostituito.
+static NEXT_LOCK_TOKEN: AtomicU64 = AtomicU64::new(1);
+
+// Lock dei Job acquisiti dai worker con `JobStore::acquire_lock`
+lazy_static! {
+    pub static ref JOB_LOCKS: Mutex<HashMap<u32, JobLock>> = Mutex::new(HashMap::new());
+}
+
+/// Lock esclusivo su un `Job`, acquisito con `JobStore::acquire_lock`.
+///
+/// Il lock viene rilasciato quando l'istanza restituita da `acquire_lock` viene rilasciata,
+/// oppure considerato libero dopo `expires_at` se il worker termina senza rilasciarlo.
+/// - `holder`: Identificativo del worker che detiene il lock.
+#[derive(Debug)]
+pub struct JobLock {
+    pub job_id: u32,
+    pub holder: String,
+    pub expires_at: Instant,
+    token: u64,
+    guard: bool,  // `false` per la copia registrata in `JOB_LOCKS`, che non rilascia il lock
+}
+
+impl JobLock {
+    /// Indica se il lock è scaduto.
+    pub fn is_expired(&self) -> bool {
+        Instant::now() >= self.expires_at
+    }
+}
+
+impl Drop for JobLock {
+    fn drop(&mut self) {
+        if !self.guard {
+            return;
+        }
+        match JOB_LOCKS.lock() {
+            // Un lock scaduto e acquisito da un altro worker non viene rimosso
+            Ok(mut locks) => {
+                if locks.get(&self.job_id).map_or(false, |lock| lock.token == self.token) {
+                    locks.remove(&self.job_id);
+                }
+            }
+            Err(e) => warn!("Rilascio del lock del job {} fallito: {}", self.job_id, e),
+        }
+    }
+}
+
+/// Struttura `JobStore`
+///
+/// Punto di accesso alle operazioni specifiche dei `Job` in memoria.
+#[derive(Debug, Clone)]
+pub struct JobStore {
+    registry: Arc<MemoryRegistry>,
+}
+
+impl Default for JobStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl JobStore {
+    /// Crea un nuovo `JobStore` che opera sul registro predefinito (`MemoryRegistry::global`).
+    pub fn new() -> Self {
+        Self::with_registry(MemoryRegistry::global())
+    }
+
+    /// Crea un nuovo `JobStore` che opera sulle mappe del registro indicato.
+    pub fn with_registry(registry: Arc<MemoryRegistry>) -> Self {
+        JobStore { registry }
+    }
+
+    /// Acquisisce il lock esclusivo sul job per il worker `holder`, valido per `ttl`.
+    ///
+    /// # Ritorna
+    /// - `Ok(JobLock)`: Il lock acquisito, rilasciato al termine del suo ciclo di vita.
+    /// - `Err(CrudError::Conflict(id))`: Se il job è bloccato da un lock non ancora scaduto.
+    /// - `Err(CrudError::NotFound(id))`: Se il job non esiste.
+    pub fn acquire_lock(&self, job_id: u32, ttl: Duration, holder: &str) -> Result<JobLock, CrudError> {
+        {
+            let jobs = self.registry.jobs().lock().map_err(|e| CrudError::LockError(e.to_string()))?;
+            if !jobs.contains_key(&job_id) {
+                return Err(CrudError::NotFound(job_id));
+            }
+        }
+
+        let mut locks = JOB_LOCKS.lock().map_err(|e| CrudError::LockError(e.to_string()))?;
+        if let Some(current) = locks.get(&job_id) {
+            if !current.is_expired() {
+                return Err(CrudError::Conflict(job_id));
+            }
+            warn!("Lock del job {} detenuto da {} scaduto, riassegnazione a {}", job_id, current.holder, holder);
+        }
+
+        let token = NEXT_LOCK_TOKEN.fetch_add(1, Ordering::Relaxed);
+        let expires_at = Instant::now() + ttl;
+        locks.insert(job_id, JobLock { job_id, holder: holder.to_string(), expires_at, token, guard: false });
+        info!("Lock del job {} acquisito da {}", job_id, holder);
+        Ok(JobLock { job_id, holder: holder.to_string(), expires_at, token, guard: true })
+    }
+
+    /// Segna come in esecuzione il job con l'ID specificato registrandone l'istante di avvio.
+    pub fn start(&self, id: u32) -> Result<(), CrudError> {
+        let mut jobs = self.registry.jobs().lock().map_err(|e| CrudError::LockError(e.to_string()))?;
+        let job = jobs.get_mut(&id).ok_or(CrudError::NotFound(id))?;
+        job.status = JobStatus::Running;
+        job.started_at = Some(now_millis());
+        Ok(())
+    }
+
+    /// Segna come completato il job con l'ID specificato salvandone il risultato e l'istante di completamento.
+    pub fn complete(&self, id: u32, output: Value) -> Result<(), CrudError> {
+        let mut jobs = self.registry.jobs().lock().map_err(|e| CrudError::LockError(e.to_string()))?;
+        let job = jobs.get_mut(&id).ok_or(CrudError::NotFound(id))?;
+        job.status = JobStatus::Completed;
+        job.output = Some(output);
+        job.completed_at = Some(now_millis());
+        Ok(())
+    }
+
+    /// Raccoglie i risultati dei job specificati in un unico oggetto JSON indicizzato per ID del job.
+    ///
+    /// # Ritorna
+    /// - `Ok(Value)`: Un oggetto `{ "<job_id>": <output>, ... }`.
+    /// - `Err(CrudError::NotFound(id))`: Se un job non esiste o non è ancora completato.
+    pub fn aggregate_outputs(&self, job_ids: &[u32]) -> Result<Value, CrudError> {
+        let mut jobs = self.registry.jobs().lock().map_err(|e| CrudError::LockError(e.to_string()))?;
+        let mut outputs = Map::new();
+        for id in job_ids {
+            let job = jobs
+                .get(id)
+                .filter(|job| job.status == JobStatus::Completed)
+                .ok_or(CrudError::NotFound(*id))?;
+            outputs.insert(id.to_string(), job.output.clone().unwrap_or(Value::Null));
+        }
+        info!("Aggregati i risultati di {} job", outputs.len());
+        Ok(Value::Object(outputs))
+    }
+
+    /// Esporta le tempistiche dei job specificati per il rendering di un diagramma di Gantt (Mermaid, Gantt.js).
+    ///
+    /// Per i job ancora in esecuzione la fine corrisponde all'istante corrente.
+    ///
+    /// # Ritorna
+    /// - `Ok(Value)`: Un array `[{ "id", "label", "start", "end", "dependencies" }, ...]` nell'ordine di `job_ids`.
+    /// - `Err(CrudError::NotFound(id))`: Se un job non esiste.
+    /// - `Err(CrudError::ValidationFailed)`: Se un job non è ancora stato avviato.
+    pub fn to_gantt_json(&self, job_ids: &[u32]) -> Result<Value, CrudError> {
+        let mut jobs = self.registry.jobs().lock().map_err(|e| CrudError::LockError(e.to_string()))?;
+        let mut entries = Vec::with_capacity(job_ids.len());
+        for id in job_ids {
+            let job = jobs.get(id).ok_or(CrudError::NotFound(*id))?;
+            let start = job
+                .started_at
+                .ok_or_else(|| CrudError::ValidationFailed(format!("Job {} non ancora avviato", id)))?;
+            entries.push(json!({
+                "id": job.id,
+                "label": job.name,
+                "start": start,
+                "end": job.completed_at.unwrap_or_else(now_millis),
+                "dependencies": job.dependencies,
+            }));
+        }
+        Ok(Value::Array(entries))
+    }
+}
@@ -0,0 +1,147 @@
+//! Modulo per la gestione dei `LogEvent` mantenuti in `LOG_EVENTS_IN_MEMORY` o nel `MemoryRegistry` indicato con `with_registry`.
+//!
+//! Con la feature `log-forwarding` attiva, il `LogEventStore` può inoltrare periodicamente
+//! gli eventi ad un endpoint HTTP esterno come Loki o Elasticsearch.
+
+use std::sync::Arc;
+#[cfg(feature = "log-forwarding")]
+use std::time::Duration;
+#[cfg(feature = "log-forwarding")]
+use log::{info, error};
+#[cfg(feature = "log-forwarding")]
+use tokio::{sync::oneshot, task::JoinHandle};
+
+use crate::core::memory_management::MemoryRegistry;
+use crate::crud::crud_ops::CrudError;
+use crate::crud::models::default::log_event::model::LogEvent;
+
+/// Handle del task di inoltro avviato con `LogEventStore::forward_to`.
+#[cfg(feature = "log-forwarding")]
+pub struct ForwardHandle {
+    shutdown: oneshot::Sender<()>,
+    task: JoinHandle<()>,
+}
+
+#[cfg(feature = "log-forwarding")]
+impl ForwardHandle {
+    /// Arresta il task di inoltro attendendo il completamento del batch in corso.
+    pub async fn stop(self) {
+        let _ = self.shutdown.send(());
+        let _ = self.task.await;
+    }
+}
+
+/// Struttura `LogEventStore`
+///
+/// Punto di accesso alle operazioni specifiche dei `LogEvent` in memoria.
+#[derive(Debug, Clone)]
+pub struct LogEventStore {
+    registry: Arc<MemoryRegistry>,
+}
+
+impl Default for LogEventStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LogEventStore {
+    /// Crea un nuovo `LogEventStore` che opera sul registro predefinito (`MemoryRegistry::global`).
+    pub fn new() -> Self {
+        Self::with_registry(MemoryRegistry::global())
+    }
+
+    /// Crea un nuovo `LogEventStore` che opera sulle mappe del registro indicato.
+    pub fn with_registry(registry: Arc<MemoryRegistry>) -> Self {
+        LogEventStore { registry }
+    }
+
+    /// Inserisce un evento in memoria.
+    ///
+    /// # Ritorna
+    /// L'ID assegnato all'evento dal buffer circolare, che sostituisce quello indicato.
+    pub fn insert(&self, event: LogEvent) -> Result<u32, CrudError> {
+        let mut events = self.registry.log_events().lock().map_err(|e| CrudError::LockError(e.to_string()))?;
+        Ok(events.push(event))
+    }
+
+    /// Restituisce al massimo `limit` eventi in ordine di inserimento, senza rimuoverli.
+    pub fn peek_batch(&self, limit: usize) -> Result<Vec<LogEvent>, CrudError> {
+        let events = self.registry.log_events().lock().map_err(|e| CrudError::LockError(e.to_string()))?;
+        Ok(events.iter().take(limit).cloned().collect())
+    }
+
+    /// Rimuove dalla memoria gli eventi con gli ID specificati.
+    pub fn remove_all(&self, ids: &[u32]) -> Result<(), CrudError> {
+        let mut events = self.registry.log_events().lock().map_err(|e| CrudError::LockError(e.to_string()))?;
+        for id in ids {
+            events.remove(*id);
+        }
+        Ok(())
+    }
+
+    /// Avvia un task Tokio che inoltra gli eventi all'endpoint indicato.
+    ///
+    /// Ad ogni `interval` vengono prelevati fino a `batch_size` eventi, serializzati come array JSON
+    /// e inviati con una `POST`. Gli eventi vengono rimossi dalla memoria solo se l'endpoint
+    /// risponde con successo, altrimenti restano disponibili per il batch successivo.
+    ///
+    /// # Ritorna
+    /// Un `ForwardHandle` per arrestare l'inoltro.
+    #[cfg(feature = "log-forwarding")]
+    pub fn forward_to(&self, url: &str, batch_size: usize, interval: Duration) -> ForwardHandle {
+        let (shutdown, mut shutdown_rx) = oneshot::channel();
+        let url = url.to_string();
+        let store = self.clone();
+
+        let task = tokio::spawn(async move {
+            let client = reqwest::Client::new();
+            let mut ticker = tokio::time::interval(interval);
+            loop {
+                tokio::select! {
+                    _ = &mut shutdown_rx => break,
+                    _ = ticker.tick() => store.forward_batch(&client, &url, batch_size).await,
+                }
+            }
+            info!("Inoltro dei LogEvent verso {} arrestato", url);
+        });
+
+        ForwardHandle { shutdown, task }
+    }
+
+    /// Invia un singolo batch di eventi all'endpoint.
+    #[cfg(feature = "log-forwarding")]
+    async fn forward_batch(&self, client: &reqwest::Client, url: &str, batch_size: usize) {
+        let batch = match self.peek_batch(batch_size) {
+            Ok(batch) if !batch.is_empty() => batch,
+            Ok(_) => return,
+            Err(e) => {
+                error!("Lettura dei LogEvent fallita: {}", e);
+                return;
+            }
+        };
+
+        let payload: Vec<serde_json::Value> = batch
+            .iter()
+            .map(|event| serde_json::json!({
+                "id": event.id,
+                "device_id": event.device_id,
+                "event_type": event.event_type,
+                "timestamp": event.timestamp,
+                "description": event.description,
+            }))
+            .collect();
+
+        match client.post(url).json(&payload).send().await {
+            Ok(response) if response.status().is_success() => {
+                let ids: Vec<u32> = batch.iter().map(|event| event.id).collect();
+                if let Err(e) = self.remove_all(&ids) {
+                    error!("Rimozione dei LogEvent inoltrati fallita: {}", e);
+                }
+                info!("Inoltrati {} LogEvent verso {}", ids.len(), url);
+            }
+            Ok(response) => error!("Inoltro dei LogEvent fallito con stato {}", response.status()),
+            Err(e) => error!("Inoltro dei LogEvent fallito: {}", e),
+        }
+    }
+}
@@ -0,0 +1,114 @@
+//! Modulo per la gestione delle `Macro` mantenute in `MACROS_IN_MEMORY` o nel `MemoryRegistry` indicato con `with_registry`.
+//!
+//! Il `MacroStore` offre un versionamento dei comandi delle macro simile a Git:
+//! ogni commit salva un'istantanea del corpo della macro identificata dal suo hash SHA-256
+//! e può essere ripristinata con `checkout`.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use lazy_static::lazy_static;
+use log::info;
+use sha2::{Digest, Sha256};
+
+use crate::core::memory_management::MemoryRegistry;
+use crate::crud::crud_ops::CrudError;
+use crate::crud::stores::now_millis;
+
+/// Commit del corpo di una macro.
+/// - `hash`: Hash SHA-256 esadecimale del corpo.
+/// - `macro_id`: ID della macro a cui appartiene il commit.
+/// - `body_snapshot`: Corpo della macro al momento del commit (comandi separati da `\n`).
+/// - `message`: Messaggio descrittivo del commit.
+/// - `committed_at`: Istante del commit (Unix millisecondi).
+#[derive(Debug, Clone, PartialEq)]
+pub struct MacroCommit {
+    pub hash: String,
+    pub macro_id: u32,
+    pub body_snapshot: String,
+    pub message: String,
+    pub committed_at: i64,
+}
+
+// Storico dei commit di ciascuna Macro
+lazy_static! {
+    pub static ref MACRO_COMMITS: Mutex<HashMap<u32, Vec<MacroCommit>>> = Mutex::new(HashMap::new());
+}
+
+/// Struttura `MacroStore`
+///
+/// Punto di accesso alle operazioni specifiche delle `Macro` in memoria.
+#[derive(Debug, Clone)]
+pub struct MacroStore {
+    registry: Arc<MemoryRegistry>,
+}
+
+impl Default for MacroStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MacroStore {
+    /// Crea un nuovo `MacroStore` che opera sul registro predefinito (`MemoryRegistry::global`).
+    pub fn new() -> Self {
+        Self::with_registry(MemoryRegistry::global())
+    }
+
+    /// Crea un nuovo `MacroStore` che opera sulle mappe del registro indicato.
+    pub fn with_registry(registry: Arc<MemoryRegistry>) -> Self {
+        MacroStore { registry }
+    }
+
+    /// Salva un commit con il corpo corrente della macro.
+    ///
+    /// Il corpo è composto dai comandi della macro separati da `\n`.
+    pub fn commit(&self, id: u32, message: &str) -> Result<MacroCommit, CrudError> {
+        let body = {
+            let mut macros = self.registry.macros().lock().map_err(|e| CrudError::LockError(e.to_string()))?;
+            macros.get(&id).ok_or(CrudError::NotFound(id))?.commands.join("\n")
+        };
+
+        let commit = MacroCommit {
+            hash: format!("{:x}", Sha256::digest(body.as_bytes())),
+            macro_id: id,
+            body_snapshot: body,
+            message: message.to_string(),
+            committed_at: now_millis(),
+        };
+
+        let mut commits = MACRO_COMMITS.lock().map_err(|e| CrudError::LockError(e.to_string()))?;
+        commits.entry(id).or_insert_with(Vec::new).push(commit.clone());
+        info!("Commit {} salvato per la macro {}", commit.hash, id);
+        Ok(commit)
+    }
+
+    /// Ripristina il corpo della macro all'istantanea del commit indicato.
+    pub fn checkout(&self, id: u32, commit_hash: &str) -> Result<(), CrudError> {
+        let snapshot = {
+            let commits = MACRO_COMMITS.lock().map_err(|e| CrudError::LockError(e.to_string()))?;
+            commits
+                .get(&id)
+                .and_then(|history| history.iter().find(|c| c.hash == commit_hash))
+                .map(|c| c.body_snapshot.clone())
+                .ok_or_else(|| CrudError::ValidationFailed(format!("Commit {} non trovato per la macro {}", commit_hash, id)))?
+        };
+
+        let mut macros = self.registry.macros().lock().map_err(|e| CrudError::LockError(e.to_string()))?;
+        let macro_script = macros.get_mut(&id).ok_or(CrudError::NotFound(id))?;
+        macro_script.commands = if snapshot.is_empty() {
+            Vec::new()
+        } else {
+            snapshot.split('\n').map(|c| c.to_string()).collect()
+        };
+        info!("Macro {} ripristinata al commit {}", id, commit_hash);
+        Ok(())
+    }
+
+    /// Restituisce lo storico dei commit della macro, dal più vecchio al più recente.
+    pub fn history(&self, id: u32) -> Vec<MacroCommit> {
+        match MACRO_COMMITS.lock() {
+            Ok(commits) => commits.get(&id).cloned().unwrap_or_default(),
+            Err(_) => Vec::new(),
+        }
+    }
+}
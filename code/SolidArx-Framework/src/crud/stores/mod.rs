@@ -0,0 +1,37 @@
+//! Modulo che raccoglie gli store dei modelli CRUD mantenuti in memoria.
+//!
+//! Gli store operano sulle strutture globali definite in `core::memory_management`
+//! ed espongono le operazioni specifiche di ciascun modello che vanno oltre il CRUD generico
+//! implementato in `crud_ops.rs`.
+//! I record propri di un singolo store (commit delle macro, aggiornamenti firmware, output dei comandi,
+//! lock dei job) sono invece mantenuti nello store stesso, così che `core` non dipenda da `crud::stores`.
+//!
+//! Gli store si trovano fuori da `models/default` perché `table_scraper.rs` genera una tabella
+//! per ogni struct presente nei file dei modelli.
+
+/// Restituisce l'istante corrente in Unix millisecondi, formato dei timestamp utilizzato dagli store.
+pub(crate) fn now_millis() -> i64 {
+    chrono::Utc::now().timestamp_millis()
+}
+
+#[cfg(feature = "embedded")]
+pub mod command_executor;
+#[cfg(feature = "embedded")]
+pub mod device_store;
+#[cfg(feature = "embedded")]
+pub mod log_event_store;
+#[cfg(feature = "embedded")]
+pub mod sensor_data_store;
+
+#[cfg(any(feature = "automation", feature = "desktop", feature = "embedded"))]
+pub mod task_store;
+#[cfg(any(feature = "automation", feature = "desktop", feature = "embedded"))]
+pub mod task_executor;
+
+#[cfg(any(feature = "automation", feature = "embedded"))]
+pub mod configuration_store;
+
+#[cfg(feature = "automation")]
+pub mod job_store;
+#[cfg(feature = "automation")]
+pub mod macro_store;
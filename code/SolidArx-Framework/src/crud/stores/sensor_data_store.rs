@@ -0,0 +1,193 @@
+//! Modulo per la gestione dei `SensorData` mantenuti in `SENSOR_DATA_IN_MEMORY` o nel `MemoryRegistry` indicato con `with_registry`.
+//!
+//! Il `SensorDataStore` gestisce l'inserimento delle letture, l'esecuzione degli hook
+//! registrati dopo ogni inserimento, come gli allarmi sulle soglie, e il filtraggio delle letture.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::mpsc::Sender;
+use lazy_static::lazy_static;
+use log::{info, warn};
+
+use crate::core::memory_management::{MemoryRegistry, update_sensor_data_index};
+use crate::crud::crud_ops::CrudError;
+use crate::crud::models::default::sensor_data::model::SensorData;
+
+/// Hook eseguito dopo ogni inserimento di un `SensorData`.
+type SensorDataHook = Arc<dyn Fn(&SensorData) + Send + Sync>;
+
+/// Identificativo progressivo degli hook registrati.
+static NEXT_HOOK_ID: AtomicU64 = AtomicU64::new(0);
+
+// Hook eseguiti dopo ogni inserimento, indicizzati per ID
+lazy_static! {
+    static ref SENSOR_DATA_HOOKS: Mutex<HashMap<u64, SensorDataHook>> = Mutex::new(HashMap::new());
+}
+
+/// Handle di un allarme registrato con `alert_on_threshold`.
+///
+/// Al rilascio dell'handle l'hook associato viene rimosso.
+#[derive(Debug)]
+pub struct AlarmHandle {
+    hook_id: u64,
+}
+
+impl Drop for AlarmHandle {
+    fn drop(&mut self) {
+        if let Ok(mut hooks) = SENSOR_DATA_HOOKS.lock() {
+            hooks.remove(&self.hook_id);
+        }
+    }
+}
+
+/// Struttura `SensorDataStore`
+///
+/// Punto di accesso alle operazioni specifiche dei `SensorData` in memoria.
+#[derive(Debug, Clone)]
+pub struct SensorDataStore {
+    registry: Arc<MemoryRegistry>,
+}
+
+impl Default for SensorDataStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SensorDataStore {
+    /// Crea un nuovo `SensorDataStore` che opera sul registro predefinito (`MemoryRegistry::global`).
+    pub fn new() -> Self {
+        Self::with_registry(MemoryRegistry::global())
+    }
+
+    /// Crea un nuovo `SensorDataStore` che opera sulle mappe del registro indicato.
+    pub fn with_registry(registry: Arc<MemoryRegistry>) -> Self {
+        SensorDataStore { registry }
+    }
+
+    /// Inserisce una lettura in memoria, aggiornando `SENSOR_DATA_TIME_INDEX`, ed esegue gli hook registrati.
+    pub fn insert(&self, data: SensorData) -> Result<(), CrudError> {
+        {
+            let mut readings = self.registry.sensor_data().lock().map_err(|e| CrudError::LockError(e.to_string()))?;
+            let mut index = self.registry.sensor_data_time_index().lock().map_err(|e| CrudError::LockError(e.to_string()))?;
+            let previous = readings.insert(data.id, data.clone());
+            update_sensor_data_index(&mut index, previous.as_ref(), &data);
+        }
+        self.run_hooks(&data)
+    }
+
+    /// Inserisce più letture acquisendo il lock una sola volta ed esegue gli hook per ciascuna.
+    ///
+    /// # Ritorna
+    /// Il numero di letture inserite.
+    pub fn insert_batch(&self, batch: Vec<SensorData>) -> Result<usize, CrudError> {
+        {
+            let mut readings = self.registry.sensor_data().lock().map_err(|e| CrudError::LockError(e.to_string()))?;
+            let mut index = self.registry.sensor_data_time_index().lock().map_err(|e| CrudError::LockError(e.to_string()))?;
+            for data in batch.iter() {
+                let previous = readings.insert(data.id, data.clone());
+                update_sensor_data_index(&mut index, previous.as_ref(), data);
+            }
+        }
+        for data in batch.iter() {
+            self.run_hooks(data)?;
+        }
+        Ok(batch.len())
+    }
+
+    /// Come `insert_batch`, ma assegna alle letture ID consecutivi a partire dal successivo
+    /// al massimo già presente in memoria.
+    ///
+    /// Il calcolo degli ID e l'inserimento avvengono sotto lo stesso lock, così che due lotti
+    /// inseriti in parallelo non ricevano gli stessi ID.
+    ///
+    /// # Ritorna
+    /// Il numero di letture inserite.
+    pub fn insert_batch_with_new_ids(&self, mut batch: Vec<SensorData>) -> Result<usize, CrudError> {
+        {
+            let mut readings = self.registry.sensor_data().lock().map_err(|e| CrudError::LockError(e.to_string()))?;
+            let mut index = self.registry.sensor_data_time_index().lock().map_err(|e| CrudError::LockError(e.to_string()))?;
+            let first_id = readings.keys().max().map_or(1, |max| max + 1);
+            for (offset, data) in batch.iter_mut().enumerate() {
+                data.id = first_id + offset as u32;
+                let previous = readings.insert(data.id, data.clone());
+                update_sensor_data_index(&mut index, previous.as_ref(), data);
+            }
+        }
+        for data in batch.iter() {
+            self.run_hooks(data)?;
+        }
+        Ok(batch.len())
+    }
+
+    /// Esegue gli hook registrati sulla lettura appena inserita.
+    ///
+    /// Gli hook vengono copiati prima di essere invocati, così che possano registrare o rimuovere
+    /// altri hook (ad esempio rilasciando un `AlarmHandle`) senza attendere `SENSOR_DATA_HOOKS`.
+    fn run_hooks(&self, data: &SensorData) -> Result<(), CrudError> {
+        let hooks: Vec<SensorDataHook> = {
+            let hooks = SENSOR_DATA_HOOKS.lock().map_err(|e| CrudError::LockError(e.to_string()))?;
+            hooks.values().cloned().collect()
+        };
+        for hook in hooks.iter() {
+            hook(data);
+        }
+        Ok(())
+    }
+
+    /// Registra un allarme per le letture del sensore fuori dall'intervallo `[min, max]`.
+    ///
+    /// Ogni `SensorData` inserito con il `sensor_id` indicato e un valore fuori intervallo
+    /// viene inviato su `tx`. L'allarme resta attivo finché l'`AlarmHandle` non viene rilasciato.
+    /// Le letture senza valore numerico (`NaN`) vengono ignorate.
+    pub fn alert_on_threshold(&self, sensor_id: u32, min: f64, max: f64, tx: Sender<SensorData>) -> AlarmHandle {
+        let hook_id = NEXT_HOOK_ID.fetch_add(1, Ordering::Relaxed);
+        let hook: SensorDataHook = Arc::new(move |data: &SensorData| {
+            if data.sensor_id == sensor_id && !data.value.is_nan() && (data.value < min || data.value > max) {
+                warn!("Lettura {} del sensore {} fuori soglia: {}", data.id, sensor_id, data.value);
+                // Il ricevitore potrebbe essere già stato chiuso, l'allarme viene semplicemente ignorato
+                let _ = tx.send(data.clone());
+            }
+        });
+
+        if let Ok(mut hooks) = SENSOR_DATA_HOOKS.lock() {
+            hooks.insert(hook_id, hook);
+        }
+        info!("Allarme registrato sul sensore {} per l'intervallo [{}, {}]", sensor_id, min, max);
+        AlarmHandle { hook_id }
+    }
+
+    /// Applica una media mobile semplice ai valori delle letture in memoria.
+    ///
+    /// Le letture vengono raggruppate per `sensor_id` e ordinate per `timestamp` (a parità, per ID):
+    /// il valore di ciascuna diventa la media aritmetica di sé stessa e delle `window - 1` letture
+    /// precedenti dello stesso sensore, oppure del prefisso disponibile per le prime letture.
+    /// Le letture senza valore numerico (`NaN`) vengono escluse dal risultato e dalle medie.
+    /// Le letture in memoria non vengono modificate.
+    ///
+    /// # Ritorna
+    /// - `Ok(Vec<SensorData>)`: Copie filtrate delle letture, ordinate per `timestamp`.
+    /// - `Err(CrudError::ValidationFailed)`: Se `window` è zero.
+    pub fn apply_moving_average(&self, window: usize) -> Result<Vec<SensorData>, CrudError> {
+        if window == 0 {
+            return Err(CrudError::ValidationFailed("La finestra della media mobile deve essere maggiore di zero".to_string()));
+        }
+        let mut readings: Vec<SensorData> = {
+            let readings = self.registry.sensor_data().lock().map_err(|e| CrudError::LockError(e.to_string()))?;
+            readings.values().filter(|reading| !reading.value.is_nan()).cloned().collect()
+        };
+        readings.sort_by(|a, b| a.timestamp.cmp(&b.timestamp).then(a.id.cmp(&b.id)));
+
+        // Valori originali di ciascun sensore, nell'ordine delle letture
+        let mut history: HashMap<u32, Vec<f64>> = HashMap::new();
+        for reading in readings.iter_mut() {
+            let values = history.entry(reading.sensor_id).or_default();
+            values.push(reading.value);
+            let start = values.len().saturating_sub(window);
+            let recent = &values[start..];
+            reading.value = recent.iter().sum::<f64>() / recent.len() as f64;
+        }
+        Ok(readings)
+    }
+}
@@ -0,0 +1,120 @@
+//! Modulo per l'esecuzione dei `Task` di un `TaskStore` con output in streaming.
+//!
+//! Il `TaskExecutor` associa ad ogni task un handler asincrono registrato dall'applicazione.
+//! L'handler riceve un `TaskOutput` con cui inviare l'output a blocchi man mano che viene prodotto,
+//! così che i task di lunga durata (ad esempio una compilazione) non debbano accumularlo in memoria.
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use log::{info, error};
+use tokio::sync::mpsc;
+use tokio_stream::{wrappers::ReceiverStream, Stream};
+
+use crate::crud::crud_ops::CrudError;
+use crate::crud::models::default::task::model::Task;
+use crate::crud::stores::task_store::TaskStore;
+
+/// Numero di blocchi di output in attesa di essere letti prima che l'handler venga sospeso.
+const STREAM_CHANNEL_CAPACITY: usize = 16;
+
+/// Canale con cui l'handler di un task invia i blocchi di output.
+#[derive(Debug, Clone)]
+pub struct TaskOutput {
+    sender: mpsc::Sender<Result<Vec<u8>, CrudError>>,
+}
+
+impl TaskOutput {
+    /// Invia un blocco di output, attendendo se il lettore non ha ancora consumato i precedenti.
+    ///
+    /// # Ritorna
+    /// `Err(CrudError::ExecutionFailed)` se lo stream è stato abbandonato dal lettore.
+    pub async fn send(&self, chunk: Vec<u8>) -> Result<(), CrudError> {
+        self.sender
+            .send(Ok(chunk))
+            .await
+            .map_err(|_| CrudError::ExecutionFailed("Stream dell'output chiuso dal lettore".to_string()))
+    }
+}
+
+/// Esecuzione asincrona di un handler, conclusa con l'eventuale messaggio di errore.
+pub type TaskFuture = Pin<Box<dyn Future<Output = Result<(), String>> + Send>>;
+
+/// Funzione handler asincrona associata ad un task, che produce l'output tramite `TaskOutput`.
+pub type StreamingHandler = Arc<dyn Fn(Task, TaskOutput) -> TaskFuture + Send + Sync>;
+
+/// Struttura `TaskExecutor`
+///
+/// Mantiene la mappa `id del task -> handler` utilizzata per eseguire i task in memoria.
+#[derive(Default)]
+pub struct TaskExecutor {
+    handlers: HashMap<u32, StreamingHandler>,
+    store: TaskStore,  // Store dei task eseguiti, che ne determina il registro
+}
+
+impl TaskExecutor {
+    /// Crea un nuovo `TaskExecutor` senza handler registrati, sui task del registro predefinito.
+    pub fn new() -> Self {
+        Self::with_store(TaskStore::new())
+    }
+
+    /// Crea un nuovo `TaskExecutor` senza handler registrati, sui task dello store indicato.
+    pub fn with_store(store: TaskStore) -> Self {
+        TaskExecutor { handlers: HashMap::new(), store }
+    }
+
+    /// Registra l'handler da eseguire per il task con l'ID indicato.
+    ///
+    /// # Parametri
+    /// - `id`: L'ID del task gestito dall'handler.
+    /// - `handler`: La funzione asincrona da eseguire, che riceve il task e il canale dell'output.
+    pub fn register_handler<F, Fut>(&mut self, id: u32, handler: F)
+    where
+        F: Fn(Task, TaskOutput) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<(), String>> + Send + 'static,
+    {
+        let handler: StreamingHandler = Arc::new(move |task, output| Box::pin(handler(task, output)) as TaskFuture);
+        self.handlers.insert(id, handler);
+    }
+
+    /// Esegue il task con l'ID specificato in un task Tokio e ne restituisce l'output in streaming.
+    ///
+    /// Il task passa in stato `Running` e, al termine dell'handler, in `Completed` o `Failed`.
+    /// Se l'handler fallisce l'ultimo elemento dello stream è `Err(CrudError::ExecutionFailed)`.
+    ///
+    /// # Ritorna
+    /// - `Ok(Stream)`: I blocchi di output nell'ordine in cui l'handler li ha inviati.
+    /// - `Err(CrudError::NotFound)`: Se il task non esiste.
+    /// - `Err(CrudError::ValidationFailed)`: Se non è registrato alcun handler per il task.
+    ///
+    /// # Nota
+    /// Deve essere chiamato all'interno di un runtime Tokio.
+    pub fn run_streaming(&self, id: u32) -> Result<impl Stream<Item = Result<Vec<u8>, CrudError>>, CrudError> {
+        let handler = self.handlers
+            .get(&id)
+            .cloned()
+            .ok_or_else(|| CrudError::ValidationFailed(format!("Nessun handler registrato per il task {}", id)))?;
+        let task = self.store.start(id)?;
+
+        info!("Esecuzione del task {} con output in streaming", id);
+        let (sender, receiver) = mpsc::channel(STREAM_CHANNEL_CAPACITY);
+        let output = TaskOutput { sender: sender.clone() };
+        let store = self.store.clone();
+        tokio::spawn(async move {
+            let finished = match handler(task, output).await {
+                Ok(()) => store.complete(id),
+                Err(e) => {
+                    error!("Errore nell'esecuzione del task {}: {}", id, e);
+                    let _ = sender.send(Err(CrudError::ExecutionFailed(e))).await;
+                    store.fail(id)
+                }
+            };
+            if let Err(e) = finished {
+                error!("Aggiornamento dello stato del task {} fallito: {}", id, e);
+            }
+        });
+
+        Ok(ReceiverStream::new(receiver))
+    }
+}
@@ -0,0 +1,445 @@
+//! Modulo per la gestione dei `Task` mantenuti in `TASKS_IN_MEMORY` o nel `MemoryRegistry` indicato con `with_registry`.
+//!
+//! Il `TaskStore` espone le operazioni sul ciclo di vita dei task, come la sottomissione
+//! con backpressure, il completamento e l'archiviazione dei task conclusi in `ARCHIVED_TASKS`.
+//! Lo stato dello scheduler (priorità, dipendenze, istante di sottomissione) è mantenuto in
+//! `TASK_SCHEDULES` e non nel modello `Task`, così da non essere persistito con il task.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use lazy_static::lazy_static;
+use log::{info, warn};
+use tokio::sync::{watch, Notify};
+use tokio::time::Instant;
+
+use crate::core::evicting_map::EvictingMap;
+use crate::core::memory_management::MemoryRegistry;
+use crate::crud::crud_ops::CrudError;
+use crate::crud::stores::now_millis;
+use crate::crud::models::default::task::model::{Task, TaskStatus};
+
+/// Numero massimo di task in attesa predefinito per `TaskStoreConfig`.
+const DEFAULT_MAX_PENDING: usize = 1024;
+
+// Versione incrementata ad ogni cambio di stato dei task, osservata da `wait_for_all`
+lazy_static! {
+    static ref TASK_STATUS_VERSION: watch::Sender<u64> = watch::channel(0).0;
+}
+
+// Risveglia i `submit_async` in attesa quando un task lascia lo stato `Pending`
+lazy_static! {
+    static ref TASK_SLOTS: Notify = Notify::new();
+}
+
+// Stato dello scheduler per ID del task, acquisito sempre dopo `TASKS_IN_MEMORY`
+lazy_static! {
+    static ref TASK_SCHEDULES: Mutex<HashMap<u32, TaskSchedule>> = Mutex::new(HashMap::new());
+}
+
+/// Enum per la priorità dei task, ordinata da `Low` a `High`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum TaskPriority {
+    Low = 0,
+    Normal = 1,
+    High = 2,
+}
+
+impl TaskPriority {
+    /// Livello numerico della priorità, usato da `TaskSchedule::boosted_from`
+    pub fn level(self) -> u32 {
+        self as u32
+    }
+
+    /// Ricostruisce la priorità dal livello numerico (i valori fuori intervallo diventano `High`)
+    pub fn from_level(level: u32) -> Self {
+        match level {
+            0 => TaskPriority::Low,
+            1 => TaskPriority::Normal,
+            _ => TaskPriority::High,
+        }
+    }
+}
+
+/// Stato dello scheduler associato a un task.
+/// - `priority`: Priorità di esecuzione del task.
+/// - `dependencies`: ID dei task da cui dipende.
+/// - `boosted_from`: Livello di priorità originale se elevata per ereditarietà.
+/// - `submitted_at`: Istante di sottomissione, usato per l'invecchiamento della priorità.
+/// - `effective_priority`: Priorità effettiva, aumentata da `TaskStore::age_priorities`.
+#[derive(Debug, Clone)]
+pub struct TaskSchedule {
+    pub priority: TaskPriority,
+    pub dependencies: Vec<u32>,
+    pub boosted_from: Option<u32>,
+    pub submitted_at: Instant,
+    pub effective_priority: u8,
+}
+
+impl Default for TaskSchedule {
+    fn default() -> Self {
+        TaskSchedule {
+            priority: TaskPriority::Normal,
+            dependencies: Vec::new(),
+            boosted_from: None,
+            submitted_at: Instant::now(),
+            effective_priority: TaskPriority::Normal.level() as u8,
+        }
+    }
+}
+
+/// Notifica ai task in attesa che lo stato di almeno un task è cambiato.
+///
+/// I `submit_async` in attesa vengono risvegliati tutti insieme e ritentano la sottomissione,
+/// senza accumulare permessi per le notifiche che nessuno attende.
+fn notify_status_change() {
+    TASK_STATUS_VERSION.send_modify(|version| *version += 1);
+    TASK_SLOTS.notify_waiters();
+}
+
+/// Verifica se il task non è ancora concluso.
+fn is_unfinished(task: &Task) -> bool {
+    task.status == TaskStatus::Pending || task.status == TaskStatus::Running
+}
+
+/// Verifica se il task con l'ID specificato esiste e non è ancora concluso.
+fn is_unfinished_id(tasks: &EvictingMap<u32, Task>, id: u32) -> bool {
+    tasks.peek(&id).map_or(false, is_unfinished)
+}
+
+/// Raccoglie gli ID dei task non conclusi da cui dipendono, anche transitivamente,
+/// i task non conclusi con priorità `High` originale.
+fn required_by_high_priority(tasks: &EvictingMap<u32, Task>, schedules: &HashMap<u32, TaskSchedule>) -> HashSet<u32> {
+    let mut required = HashSet::new();
+    let mut stack: Vec<u32> = schedules
+        .iter()
+        .filter(|(id, s)| is_unfinished_id(tasks, **id) && s.priority == TaskPriority::High && s.boosted_from.is_none())
+        .flat_map(|(_, s)| s.dependencies.iter().copied())
+        .collect();
+
+    while let Some(id) = stack.pop() {
+        // L'insieme dei visitati evita cicli infiniti in caso di dipendenze circolari
+        if is_unfinished_id(tasks, id) && required.insert(id) {
+            if let Some(schedule) = schedules.get(&id) {
+                stack.extend(schedule.dependencies.iter().copied());
+            }
+        }
+    }
+    required
+}
+
+/// Ripristina la priorità originale dei task elevati non più richiesti da un task `High`.
+fn revert_priority_boosts(tasks: &EvictingMap<u32, Task>, schedules: &mut HashMap<u32, TaskSchedule>) -> usize {
+    let required = required_by_high_priority(tasks, schedules);
+    let mut reverted = 0;
+    for (id, schedule) in schedules.iter_mut() {
+        if let Some(level) = schedule.boosted_from {
+            if !required.contains(id) {
+                schedule.priority = TaskPriority::from_level(level);
+                schedule.boosted_from = None;
+                reverted += 1;
+            }
+        }
+    }
+    reverted
+}
+
+/// Configurazione del `TaskStore`.
+/// - `max_pending`: Numero massimo di task sottomessi ancora in stato `Pending`.
+#[derive(Debug, Clone)]
+pub struct TaskStoreConfig {
+    pub max_pending: usize,
+}
+
+impl Default for TaskStoreConfig {
+    fn default() -> Self {
+        TaskStoreConfig { max_pending: DEFAULT_MAX_PENDING }
+    }
+}
+
+/// Struttura `TaskStore`
+///
+/// Punto di accesso alle operazioni specifiche dei `Task` in memoria.
+/// Il limite di `max_pending` si applica ai task sottomessi tramite lo stesso store (e i suoi cloni).
+#[derive(Debug, Clone)]
+pub struct TaskStore {
+    config: TaskStoreConfig,
+    submitted: Arc<Mutex<HashSet<u32>>>,  // ID dei task sottomessi tramite questo store
+    registry: Arc<MemoryRegistry>,        // Registro che contiene i task e quelli archiviati
+}
+
+impl Default for TaskStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TaskStore {
+    /// Crea un nuovo `TaskStore` con la configurazione predefinita.
+    pub fn new() -> Self {
+        Self::with_config(TaskStoreConfig::default())
+    }
+
+    /// Crea un nuovo `TaskStore` con la configurazione specificata, sul registro predefinito.
+    pub fn with_config(config: TaskStoreConfig) -> Self {
+        Self::with_registry(config, MemoryRegistry::global())
+    }
+
+    /// Crea un nuovo `TaskStore` con la configurazione specificata, sulle mappe del registro indicato.
+    pub fn with_registry(config: TaskStoreConfig, registry: Arc<MemoryRegistry>) -> Self {
+        TaskStore { config, submitted: Arc::new(Mutex::new(HashSet::new())), registry }
+    }
+
+    /// Inserisce un task in `TASKS_IN_MEMORY` se la coda dei task in attesa non è piena.
+    ///
+    /// # Ritorna
+    /// - `Ok(u32)`: L'ID del task inserito.
+    /// - `Err(CrudError::QueueFull)`: Se i task sottomessi in stato `Pending` sono già `max_pending`.
+    /// - `Err(CrudError::ValidationFailed)`: Se esiste già un task con lo stesso ID.
+    pub fn submit(&self, task: Task) -> Result<u32, CrudError> {
+        let mut tasks = self.registry.tasks().lock().map_err(|e| CrudError::LockError(e.to_string()))?;
+        let mut submitted = self.submitted.lock().map_err(|e| CrudError::LockError(e.to_string()))?;
+
+        // I task non più presenti o conclusi non occupano posti nella coda
+        submitted.retain(|id| tasks.get(id).map_or(false, |t| t.status == TaskStatus::Pending));
+        if submitted.len() >= self.config.max_pending {
+            return Err(CrudError::QueueFull);
+        }
+        if tasks.contains_key(&task.id) {
+            return Err(CrudError::ValidationFailed(format!("Task con ID {} già presente", task.id)));
+        }
+
+        let id = task.id;
+        let mut schedules = TASK_SCHEDULES.lock().map_err(|e| CrudError::LockError(e.to_string()))?;
+        schedules.entry(id).or_default().submitted_at = Instant::now();
+        submitted.insert(id);
+        tasks.insert(id, task);
+        Ok(id)
+    }
+
+    /// Imposta la priorità e le dipendenze del task con l'ID specificato.
+    ///
+    /// Può essere chiamata prima o dopo `submit`: l'eventuale elevazione per ereditarietà viene
+    /// annullata e la priorità effettiva riportata al livello di `priority`.
+    pub fn set_schedule(&self, id: u32, priority: TaskPriority, dependencies: Vec<u32>) -> Result<(), CrudError> {
+        let mut schedules = TASK_SCHEDULES.lock().map_err(|e| CrudError::LockError(e.to_string()))?;
+        let schedule = schedules.entry(id).or_default();
+        schedule.priority = priority;
+        schedule.dependencies = dependencies;
+        schedule.boosted_from = None;
+        schedule.effective_priority = priority.level() as u8;
+        Ok(())
+    }
+
+    /// Restituisce una copia dello stato dello scheduler del task con l'ID specificato, se presente.
+    pub fn schedule(&self, id: u32) -> Option<TaskSchedule> {
+        TASK_SCHEDULES.lock().ok()?.get(&id).cloned()
+    }
+
+    /// Variante asincrona di `submit` che, a coda piena, attende che un task lasci lo stato `Pending`
+    /// (con `start`, `complete` o `fail`) invece di fallire.
+    pub async fn submit_async(&self, task: Task) -> Result<u32, CrudError> {
+        loop {
+            // Creata prima del tentativo, così da ricevere anche le notifiche arrivate nel frattempo
+            let slot_released = TASK_SLOTS.notified();
+            match self.submit(task.clone()) {
+                Err(CrudError::QueueFull) => slot_released.await,
+                result => return result,
+            }
+        }
+    }
+
+    /// Porta in stato `Running` il task con l'ID specificato, liberandone il posto nella coda dei task in attesa.
+    ///
+    /// # Ritorna
+    /// - `Ok(Task)`: Una copia del task in esecuzione.
+    /// - `Err(CrudError::NotFound)`: Se il task non esiste.
+    pub fn start(&self, id: u32) -> Result<Task, CrudError> {
+        let task = {
+            let mut tasks = self.registry.tasks().lock().map_err(|e| CrudError::LockError(e.to_string()))?;
+            let task = tasks.get_mut(&id).ok_or(CrudError::NotFound(id))?;
+            task.status = TaskStatus::Running;
+            task.clone()
+        };
+        notify_status_change();
+        Ok(task)
+    }
+
+    /// Segna come completato il task con l'ID specificato registrandone l'istante di completamento.
+    pub fn complete(&self, id: u32) -> Result<(), CrudError> {
+        self.finish(id, TaskStatus::Completed)
+    }
+
+    /// Segna come fallito il task con l'ID specificato registrandone l'istante di conclusione.
+    pub fn fail(&self, id: u32) -> Result<(), CrudError> {
+        self.finish(id, TaskStatus::Failed)
+    }
+
+    /// Imposta lo stato finale del task e notifica chi è in attesa con `wait_for_all`.
+    fn finish(&self, id: u32, status: TaskStatus) -> Result<(), CrudError> {
+        {
+            let mut tasks = self.registry.tasks().lock().map_err(|e| CrudError::LockError(e.to_string()))?;
+            let task = tasks.get_mut(&id).ok_or(CrudError::NotFound(id))?;
+            task.status = status;
+            task.completed_at = Some(now_millis());
+
+            let mut schedules = TASK_SCHEDULES.lock().map_err(|e| CrudError::LockError(e.to_string()))?;
+            let reverted = revert_priority_boosts(&tasks, &mut schedules);
+            if reverted > 0 {
+                info!("Ripristinata la priorità originale di {} task", reverted);
+            }
+        }
+        notify_status_change();
+        Ok(())
+    }
+
+    /// Risolve le inversioni di priorità elevando a `High` le dipendenze, anche transitive,
+    /// dei task non conclusi con priorità `High`.
+    ///
+    /// La priorità originale viene salvata in `TaskSchedule::boosted_from` e ripristinata automaticamente
+    /// da `complete`/`fail` quando nessun task `High` in attesa dipende più dal task elevato.
+    ///
+    /// # Ritorna
+    /// Il numero di task la cui priorità è stata elevata.
+    pub fn apply_priority_inheritance(&self) -> Result<usize, CrudError> {
+        let tasks = self.registry.tasks().lock().map_err(|e| CrudError::LockError(e.to_string()))?;
+        let mut schedules = TASK_SCHEDULES.lock().map_err(|e| CrudError::LockError(e.to_string()))?;
+        let required = required_by_high_priority(&tasks, &schedules);
+
+        let mut boosted = 0;
+        for id in required {
+            let schedule = schedules.entry(id).or_default();
+            if schedule.priority < TaskPriority::High {
+                schedule.boosted_from = Some(schedule.priority.level());
+                schedule.priority = TaskPriority::High;
+                boosted += 1;
+            }
+        }
+
+        info!("Priorità elevata per ereditarietà su {} task", boosted);
+        Ok(boosted)
+    }
+
+    /// Invecchia la priorità dei task in attesa per evitare che quelli a bassa priorità
+    /// restino indefinitamente esclusi dall'esecuzione.
+    ///
+    /// La priorità effettiva di ogni task `Pending` diventa il livello di `priority` aumentato di
+    /// `rate` per ogni secondo trascorso da `submitted_at`, con un massimo di `u8::MAX`.
+    /// Il calcolo parte sempre dal livello di base, quindi chiamate ripetute non accumulano l'aumento.
+    ///
+    /// # Ritorna
+    /// - `Ok(usize)`: Il numero di task la cui priorità effettiva è cambiata.
+    /// - `Err(CrudError::ValidationFailed)`: Se `rate` è negativo o non finito.
+    pub fn age_priorities(&self, rate: f32) -> Result<usize, CrudError> {
+        if !rate.is_finite() || rate < 0.0 {
+            return Err(CrudError::ValidationFailed(format!("Fattore di invecchiamento non valido: {}", rate)));
+        }
+        let now = Instant::now();
+        let tasks = self.registry.tasks().lock().map_err(|e| CrudError::LockError(e.to_string()))?;
+        let mut schedules = TASK_SCHEDULES.lock().map_err(|e| CrudError::LockError(e.to_string()))?;
+
+        let mut changed = 0;
+        for task in tasks.values().filter(|t| t.status == TaskStatus::Pending) {
+            let schedule = schedules.entry(task.id).or_default();
+            let waited = now.saturating_duration_since(schedule.submitted_at).as_secs_f32();
+            let aged = schedule.priority.level() as f32 + rate * waited;
+            // La conversione `as` satura a `u8::MAX`
+            let effective = aged as u8;
+            if effective != schedule.effective_priority {
+                schedule.effective_priority = effective;
+                changed += 1;
+            }
+        }
+
+        info!("Priorità effettiva aggiornata per invecchiamento su {} task", changed);
+        Ok(changed)
+    }
+
+    /// Attende che tutti i task specificati siano in stato `Completed` o `Failed`.
+    ///
+    /// Il task asincrono chiamante resta sospeso fino alla notifica di un cambio di stato,
+    /// senza effettuare polling.
+    ///
+    /// # Ritorna
+    /// - `Ok(())`: Se tutti i task sono conclusi entro `timeout`.
+    /// - `Err(CrudError::Timeout)`: Se `timeout` scade prima della conclusione di tutti i task.
+    /// - `Err(CrudError::NotFound(id))`: Se uno dei task non esiste.
+    pub async fn wait_for_all(&self, ids: &[u32], timeout: Duration) -> Result<(), CrudError> {
+        let deadline = tokio::time::Instant::now() + timeout;
+        let mut receiver = TASK_STATUS_VERSION.subscribe();
+
+        loop {
+            // Segna la versione corrente come letta prima del controllo, per non perdere notifiche
+            receiver.borrow_and_update();
+            if self.all_finished(ids)? {
+                return Ok(());
+            }
+            match tokio::time::timeout_at(deadline, receiver.changed()).await {
+                Ok(_) => continue,
+                Err(_) => {
+                    warn!("Timeout nell'attesa di {} task", ids.len());
+                    return Err(CrudError::Timeout);
+                }
+            }
+        }
+    }
+
+    /// Verifica se tutti i task specificati sono in uno stato finale.
+    fn all_finished(&self, ids: &[u32]) -> Result<bool, CrudError> {
+        let mut tasks = self.registry.tasks().lock().map_err(|e| CrudError::LockError(e.to_string()))?;
+        for id in ids {
+            let task = tasks.get(id).ok_or(CrudError::NotFound(*id))?;
+            if task.status != TaskStatus::Completed && task.status != TaskStatus::Failed {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+
+    /// Sposta in `ARCHIVED_TASKS` i task completati da più di `older_than`, eliminandone lo stato dello scheduler.
+    ///
+    /// # Ritorna
+    /// Il numero di task archiviati.
+    pub fn archive_completed(&self, older_than: Duration) -> Result<usize, CrudError> {
+        let threshold = now_millis() - older_than.as_millis() as i64;
+
+        let mut tasks = self.registry.tasks().lock().map_err(|e| CrudError::LockError(e.to_string()))?;
+        let mut archived = self.registry.archived_tasks().lock().map_err(|e| CrudError::LockError(e.to_string()))?;
+
+        let expired: Vec<u32> = tasks
+            .values()
+            .filter(|t| t.status == TaskStatus::Completed && t.completed_at.map_or(false, |at| at <= threshold))
+            .map(|t| t.id)
+            .collect();
+
+        let mut schedules = TASK_SCHEDULES.lock().map_err(|e| CrudError::LockError(e.to_string()))?;
+        for id in expired.iter() {
+            if let Some(task) = tasks.remove(id) {
+                archived.insert(*id, task);
+            }
+            // Lo stato dello scheduler si elimina solo con il task: quello impostato prima di `submit` resta valido
+            schedules.remove(id);
+        }
+
+        info!("Archiviati {} task completati", expired.len());
+        Ok(expired.len())
+    }
+
+    /// Restituisce una copia di tutti i task archiviati.
+    pub fn archived_tasks(&self) -> Vec<Task> {
+        match self.registry.archived_tasks().lock() {
+            Ok(archived) => archived.values().cloned().collect(),
+            Err(_) => Vec::new(),
+        }
+    }
+
+    /// Riporta in `TASKS_IN_MEMORY` il task archiviato con l'ID specificato.
+    pub fn restore_archived(&self, id: u32) -> Result<(), CrudError> {
+        // Stesso ordine di lock di `archive_completed` per evitare deadlock
+        let mut tasks = self.registry.tasks().lock().map_err(|e| CrudError::LockError(e.to_string()))?;
+        let mut archived = self.registry.archived_tasks().lock().map_err(|e| CrudError::LockError(e.to_string()))?;
+        let task = archived.remove(&id).ok_or(CrudError::NotFound(id))?;
+        tasks.insert(id, task);
+        Ok(())
+    }
+}
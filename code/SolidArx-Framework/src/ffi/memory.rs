@@ -0,0 +1,171 @@
+//! Interfaccia C del `MemoryManager`.
+//!
+//! Le funzioni utilizzano un `MemoryManager` globale al processo, inizializzato con `arx_init_memory`.
+//! I puntatori restituiti da `arx_allocate` devono essere rilasciati esclusivamente con `arx_deallocate`.
+
+use std::collections::HashMap;
+use std::os::raw::c_int;
+use std::sync::Mutex;
+use lazy_static::lazy_static;
+use log::{error, info};
+
+use crate::config::{global_config::ApplicationType, memory_config::MemoryConfig};
+use crate::core::memory_management::{AllocationStrategy, ManagedBuffer, MemoryManager};
+
+/// Usa la strategia di default del `MemoryManager` globale (`PoolBased`).
+pub const ARX_STRATEGY_DEFAULT: u8 = 0;
+/// Allocazione dinamica tramite l'allocatore di sistema.
+pub const ARX_STRATEGY_STANDARD: u8 = 1;
+/// Allocazione da un buffer del pool.
+pub const ARX_STRATEGY_POOL: u8 = 2;
+/// Allocazione con la dimensione fissa configurata per i sistemi embedded.
+pub const ARX_STRATEGY_EMBEDDED: u8 = 3;
+
+/// Stato globale dell'interfaccia C.
+/// - `manager`: Il `MemoryManager` condiviso.
+/// - `live`: Buffer consegnati al chiamante, indicizzati per indirizzo, con l'indicazione
+///   se il buffer va restituito al pool al rilascio.
+struct FfiMemory {
+    manager: MemoryManager,
+    live: HashMap<usize, (ManagedBuffer, bool)>,
+}
+
+lazy_static! {
+    static ref FFI_MEMORY: Mutex<Option<FfiMemory>> = Mutex::new(None);
+}
+
+/// Converte il codice della strategia ricevuto da C.
+fn strategy_from_code(code: u8) -> Option<Option<AllocationStrategy>> {
+    match code {
+        ARX_STRATEGY_DEFAULT => Some(None),
+        ARX_STRATEGY_STANDARD => Some(Some(AllocationStrategy::Standard)),
+        ARX_STRATEGY_POOL => Some(Some(AllocationStrategy::PoolBased)),
+        ARX_STRATEGY_EMBEDDED => Some(Some(AllocationStrategy::CustomEmbedded)),
+        _ => None,
+    }
+}
+
+/// Inizializza (o reinizializza) il `MemoryManager` globale con un pool di `pool_size` byte
+/// suddiviso in buffer da `buffer_size` byte.
+///
+/// # Ritorna
+/// `0` in caso di successo, `-1` se la configurazione non è valida o lo stato globale non è accessibile.
+#[no_mangle]
+pub extern "C" fn arx_init_memory(buffer_size: usize, pool_size: usize) -> c_int {
+    if buffer_size == 0 {
+        error!("arx_init_memory: la dimensione dei buffer deve essere maggiore di zero");
+        return -1;
+    }
+    let manager = match MemoryManager::new(ApplicationType::WebApp, MemoryConfig::new(pool_size, buffer_size, 1)) {
+        Ok(manager) => manager,
+        Err(e) => {
+            error!("arx_init_memory: {}", e);
+            return -1;
+        }
+    };
+    match FFI_MEMORY.lock() {
+        Ok(mut state) => {
+            *state = Some(FfiMemory { manager, live: HashMap::new() });
+            info!("MemoryManager FFI inizializzato con buffer da {} byte", buffer_size);
+            0
+        }
+        Err(_) => -1,
+    }
+}
+
+/// Alloca almeno `size` byte con la strategia indicata (vedi le costanti `ARX_STRATEGY_*`).
+///
+/// Se il buffer del pool è più piccolo di `size` viene restituito al pool e la memoria
+/// viene allocata dinamicamente.
+///
+/// # Ritorna
+/// Il puntatore al buffer, oppure `NULL` se `size` è zero, la strategia non è valida,
+/// il `MemoryManager` non è inizializzato o l'allocazione fallisce.
+#[no_mangle]
+pub extern "C" fn arx_allocate(size: usize, strategy: u8) -> *mut u8 {
+    let strategy = match strategy_from_code(strategy) {
+        Some(strategy) => strategy,
+        None => {
+            error!("arx_allocate: strategia {} non valida", strategy);
+            return std::ptr::null_mut();
+        }
+    };
+    if size == 0 {
+        return std::ptr::null_mut();
+    }
+    let mut guard = match FFI_MEMORY.lock() {
+        Ok(guard) => guard,
+        Err(_) => return std::ptr::null_mut(),
+    };
+    let state = match guard.as_mut() {
+        Some(state) => state,
+        None => {
+            error!("arx_allocate: MemoryManager non inizializzato, chiamare arx_init_memory");
+            return std::ptr::null_mut();
+        }
+    };
+
+    // Solo i buffer del pool tornano al `MemoryManager`, gli altri vengono liberati al rilascio
+    let pooled = strategy.as_ref().map_or(true, |s| s.is_pool_based());
+    let buffer_size = state.manager.memory_stats().buffer_size;
+    let (mut buffer, managed) = match state.manager.allocate(strategy, size) {
+        Ok(buffer) if buffer.len() >= size => {
+            let managed = pooled && buffer.len() == buffer_size;
+            (buffer, managed)
+        }
+        Ok(buffer) => {
+            // Buffer a dimensione fissa troppo piccolo: torna al gestore e si alloca dinamicamente
+            if let Err(e) = state.manager.deallocate(buffer) {
+                error!("arx_allocate: {}", e);
+            }
+            (ManagedBuffer::from(vec![0u8; size]), false)
+        }
+        Err(e) => {
+            error!("arx_allocate: {}", e);
+            return std::ptr::null_mut();
+        }
+    };
+
+    // Il buffer resta in `live`: il chiamante scrive nella sua memoria tramite il puntatore
+    let ptr = buffer.as_mut_ptr();
+    state.live.insert(ptr as usize, (buffer, managed));
+    ptr
+}
+
+/// Rilascia un buffer ottenuto con `arx_allocate`.
+///
+/// `size` deve corrispondere alla dimensione richiesta all'allocazione; i puntatori `NULL`
+/// o sconosciuti vengono ignorati.
+#[no_mangle]
+pub extern "C" fn arx_deallocate(ptr: *mut u8, size: usize) {
+    if ptr.is_null() {
+        return;
+    }
+    let mut guard = match FFI_MEMORY.lock() {
+        Ok(guard) => guard,
+        Err(_) => return,
+    };
+    let Some(state) = guard.as_mut() else { return };
+    let Some((buffer, managed)) = state.live.remove(&(ptr as usize)) else {
+        error!("arx_deallocate: puntatore {:p} non allocato da arx_allocate", ptr);
+        return;
+    };
+    if size > buffer.len() {
+        error!("arx_deallocate: dimensione {} maggiore di quella allocata ({})", size, buffer.len());
+    }
+
+    if managed {
+        if let Err(e) = state.manager.deallocate(buffer) {
+            error!("arx_deallocate: {}", e);
+        }
+    }
+}
+
+/// Numero di buffer liberi nel pool del `MemoryManager` globale (`0` se non inizializzato).
+#[no_mangle]
+pub extern "C" fn arx_pool_free() -> usize {
+    match FFI_MEMORY.lock() {
+        Ok(state) => state.as_ref().map_or(0, |s| s.manager.memory_stats().free_buffers),
+        Err(_) => 0,
+    }
+}
@@ -0,0 +1,7 @@
+//! Interfacce esportate verso altri linguaggi (C/C++) tramite ABI C.
+//!
+//! L'header C corrispondente viene generato da `cbindgen` in `OUT_DIR/include/arx_memory.h`
+//! durante la compilazione con la feature `ffi`. La libreria statica per il collegamento da C/C++
+//! si ottiene con `cargo rustc --lib --release --features ffi --crate-type staticlib`.
+
+pub mod memory;
@@ -46,6 +46,9 @@ pub mod ml;
 #[cfg(feature = "frontend")]
 pub mod frontend;
 
+#[cfg(feature = "ffi")]
+pub mod ffi;
+
 
 // ### Estensione del Framework
 //
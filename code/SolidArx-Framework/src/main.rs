@@ -1,5 +1,11 @@
 use solid_arx::cli::{parse_arguments,Commands};
 use solid_arx::core::system_core::CoreSystem;
+use solid_arx::core::memory_management::{
+    define_buffer_size,
+    define_multiplier,
+    define_pool_size,
+    ConfigResolutionMode,
+};
 use solid_arx::config::{
     global_config::CoreConfig,
     memory_config::MemoryConfig,
@@ -30,8 +36,8 @@ fn handle_init(
         // Configurazioni Core e Memoria
         let core_config = CoreConfig::new(app_type.clone(), *max_threads);
         let memory_config = MemoryConfig::new(
-            define_pool_size(app_type.clone(), *pool_size),
-            define_buffer_size(app_type.clone(), *buffer_size),
+            define_pool_size(app_type.clone(), *pool_size, ConfigResolutionMode::Interactive),
+            define_buffer_size(app_type.clone(), *buffer_size, ConfigResolutionMode::Interactive),
             define_multiplier(app_type.clone(), *memory_scale),
         );
 
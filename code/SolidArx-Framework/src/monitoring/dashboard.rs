@@ -0,0 +1,132 @@
+//! Modulo per la dashboard HTTP delle statistiche del `MemoryManager`.
+//!
+//! Disponibile solo con la feature `http-dashboard`. Il server risponde a:
+//! - `GET /memory`: pagina HTML con le statistiche del pool.
+//! - `GET /memory/json`: statistiche in formato JSON (`MemoryStats`).
+//! - `GET /memory/metrics`: statistiche nel formato testuale di Prometheus.
+
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::thread::JoinHandle;
+use log::{info, error};
+use tiny_http::{Header, Response, Server};
+
+use crate::core::memory_management::{AllocationStrategy, MemoryManager, MemoryStats, PoolCounters};
+use crate::core::system_core::CoreError;
+
+/// Handle della dashboard in esecuzione.
+///
+/// Il server viene arrestato con `stop()` oppure quando l'handle viene rilasciato.
+pub struct DashboardHandle {
+    addr: SocketAddr,
+    server: Arc<Server>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl DashboardHandle {
+    /// Indirizzo effettivo su cui il server è in ascolto (utile quando si usa la porta 0).
+    pub fn addr(&self) -> SocketAddr {
+        self.addr
+    }
+
+    /// Arresta il server e attende la terminazione del thread.
+    pub fn stop(mut self) {
+        self.shutdown();
+    }
+
+    fn shutdown(&mut self) {
+        self.server.unblock();
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+impl Drop for DashboardHandle {
+    fn drop(&mut self) {
+        self.shutdown();
+    }
+}
+
+/// Genera la pagina HTML della dashboard.
+fn render_html(stats: &MemoryStats) -> String {
+    format!(
+        "<!DOCTYPE html><html><head><title>Arx Memory</title></head><body>\
+        <h1>MemoryManager</h1><table>\
+        <tr><td>Strategia</td><td>{}</td></tr>\
+        <tr><td>Dimensione buffer</td><td>{}</td></tr>\
+        <tr><td>Capacità pool</td><td>{}</td></tr>\
+        <tr><td>Buffer liberi</td><td>{}</td></tr>\
+        <tr><td>Allocazioni</td><td>{}</td></tr>\
+        <tr><td>Deallocazioni</td><td>{}</td></tr>\
+        </table></body></html>",
+        stats.strategy,
+        stats.buffer_size,
+        stats.pool_capacity,
+        stats.free_buffers,
+        stats.total_allocations,
+        stats.total_deallocations,
+    )
+}
+
+/// Genera le metriche nel formato testuale di Prometheus.
+fn render_metrics(stats: &MemoryStats) -> String {
+    let metrics = [
+        ("arx_memory_buffer_size_bytes", "Dimensione di ciascun buffer del pool", stats.buffer_size as u64),
+        ("arx_memory_pool_capacity", "Numero totale di buffer del pool", stats.pool_capacity as u64),
+        ("arx_memory_pool_free_buffers", "Numero di buffer liberi nel pool", stats.free_buffers as u64),
+        ("arx_memory_allocations_total", "Numero di allocazioni effettuate", stats.total_allocations),
+        ("arx_memory_deallocations_total", "Numero di deallocazioni effettuate", stats.total_deallocations),
+    ];
+    let mut output = String::new();
+    for (name, help, value) in metrics {
+        let kind = if name.ends_with("_total") { "counter" } else { "gauge" };
+        output.push_str(&format!("# HELP {} {}\n# TYPE {} {}\n{} {}\n", name, help, name, kind, name, value));
+    }
+    output
+}
+
+impl MemoryManager {
+    /// Avvia la dashboard HTTP delle statistiche sull'indirizzo specificato.
+    ///
+    /// Il server legge i contatori condivisi del `MemoryManager`, pertanto le statistiche
+    /// mostrate sono sempre aggiornate senza bloccare il gestore della memoria.
+    ///
+    /// # Ritorna
+    /// Un `DashboardHandle` per arrestare il server, oppure `CoreError::InitializationError`
+    /// se non è possibile mettersi in ascolto sull'indirizzo.
+    pub fn start_dashboard(&self, addr: SocketAddr) -> Result<DashboardHandle, CoreError> {
+        let server = Server::http(addr)
+            .map_err(|e| CoreError::InitializationError(format!("Avvio della dashboard fallito: {}", e), Vec::new()))?;
+        let addr = server
+            .server_addr()
+            .to_ip()
+            .ok_or_else(|| CoreError::InitializationError("Indirizzo della dashboard non valido".to_string(), Vec::new()))?;
+        let server = Arc::new(server);
+
+        let strategy: AllocationStrategy = self.default_allocation_strategy.clone();
+        let buffer_size = self.memory_config.buffer_size;
+        let counters: Arc<PoolCounters> = Arc::clone(&self.counters);
+        let worker = Arc::clone(&server);
+
+        let thread = std::thread::spawn(move || {
+            for request in worker.incoming_requests() {
+                let stats = MemoryStats::from_counters(&strategy, buffer_size, &counters);
+                let (body, content_type, status) = match request.url() {
+                    "/memory" => (render_html(&stats), "text/html; charset=utf-8", 200),
+                    "/memory/json" => (serde_json::to_string(&stats).unwrap_or_default(), "application/json", 200),
+                    "/memory/metrics" => (render_metrics(&stats), "text/plain; version=0.0.4", 200),
+                    _ => ("Not Found".to_string(), "text/plain", 404),
+                };
+                let header = Header::from_bytes("Content-Type", content_type).expect("Header statico valido");
+                let response = Response::from_string(body).with_header(header).with_status_code(status);
+                if let Err(e) = request.respond(response) {
+                    error!("Errore nella risposta della dashboard: {}", e);
+                }
+            }
+        });
+
+        info!("Dashboard della memoria in ascolto su http://{}/memory", addr);
+        Ok(DashboardHandle { addr, server, thread: Some(thread) })
+    }
+}
@@ -0,0 +1,221 @@
+//! Modulo per l'ispezione a runtime del `MemoryManager` tramite socket Unix.
+//!
+//! Disponibile solo con la feature `ipc` sui sistemi Unix. Il protocollo è JSON delimitato
+//! da newline: ogni riga inviata dal client è un `IpcCommand` e il server risponde con una
+//! riga contenente un `IpcResponse`. I comandi supportati sono:
+//! - `{"command":"stats"}`: statistiche correnti (`MemoryStats`).
+//! - `{"command":"reset"}`: azzera i contatori delle allocazioni.
+//! - `{"command":"drain"}`: rilascia i buffer liberi del pool.
+//! - `{"command":"refill"}`: riporta il pool alla capacità configurata.
+//!
+//! Il client da riga di comando è il binario `arx-mem-cli` in `tools/arx-mem-cli`.
+
+use std::io::{BufRead, BufReader, Write};
+use std::os::unix::fs::{DirBuilderExt, FileTypeExt, PermissionsExt};
+use std::os::unix::net::{UnixListener, UnixStream};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread::JoinHandle;
+use log::{info, warn, error};
+use serde::{Serialize, Deserialize};
+
+use crate::core::memory_management::{MemoryManager, MemoryStats};
+use crate::core::system_core::CoreError;
+
+/// Comando inviato al server IPC.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "command", rename_all = "lowercase")]
+pub enum IpcCommand {
+    Stats,
+    Reset,
+    Drain,
+    Refill,
+}
+
+/// Risposta del server IPC: le statistiche dopo l'esecuzione del comando oppure un errore.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum IpcResponse {
+    Stats(MemoryStats),
+    Error(String),
+}
+
+/// Handle del server IPC in esecuzione.
+///
+/// Il server viene arrestato con `stop()` oppure quando l'handle viene rilasciato;
+/// all'arresto il file del socket viene rimosso.
+pub struct IpcServerHandle {
+    socket_path: PathBuf,
+    running: Arc<AtomicBool>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl IpcServerHandle {
+    /// Percorso del socket su cui il server è in ascolto.
+    pub fn socket_path(&self) -> &Path {
+        &self.socket_path
+    }
+
+    /// Arresta il server e attende la terminazione del thread.
+    pub fn stop(mut self) {
+        self.shutdown();
+    }
+
+    fn shutdown(&mut self) {
+        self.running.store(false, Ordering::SeqCst);
+        // Una connessione fittizia sblocca `accept` così che il thread possa terminare
+        let _ = UnixStream::connect(&self.socket_path);
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+        let _ = std::fs::remove_file(&self.socket_path);
+    }
+}
+
+impl Drop for IpcServerHandle {
+    fn drop(&mut self) {
+        self.shutdown();
+    }
+}
+
+/// Esegue un comando sul gestore della memoria e ne restituisce la risposta.
+fn handle_command(manager: &Mutex<MemoryManager>, command: IpcCommand) -> IpcResponse {
+    let mut manager = match manager.lock() {
+        Ok(manager) => manager,
+        Err(e) => return IpcResponse::Error(e.to_string()),
+    };
+    match command {
+        IpcCommand::Stats => {}
+        IpcCommand::Reset => manager.reset_stats(),
+        IpcCommand::Drain => {
+            manager.drain_pool();
+        }
+        IpcCommand::Refill => {
+            manager.refill_pool();
+        }
+    }
+    IpcResponse::Stats(manager.memory_stats())
+}
+
+/// Serve i comandi di un client finché la connessione resta aperta.
+fn serve_client(manager: &Mutex<MemoryManager>, stream: UnixStream) -> std::io::Result<()> {
+    let mut writer = stream.try_clone()?;
+    for line in BufReader::new(stream).lines() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let response = match serde_json::from_str::<IpcCommand>(&line) {
+            Ok(command) => handle_command(manager, command),
+            Err(e) => IpcResponse::Error(format!("Comando non valido: {}", e)),
+        };
+        let mut payload = serde_json::to_string(&response).map_err(std::io::Error::other)?;
+        payload.push('\n');
+        writer.write_all(payload.as_bytes())?;
+    }
+    Ok(())
+}
+
+impl MemoryManager {
+    /// Avvia il server IPC per l'ispezione del gestore sul socket Unix indicato.
+    ///
+    /// Il gestore è condiviso dietro un `Mutex`, acquisito solo durante l'esecuzione di ciascun
+    /// comando. Ogni client viene servito in un thread dedicato.
+    ///
+    /// Il socket viene creato con permessi `0600`: il protocollo non prevede autenticazione, quindi
+    /// solo l'utente del processo può inviare comandi. Il `bind` avviene in una directory temporanea
+    /// con permessi `0700` accanto a `socket_path` e il socket viene spostato al suo posto solo dopo
+    /// aver applicato i permessi, così che nessun altro utente possa connettersi nel frattempo.
+    ///
+    /// # Parametri
+    /// - `manager`: Il gestore della memoria da ispezionare.
+    /// - `socket_path`: Il percorso del socket; un socket già presente viene sostituito.
+    ///
+    /// # Ritorna
+    /// Un `IpcServerHandle` per arrestare il server, oppure `CoreError::InitializationError`
+    /// se il percorso è occupato da un file che non è un socket o non è possibile mettersi
+    /// in ascolto sul socket.
+    pub fn start_ipc_server(manager: Arc<Mutex<MemoryManager>>, socket_path: &Path) -> Result<IpcServerHandle, CoreError> {
+        let init_error = |e: std::io::Error| {
+            CoreError::InitializationError(format!("Avvio del server IPC fallito: {}", e), Vec::new()).with_context("start_ipc_server")
+        };
+
+        // Un socket rimasto da un'esecuzione precedente impedirebbe il `bind`, gli altri file non vengono toccati
+        if let Ok(metadata) = std::fs::symlink_metadata(socket_path) {
+            if !metadata.file_type().is_socket() {
+                return Err(CoreError::InitializationError(
+                    format!("Il percorso {:?} esiste e non è un socket", socket_path),
+                    Vec::new(),
+                ).with_context("start_ipc_server"));
+            }
+            std::fs::remove_file(socket_path).map_err(init_error)?;
+        }
+        let listener = bind_private(socket_path).map_err(init_error)?;
+
+        let running = Arc::new(AtomicBool::new(true));
+        let flag = Arc::clone(&running);
+        let thread = std::thread::spawn(move || {
+            for stream in listener.incoming() {
+                if !flag.load(Ordering::SeqCst) {
+                    break;
+                }
+                match stream {
+                    Ok(stream) => {
+                        let manager = Arc::clone(&manager);
+                        std::thread::spawn(move || {
+                            if let Err(e) = serve_client(&manager, stream) {
+                                warn!("Connessione IPC interrotta: {}", e);
+                            }
+                        });
+                    }
+                    Err(e) => error!("Errore nell'accettazione di una connessione IPC: {}", e),
+                }
+            }
+        });
+
+        info!("Server IPC della memoria in ascolto su {:?}", socket_path);
+        Ok(IpcServerHandle { socket_path: socket_path.to_path_buf(), running, thread: Some(thread) })
+    }
+}
+
+/// Crea il socket in una directory temporanea con permessi `0700`, ne imposta i permessi a `0600`
+/// e lo sposta in `socket_path`, rimuovendo la directory temporanea in ogni caso.
+fn bind_private(socket_path: &Path) -> std::io::Result<UnixListener> {
+    let file_name = socket_path.file_name().ok_or_else(|| {
+        std::io::Error::new(std::io::ErrorKind::InvalidInput, format!("Percorso del socket non valido: {:?}", socket_path))
+    })?;
+    let mut dir_name = std::ffi::OsString::from(".");
+    dir_name.push(file_name);
+    dir_name.push(format!(".{}", std::process::id()));
+    let private_dir = socket_path.with_file_name(dir_name);
+    std::fs::DirBuilder::new().mode(0o700).create(&private_dir)?;
+
+    let staged_path = private_dir.join("s");
+    let bound = UnixListener::bind(&staged_path).and_then(|listener| {
+        std::fs::set_permissions(&staged_path, std::fs::Permissions::from_mode(0o600))?;
+        std::fs::rename(&staged_path, socket_path)?;
+        Ok(listener)
+    });
+    if let Err(e) = std::fs::remove_dir_all(&private_dir) {
+        warn!("Rimozione della directory temporanea {:?} fallita: {}", private_dir, e);
+    }
+    bound
+}
+
+/// Invia un comando al server IPC in ascolto sul socket e ne attende la risposta.
+///
+/// # Ritorna
+/// L'`IpcResponse` del server, oppure `CoreError::GenericError` se la connessione o la
+/// decodifica della risposta falliscono.
+pub fn send_command(socket_path: &Path, command: IpcCommand) -> Result<IpcResponse, CoreError> {
+    let io_error = |e: std::io::Error| CoreError::GenericError(format!("Comunicazione IPC fallita: {}", e), Vec::new());
+    let mut stream = UnixStream::connect(socket_path).map_err(io_error)?;
+    let mut request = serde_json::to_string(&command).map_err(|e| CoreError::GenericError(e.to_string(), Vec::new()))?;
+    request.push('\n');
+    stream.write_all(request.as_bytes()).map_err(io_error)?;
+
+    let mut line = String::new();
+    BufReader::new(stream).read_line(&mut line).map_err(io_error)?;
+    serde_json::from_str(&line).map_err(|e| CoreError::GenericError(format!("Risposta IPC non valida: {}", e), Vec::new()))
+}
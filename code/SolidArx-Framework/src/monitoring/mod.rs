@@ -1,2 +1,8 @@
 pub mod logger;
 pub mod metrics;
+#[cfg(feature = "http-dashboard")]
+pub mod dashboard;
+#[cfg(all(unix, feature = "ipc"))]
+pub mod ipc;
+#[cfg(all(feature = "wasm", feature = "wasm-bindgen"))]
+pub mod wasm_bridge;
@@ -0,0 +1,66 @@
+//! Modulo per l'esposizione delle statistiche del `MemoryManager` a JavaScript.
+//!
+//! Disponibile solo con le feature `wasm` e `wasm-bindgen`. Dopo aver registrato un
+//! `MemoryManager` con `expose_to_js`, il codice JavaScript può interrogare lo stato del pool
+//! tramite `pool_free_buffers()`, `pool_capacity()` e `memory_stats()`.
+
+use std::sync::{Arc, Mutex};
+use std::sync::atomic::Ordering;
+use log::info;
+use wasm_bindgen::prelude::*;
+
+use crate::core::memory_management::{AllocationStrategy, MemoryManager, MemoryStats, PoolCounters};
+
+/// Sorgente delle statistiche esposte a JavaScript: strategia, dimensione dei buffer e contatori condivisi.
+type StatsSource = (AllocationStrategy, usize, Arc<PoolCounters>);
+
+/// `MemoryManager` registrato per JavaScript con `expose_to_js`.
+static JS_STATS_SOURCE: Mutex<Option<StatsSource>> = Mutex::new(None);
+
+impl MemoryManager {
+    /// Rende le statistiche di questo `MemoryManager` accessibili alle funzioni esportate a JavaScript.
+    ///
+    /// Una nuova registrazione sostituisce la precedente.
+    pub fn expose_to_js(&self) {
+        if let Ok(mut source) = JS_STATS_SOURCE.lock() {
+            *source = Some((self.default_allocation_strategy.clone(), self.memory_config.buffer_size, Arc::clone(&self.counters)));
+            info!("Statistiche della memoria esposte a JavaScript");
+        }
+    }
+}
+
+/// Legge un contatore del `MemoryManager` registrato, oppure 0 se nessuno è stato registrato.
+fn read_counter(read: impl Fn(&PoolCounters) -> usize) -> u32 {
+    match JS_STATS_SOURCE.lock() {
+        Ok(source) => source.as_ref().map_or(0, |(_, _, counters)| read(counters) as u32),
+        Err(_) => 0,
+    }
+}
+
+/// Numero di buffer liberi nel pool.
+#[wasm_bindgen]
+pub fn pool_free_buffers() -> u32 {
+    read_counter(|counters| counters.free_buffers.load(Ordering::Relaxed))
+}
+
+/// Numero totale di buffer del pool.
+#[wasm_bindgen]
+pub fn pool_capacity() -> u32 {
+    read_counter(|counters| counters.pool_capacity.load(Ordering::Relaxed))
+}
+
+/// Statistiche complete (`MemoryStats`) come oggetto JavaScript, oppure `null` se nessun
+/// `MemoryManager` è stato registrato.
+#[wasm_bindgen]
+pub fn memory_stats() -> Result<JsValue, JsValue> {
+    let stats: Option<MemoryStats> = JS_STATS_SOURCE
+        .lock()
+        .map_err(|e| JsValue::from_str(&e.to_string()))?
+        .as_ref()
+        .map(|(strategy, buffer_size, counters)| MemoryStats::from_counters(strategy, *buffer_size, counters));
+
+    match stats {
+        Some(stats) => serde_wasm_bindgen::to_value(&stats).map_err(|e| JsValue::from_str(&e.to_string())),
+        None => Ok(JsValue::NULL),
+    }
+}
@@ -0,0 +1,117 @@
+//! Modulo per la cifratura a livello di colonna delle query del `ConnectionManager`.
+//!
+//! Disponibile solo con la feature `encryption`. I valori delle colonne registrate con
+//! `ConnectionManager::register_encrypted_column` vengono cifrati con AES-256-GCM e salvati
+//! come stringa esadecimale composta da nonce (12 byte) seguito dal testo cifrato.
+
+use aes_gcm::{
+    aead::{Aead, AeadCore, KeyInit, OsRng},
+    Aes256Gcm, Key, Nonce,
+};
+
+use crate::network::connection_management::{ConnectionErrors, SqlValue, ToSql};
+
+/// Dimensione in byte del nonce di AES-256-GCM.
+const NONCE_LEN: usize = 12;
+
+/// Chiave AES-256 associata ad una colonna cifrata.
+///
+/// L'implementazione di `Debug` non espone il contenuto della chiave.
+#[derive(Clone)]
+pub(crate) struct EncryptionKey([u8; 32]);
+
+impl EncryptionKey {
+    pub(crate) fn new(key: &[u8; 32]) -> Self {
+        EncryptionKey(*key)
+    }
+
+    fn cipher(&self) -> Aes256Gcm {
+        Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(&self.0))
+    }
+
+    /// Cifra il testo in chiaro restituendo nonce e testo cifrato in formato esadecimale.
+    pub(crate) fn encrypt(&self, plaintext: &str) -> Result<String, ConnectionErrors> {
+        let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+        let ciphertext = self
+            .cipher()
+            .encrypt(&nonce, plaintext.as_bytes())
+            .map_err(|e| ConnectionErrors::UnknownError(format!("Cifratura fallita: {}", e)))?;
+        Ok(nonce.iter().chain(ciphertext.iter()).map(|b| format!("{:02x}", b)).collect())
+    }
+
+    /// Decifra un valore prodotto da `encrypt`.
+    ///
+    /// # Ritorna
+    /// Il testo in chiaro, oppure `None` se il valore non è un testo cifrato valido per questa chiave.
+    pub(crate) fn decrypt(&self, stored: &str) -> Option<String> {
+        let bytes = decode_hex(stored)?;
+        if bytes.len() <= NONCE_LEN {
+            return None;
+        }
+        let (nonce, ciphertext) = bytes.split_at(NONCE_LEN);
+        let plaintext = self.cipher().decrypt(Nonce::from_slice(nonce), ciphertext).ok()?;
+        String::from_utf8(plaintext).ok()
+    }
+}
+
+impl std::fmt::Debug for EncryptionKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "EncryptionKey(***)")
+    }
+}
+
+/// Converte una stringa esadecimale in byte.
+fn decode_hex(value: &str) -> Option<Vec<u8>> {
+    if value.len() % 2 != 0 {
+        return None;
+    }
+    (0..value.len())
+        .step_by(2)
+        .map(|i| value.get(i..i + 2).and_then(|byte| u8::from_str_radix(byte, 16).ok()))
+        .collect()
+}
+
+/// Trait per le righe lette con `ConnectionManager::query_as` che contengono colonne cifrate.
+///
+/// `query_as` è generico sul tipo della riga, pertanto ogni riga espone la propria tabella
+/// e l'accesso alle colonne testuali che possono essere decifrate.
+pub trait EncryptedRow {
+    /// Nome della tabella da cui proviene la riga.
+    fn table_name() -> &'static str;
+
+    /// Restituisce il valore della colonna indicata, se presente nella riga.
+    fn column_mut(&mut self, column: &str) -> Option<&mut String>;
+}
+
+/// Parametro di `ConnectionManager::execute_parameterized` destinato ad una colonna cifrata.
+///
+/// La colonna viene indicata esplicitamente, pertanto la cifratura non dipende dal tipo di istruzione
+/// (`INSERT`, `UPDATE`, `WHERE` di una `DELETE`, ...) né dalla posizione del segnaposto nella query.
+/// I parametri non racchiusi in un `EncryptedParam` vengono inviati in chiaro.
+pub struct EncryptedParam<'a> {
+    table: &'a str,
+    column: &'a str,
+    value: &'a dyn ToSql,
+}
+
+impl<'a> EncryptedParam<'a> {
+    /// Crea un parametro da cifrare con la chiave registrata per `table.column`.
+    ///
+    /// # Parametri
+    /// - `table`: La tabella che contiene la colonna.
+    /// - `column`: La colonna registrata con `register_encrypted_column`.
+    /// - `value`: Il valore testuale in chiaro.
+    pub fn new(table: &'a str, column: &'a str, value: &'a dyn ToSql) -> Self {
+        EncryptedParam { table, column, value }
+    }
+}
+
+impl ToSql for EncryptedParam<'_> {
+    fn to_sql_value(&self) -> SqlValue {
+        self.value.to_sql_value()
+    }
+
+    fn encrypted_column(&self) -> Option<(&str, &str)> {
+        Some((self.table, self.column))
+    }
+}
@@ -1,3 +1,5 @@
 pub mod connection_management;
+#[cfg(feature = "encryption")]
+pub mod encryption;
 pub mod lead_balancer;
 pub mod resource_pool;
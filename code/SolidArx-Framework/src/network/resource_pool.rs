@@ -0,0 +1,219 @@
+//! Modulo per il pooling delle connessioni al database.
+//!
+//! Il `ConnectionPool` mantiene un insieme di connessioni aperte create dal `ConnectionManager`,
+//! così che i chiamanti non debbano riconnettersi ad ogni operazione. Le connessioni vengono
+//! prestate con `acquire` come `PooledConnection`, che le restituisce al pool quando viene rilasciata.
+//! Prima di essere prestate, le connessioni inattive vengono verificate con `ConnectionManager::health_check`.
+//! Le nuove connessioni vengono aperte con `initialize_connection`, che applica i tentativi con backoff,
+//! le impostazioni di sessione e le migrazioni configurate.
+
+use std::collections::VecDeque;
+use std::ops::{Deref, DerefMut};
+use std::sync::{Arc, Mutex};
+use log::{info, warn};
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+use tokio::time::{timeout, Duration};
+
+use crate::network::connection_management::{ConnectionErrors, ConnectionManager, DatabaseConnection, DbConnection};
+
+/// Pool di connessioni al database con un numero minimo di connessioni inattive e un limite massimo.
+///
+/// # Campi
+/// - `manager`: Il gestore utilizzato per aprire nuove connessioni.
+/// - `min_idle`: Connessioni inattive aperte alla creazione del pool e ripristinate dopo quelle scartate.
+/// - `max_connections`: Numero massimo di connessioni prestate contemporaneamente.
+/// - `acquire_timeout`: Attesa massima di `acquire` quando tutte le connessioni sono in uso.
+/// - `connections`: Connessioni inattive, condivise con le `PooledConnection` che le restituiscono.
+/// - `slots`: Un permesso per ciascuna connessione che può ancora essere prestata.
+pub struct ConnectionPool {
+    manager: ConnectionManager,
+    min_idle: u32,
+    max_connections: u32,
+    acquire_timeout: Duration,
+    connections: Arc<Mutex<VecDeque<DbConnection>>>,
+    slots: Arc<Semaphore>,
+}
+
+impl ConnectionPool {
+    /// Crea il pool aprendo subito `min_idle` connessioni.
+    ///
+    /// # Parametri
+    /// - `manager`: Il gestore del database a cui connettersi.
+    /// - `min_idle`: Il numero di connessioni da aprire alla creazione.
+    /// - `max_connections`: Il numero massimo di connessioni in uso contemporaneamente.
+    /// - `acquire_timeout`: L'attesa massima per ottenere una connessione.
+    ///
+    /// # Ritorna
+    /// - `Ok(ConnectionPool)`: Il pool con le connessioni iniziali.
+    /// - `Err(ConnectionErrors::Init)`: Se `min_idle` supera `max_connections` o se l'apertura di una
+    ///   connessione iniziale fallisce, con l'errore del database nel messaggio.
+    pub async fn new(manager: ConnectionManager, min_idle: u32, max_connections: u32, acquire_timeout: Duration) -> Result<Self, ConnectionErrors> {
+        if max_connections == 0 || min_idle > max_connections {
+            return Err(ConnectionErrors::Init(format!(
+                "Parametri del pool non validi: min_idle {}, max_connections {}", min_idle, max_connections
+            )));
+        }
+
+        let mut connections = VecDeque::with_capacity(max_connections as usize);
+        for _ in 0..min_idle {
+            let conn = open_connection(&manager).await.map_err(|e| e.with_context("ConnectionPool::new"))?;
+            connections.push_back(conn);
+        }
+        info!("Pool di connessioni creato con {} connessioni inattive (massimo {})", min_idle, max_connections);
+
+        Ok(Self {
+            manager,
+            min_idle,
+            max_connections,
+            acquire_timeout,
+            connections: Arc::new(Mutex::new(connections)),
+            slots: Arc::new(Semaphore::new(max_connections as usize)),
+        })
+    }
+
+    /// Preleva una connessione dal pool, aprendone una nuova se non ve ne sono di inattive.
+    /// Le connessioni inattive che non superano `ConnectionManager::health_check` vengono scartate
+    /// e sostituite fino a tornare a `min_idle` connessioni inattive.
+    ///
+    /// Se `max_connections` connessioni sono già in uso, attende che una venga restituita
+    /// per al massimo `acquire_timeout`.
+    ///
+    /// # Ritorna
+    /// - `Ok(PooledConnection)`: La connessione, restituita al pool al suo rilascio.
+    /// - `Err(ConnectionErrors::Timeout)`: Se nessuna connessione si libera entro il timeout.
+    /// - `Err(ConnectionErrors::Init)`: Se l'apertura di una nuova connessione fallisce.
+    pub async fn acquire(&self) -> Result<PooledConnection, ConnectionErrors> {
+        let permit = match timeout(self.acquire_timeout, Arc::clone(&self.slots).acquire_owned()).await {
+            Ok(permit) => permit.map_err(|e| ConnectionErrors::UnknownError(e.to_string()))?,
+            Err(_) => {
+                warn!("Nessuna connessione disponibile entro {:?}", self.acquire_timeout);
+                return Err(ConnectionErrors::Timeout);
+            }
+        };
+
+        let mut idle = None;
+        let mut discarded = 0;
+        loop {
+            let next = self.connections.lock().map_err(|e| ConnectionErrors::UnknownError(e.to_string()))?.pop_front();
+            let Some(mut conn) = next else { break };
+            match self.manager.health_check(&mut conn).await {
+                Ok(true) => {
+                    idle = Some(conn);
+                    break;
+                }
+                Ok(false) | Err(_) => {
+                    warn!("Connessione inattiva non valida scartata dal pool");
+                    discarded += 1;
+                }
+            }
+        }
+        let conn = match idle {
+            Some(conn) => conn,
+            None => {
+                info!("Nessuna connessione inattiva, apertura di una nuova connessione...");
+                open_connection(&self.manager).await.map_err(|e| e.with_context("ConnectionPool::acquire"))?
+            }
+        };
+        if discarded > 0 {
+            self.replenish().await;
+        }
+
+        Ok(PooledConnection { conn: Some(conn), connections: Arc::clone(&self.connections), _permit: permit })
+    }
+
+    /// Riapre connessioni inattive fino a `min_idle`, senza superare `max_connections` connessioni aperte.
+    /// Un fallimento viene solo registrato: la connessione prestata da `acquire` resta valida.
+    async fn replenish(&self) {
+        loop {
+            let idle = self.idle_connections();
+            if idle >= self.min_idle as usize || idle + self.active_connections() >= self.max_connections as usize {
+                break;
+            }
+            match open_connection(&self.manager).await {
+                Ok(conn) => match self.connections.lock() {
+                    Ok(mut connections) => connections.push_back(conn),
+                    Err(e) => {
+                        warn!("Connessione scartata, pool non accessibile: {}", e);
+                        break;
+                    }
+                },
+                Err(e) => {
+                    warn!("Ripristino delle connessioni inattive fallito: {}", e);
+                    break;
+                }
+            }
+        }
+    }
+
+    /// Restituisce esplicitamente una connessione al pool, equivalente al suo rilascio.
+    pub fn release(&self, conn: PooledConnection) {
+        drop(conn);
+    }
+
+    /// Numero di connessioni inattive nel pool.
+    pub fn idle_connections(&self) -> usize {
+        self.connections.lock().map_or(0, |connections| connections.len())
+    }
+
+    /// Numero di connessioni attualmente prestate.
+    pub fn active_connections(&self) -> usize {
+        self.max_connections as usize - self.slots.available_permits()
+    }
+
+    /// Numero minimo di connessioni aperte alla creazione del pool.
+    pub fn min_idle(&self) -> u32 {
+        self.min_idle
+    }
+
+    /// Numero massimo di connessioni prestate contemporaneamente.
+    pub fn max_connections(&self) -> u32 {
+        self.max_connections
+    }
+}
+
+/// Apre una nuova connessione del pool con `initialize_connection`.
+///
+/// # Ritorna
+/// La connessione, oppure `ConnectionErrors::Init` con l'errore del database nel messaggio.
+async fn open_connection(manager: &ConnectionManager) -> Result<DbConnection, ConnectionErrors> {
+    manager.initialize_connection().await.map_err(|e| match e {
+        ConnectionErrors::Init(message) => ConnectionErrors::Init(message),
+        e => ConnectionErrors::Init(format!("Apertura di una connessione del pool fallita: {}", e)),
+    })
+}
+
+/// Connessione prestata dal `ConnectionPool`.
+///
+/// Si usa come una `DbConnection`; quando viene rilasciata la connessione torna nel pool
+/// e il permesso viene restituito, risvegliando un eventuale chiamante in attesa in `acquire`.
+pub struct PooledConnection {
+    conn: Option<DbConnection>,
+    connections: Arc<Mutex<VecDeque<DbConnection>>>,
+    _permit: OwnedSemaphorePermit,
+}
+
+impl Deref for PooledConnection {
+    type Target = DbConnection;
+
+    fn deref(&self) -> &DbConnection {
+        self.conn.as_ref().expect("Connessione già restituita al pool")
+    }
+}
+
+impl DerefMut for PooledConnection {
+    fn deref_mut(&mut self) -> &mut DbConnection {
+        self.conn.as_mut().expect("Connessione già restituita al pool")
+    }
+}
+
+impl Drop for PooledConnection {
+    fn drop(&mut self) {
+        if let Some(conn) = self.conn.take() {
+            match self.connections.lock() {
+                // Il permesso viene rilasciato al termine di `drop`, a connessione già reinserita
+                Ok(mut connections) => connections.push_back(conn),
+                Err(e) => warn!("Connessione scartata, pool non accessibile: {}", e),
+            }
+        }
+    }
+}
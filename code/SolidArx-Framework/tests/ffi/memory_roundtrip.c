@@ -0,0 +1,58 @@
+/*
+ * Verifica dell'interfaccia C del MemoryManager (vedi `tests/test_ffi.rs`).
+ * Compilato da `build.rs` con la feature `ffi`.
+ *
+ * Ritorna 0 in caso di successo, altrimenti il numero del controllo fallito.
+ */
+#include <stdint.h>
+#include <string.h>
+#include "arx_memory.h"
+
+int arx_ffi_roundtrip(void) {
+    if (arx_init_memory(1024, 8 * 1024) != 0) {
+        return 1;
+    }
+    uintptr_t free_before = arx_pool_free();
+    if (free_before != 8) {
+        return 2;
+    }
+
+    uint8_t *buffer = arx_allocate(256, ARX_STRATEGY_POOL);
+    if (buffer == NULL) {
+        return 3;
+    }
+    if (arx_pool_free() != free_before - 1) {
+        return 4;
+    }
+
+    /* Scrittura e rilettura tramite il puntatore grezzo */
+    for (int i = 0; i < 256; i++) {
+        buffer[i] = (uint8_t)i;
+    }
+    for (int i = 0; i < 256; i++) {
+        if (buffer[i] != (uint8_t)i) {
+            return 5;
+        }
+    }
+
+    arx_deallocate(buffer, 256);
+    if (arx_pool_free() != free_before) {
+        return 6;
+    }
+
+    /* Una richiesta più grande dei buffer del pool viene soddisfatta dinamicamente */
+    uint8_t *large = arx_allocate(4096, ARX_STRATEGY_DEFAULT);
+    if (large == NULL) {
+        return 7;
+    }
+    memset(large, 0xAB, 4096);
+    arx_deallocate(large, 4096);
+    if (arx_pool_free() != free_before) {
+        return 8;
+    }
+
+    if (arx_allocate(16, 42) != NULL) {
+        return 9;
+    }
+    return 0;
+}
@@ -0,0 +1 @@
+DROP TABLE migrated_items;
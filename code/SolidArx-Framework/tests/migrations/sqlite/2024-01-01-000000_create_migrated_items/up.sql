@@ -0,0 +1 @@
+CREATE TABLE migrated_items (id INTEGER NOT NULL PRIMARY KEY, name TEXT NOT NULL);
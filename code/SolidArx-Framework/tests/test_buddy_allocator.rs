@@ -0,0 +1,49 @@
+/// # Unit Tests per `core/buddy_allocator.rs`
+///
+/// Verificano la divisione e la fusione dei blocchi e le metriche di frammentazione.
+
+#[cfg(test)]
+mod tests {
+    use solid_arx_lib::core::buddy_allocator::BuddyAllocator;
+
+    /// Test per verificare che la fusione dei buddy ricostruisca il blocco iniziale
+    #[test]
+    fn test_split_and_merge() {
+        let mut buddy = BuddyAllocator::new(1024, 64).unwrap();
+        let small = buddy.allocate(60).unwrap();
+        let large = buddy.allocate(256).unwrap();
+        assert_eq!(buddy.block_size(small.order), 64);
+        assert_eq!(buddy.block_size(large.order), 256);
+        assert_eq!(buddy.total_free_bytes(), 1024 - 64 - 256);
+
+        buddy.deallocate(small).unwrap();
+        buddy.deallocate(large).unwrap();
+        assert_eq!(buddy.total_free_bytes(), 1024);
+        assert_eq!(buddy.largest_free_contiguous_bytes(), 1024);
+        assert!(buddy.allocate(1024).is_ok(), "Dopo la fusione l'intera area deve essere disponibile");
+    }
+
+    /// Test per verificare che la frammentazione cresca liberando blocchi non adiacenti
+    #[test]
+    fn test_fragmentation_ratio_increases() {
+        let mut buddy = BuddyAllocator::new(1024, 64).unwrap();
+        assert_eq!(buddy.fragmentation_ratio(), 0.0);
+
+        // Alterna blocchi da 64 e da 128 byte fino a riempire l'area
+        let mut blocks = Vec::new();
+        for size in [64, 64, 128, 64, 64, 128, 64, 64, 128, 64, 64, 128] {
+            blocks.push(buddy.allocate(size).unwrap());
+        }
+        assert_eq!(buddy.total_free_bytes(), 0);
+
+        // Libera un blocco ogni due: ogni rilascio aggiunge una nuova area isolata
+        let mut previous = buddy.fragmentation_ratio();
+        for block in blocks.iter().step_by(2) {
+            buddy.deallocate(*block).unwrap();
+            let ratio = buddy.fragmentation_ratio();
+            assert!(ratio >= previous, "La frammentazione non deve diminuire: {} < {}", ratio, previous);
+            previous = ratio;
+        }
+        assert!(previous > 0.5);
+    }
+}
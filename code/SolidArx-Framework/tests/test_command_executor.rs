@@ -0,0 +1,98 @@
+/// # Unit Tests per `crud/stores/command_executor.rs`
+///
+/// Verificano l'invio dei `Command` ai webhook esterni tramite un mock server HTTP.
+///
+/// Eseguire con:
+/// cargo test --features "crud","embedded","webhooks"
+
+#[cfg(all(test, feature = "crud", feature = "embedded", feature = "webhooks"))]
+mod tests {
+    use httpmock::prelude::*;
+    use solid_arx_lib::core::memory_management::enqueue_command;
+    use solid_arx_lib::crud::models::default::command::model::Command;
+    use solid_arx_lib::crud::stores::command_executor::{CommandExecutor, CommandError};
+
+    /// Inserisce in memoria un comando che punta al webhook indicato
+    fn insert_command(id: u32, url: String, max_webhook_retries: u32) {
+        let mut command = Command::new(id, 1, "reboot".to_string(), "2024-01-01".to_string(), vec![0u8; 8].into_boxed_slice());
+        command.webhook_url = Some(url);
+        command.max_webhook_retries = max_webhook_retries;
+        enqueue_command(command).unwrap();
+    }
+
+    /// Test per verificare che il webhook venga chiamato con il payload del comando
+    #[tokio::test]
+    async fn test_webhook_is_called() {
+        let server = MockServer::start_async().await;
+        let mock = server.mock_async(|when, then| {
+            when.method(POST).path("/hook").json_body_partial(r#"{"id": 101}"#);
+            then.status(200);
+        }).await;
+
+        insert_command(101, server.url("/hook"), 0);
+        let result = CommandExecutor::new().execute(101).await;
+
+        assert!(result.is_ok(), "Il comando dovrebbe essere eseguito correttamente");
+        mock.assert_hits_async(1).await;
+    }
+
+    /// Test per verificare i tentativi sul 500 e il fallimento dopo `max_webhook_retries`
+    #[tokio::test]
+    async fn test_webhook_retried_then_fails() {
+        let server = MockServer::start_async().await;
+        let mock = server.mock_async(|when, then| {
+            when.method(POST).path("/hook");
+            then.status(500).body("errore");
+        }).await;
+
+        insert_command(102, server.url("/hook"), 2);
+        let started = std::time::Instant::now();
+        let result = CommandExecutor::new().execute(102).await;
+        // Attese di 100 ms e 200 ms prima dei due tentativi
+        assert!(started.elapsed() >= std::time::Duration::from_millis(300));
+
+        match result {
+            Err(CommandError::WebhookFailed { status, body }) => {
+                assert_eq!(status, 500);
+                assert_eq!(body, "errore");
+            }
+            other => panic!("Atteso WebhookFailed, ottenuto {:?}", other),
+        }
+        // Un primo invio più due tentativi
+        mock.assert_hits_async(3).await;
+    }
+
+    /// Test per verificare che un webhook irraggiungibile restituisca `WebhookTransport` dopo i tentativi
+    #[tokio::test]
+    async fn test_webhook_transport_error() {
+        // Nessun servizio in ascolto sulla porta 1
+        insert_command(103, "http://127.0.0.1:1/hook".to_string(), 1);
+        let result = CommandExecutor::new().execute(103).await;
+
+        assert!(matches!(result, Err(CommandError::WebhookTransport(_))), "Ottenuto {:?}", result);
+    }
+}
+
+#[cfg(all(test, feature = "crud", feature = "embedded"))]
+mod output_tests {
+    use solid_arx_lib::core::memory_management::enqueue_command;
+    use solid_arx_lib::crud::models::default::command::model::Command;
+    use solid_arx_lib::crud::stores::command_executor::{CommandExecutor, COMMAND_OUTPUTS};
+
+    /// Test per verificare la cattura di stdout e codice di uscita di un sotto-processo
+    #[test]
+    fn test_execute_capturing_output() {
+        let command = Command::new(110, 1, "echo".to_string(), "2024-01-01".to_string(), vec![0u8; 8].into_boxed_slice());
+        enqueue_command(command).unwrap();
+
+        let mut executor = CommandExecutor::new();
+        executor.register_output_handler("echo", Box::new(|_| {
+            std::process::Command::new("echo").arg("hello").output().map_err(|e| e.to_string())
+        }));
+
+        let output = executor.execute_capturing_output(110, "operator").unwrap();
+        assert_eq!(output.stdout, "hello\n");
+        assert_eq!(output.exit_code, 0);
+        assert_eq!(COMMAND_OUTPUTS.lock().unwrap().get(&110), Some(&output));
+    }
+}
@@ -47,6 +47,7 @@ mod tests {
     use arx_framework::core::memory_management::define_pool_size;
     use arx_framework::core::memory_management::define_buffer_size;
     use arx_framework::core::memory_management::define_multiplier;
+    use arx_framework::core::memory_management::ConfigResolutionMode;
 
     /// Test per verificare che il `CoreSystem` venga inizializzato correttamente per una WebApp
     #[test]
@@ -63,8 +64,8 @@ mod tests {
         assert_eq!(memory_config.pool_size, 10, "Default pool size should be 10");
 
         memory_config = MemoryConfig::new(
-            define_pool_size(core_config.app_type.clone(),10), 
-            define_buffer_size(core_config.app_type.clone(),1024), 
+            define_pool_size(core_config.app_type.clone(),10, ConfigResolutionMode::Clamp), 
+            define_buffer_size(core_config.app_type.clone(),1024, ConfigResolutionMode::Clamp), 
             define_multiplier(core_config.app_type.clone(),0) // 0 Valore di default
         ); // Assumo che ci sia una funzione di istanza in memory_management.rs
 
@@ -89,8 +90,8 @@ mod tests {
             max_threads: 8,
         };
         let memory_config = MemoryConfig::new(
-            define_pool_size(core_config.app_type.clone(),0), // limite  valore massimo
-            define_buffer_size(core_config.app_type.clone(),0), // Valore di default
+            define_pool_size(core_config.app_type.clone(),0, ConfigResolutionMode::Clamp), // limite  valore massimo
+            define_buffer_size(core_config.app_type.clone(),0, ConfigResolutionMode::Clamp), // Valore di default
             define_multiplier(core_config.app_type.clone(),u8::MAX) // limite valore massimo
         ); 
 
@@ -108,4 +109,54 @@ mod tests {
 
         assert!(result.is_err(), "Module initialization should fail");
     }
+
+    /// Test per verificare che in modalità `Clamp` i valori eccessivi vengano ridotti senza richiedere input
+    #[test]
+    fn test_define_sizes_clamp_mode() {
+        let app_type = ApplicationType::WebApp;
+        assert_eq!(define_buffer_size(app_type.clone(), usize::MAX, ConfigResolutionMode::Clamp), usize::MAX / 2);
+        assert_eq!(define_pool_size(app_type.clone(), usize::MAX, ConfigResolutionMode::Clamp), usize::MAX / 2);
+        assert_eq!(define_pool_size(app_type.clone(), 4096, ConfigResolutionMode::Clamp), 4096);
+        assert_eq!(define_multiplier(app_type, u8::MAX), u8::MAX);
+    }
+
+    /// Test per verificare i valori predefiniti di `WasmBased` e il rifiuto di `CustomEmbedded` in WebAssembly
+    #[cfg(target_arch = "wasm32")]
+    #[test]
+    fn test_wasm_based_defaults() {
+        use arx_framework::core::memory_management::{AllocationStrategy, MemoryManager};
+        use arx_framework::core::system_core::CoreError;
+
+        let app_type = ApplicationType::WasmBased;
+        let buffer_size = define_buffer_size(app_type.clone(), 0, ConfigResolutionMode::Clamp);
+        let pool_size = define_pool_size(app_type.clone(), 0, ConfigResolutionMode::Clamp);
+        assert_eq!(buffer_size, 64 * 1024);
+        assert_eq!(pool_size, 512 * 1024);
+
+        let mut manager = MemoryManager::new(app_type, MemoryConfig::new(pool_size, buffer_size, 1)).unwrap();
+        assert_eq!(manager.allocate(None, 128).unwrap().len(), 128);
+        assert!(matches!(
+            manager.allocate(Some(AllocationStrategy::CustomEmbedded), 128),
+            Err(CoreError::WasmUnsupported(_, _))
+        ));
+    }
+
+    /// Test per verificare il confronto tra `CoreError` e la conversione in `Box<dyn Error>`
+    #[test]
+    fn test_core_error_equality_and_boxing() {
+        use arx_framework::core::system_core::CoreError;
+
+        let error = CoreError::PoolExhausted("Pool esaurito".to_string(), Vec::new()).with_context("allocate");
+        assert_eq!(error, CoreError::PoolExhausted("Pool esaurito".to_string(), vec!["allocate".to_string()]));
+        assert_ne!(error, CoreError::PoolExhausted("Pool esaurito".to_string(), Vec::new()));
+        assert_eq!(error.to_string(), "[allocate]: PoolExhausted: Pool esaurito");
+
+        fn fails() -> Result<(), Box<dyn std::error::Error>> {
+            Err(CoreError::ConfigurationError("buffer_size non valido".to_string(), Vec::new()))?;
+            Ok(())
+        }
+        let boxed = fails().unwrap_err();
+        assert_eq!(boxed.to_string(), "ConfigurationError: buffer_size non valido");
+        assert!(boxed.downcast_ref::<CoreError>().is_some());
+    }
 }
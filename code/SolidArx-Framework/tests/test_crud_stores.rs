@@ -0,0 +1,742 @@
+/// # Unit Tests per gli store in `crud/stores`
+///
+/// Gli store operano sulle strutture globali di `memory_management.rs`, per questo ogni
+/// test utilizza un intervallo di ID dedicato così da non interferire con gli altri.
+///
+/// Eseguire con la feature `crud` e quella del tipo di applicazione, ad esempio:
+/// cargo test --features "crud","automation"
+
+#[cfg(all(test, feature = "crud", any(feature = "automation", feature = "desktop", feature = "embedded")))]
+mod task_tests {
+    use std::time::Duration;
+    use solid_arx_lib::core::memory_management::TASKS_IN_MEMORY;
+    use solid_arx_lib::crud::models::default::task::model::Task;
+    use solid_arx_lib::crud::stores::task_store::TaskStore;
+
+    /// Crea un task e lo inserisce in `TASKS_IN_MEMORY`
+    fn insert_task(id: u32) {
+        let task = Task::new(
+            id,
+            format!("Task {}", id),
+            #[cfg(feature = "automation")] None,
+            #[cfg(feature = "desktop")] None,
+            #[cfg(feature = "embedded")] None,
+            vec![0u8; 8].into_boxed_slice(),
+        );
+        TASKS_IN_MEMORY.lock().unwrap().insert(id, task);
+    }
+
+    /// Test per verificare che vengano archiviati solo i task completati da più tempo della soglia
+    #[test]
+    fn test_archive_completed() {
+        let store = TaskStore::new();
+        for id in 1000..1010 {
+            insert_task(id);
+            store.complete(id).unwrap();
+        }
+
+        // Simula il passare del tempo per i primi sei task
+        {
+            let mut tasks = TASKS_IN_MEMORY.lock().unwrap();
+            for id in 1000..1006 {
+                let task = tasks.get_mut(&id).unwrap();
+                task.completed_at = task.completed_at.map(|at| at - 60_000);
+            }
+        }
+
+        store.archive_completed(Duration::from_secs(30)).unwrap();
+
+        let mut archived: Vec<u32> = store.archived_tasks().iter().map(|t| t.id).filter(|id| (1000..1010).contains(id)).collect();
+        archived.sort();
+        assert_eq!(archived, (1000..1006).collect::<Vec<u32>>());
+        assert!(TASKS_IN_MEMORY.lock().unwrap().contains_key(&1007));
+
+        store.restore_archived(1000).unwrap();
+        assert!(TASKS_IN_MEMORY.lock().unwrap().contains_key(&1000));
+    }
+
+    /// Test per verificare che `wait_for_all` termini quando tutti i task sono conclusi
+    #[tokio::test(start_paused = true)]
+    async fn test_wait_for_all_completes() {
+        for id in 1100..1103 {
+            insert_task(id);
+        }
+
+        let worker = tokio::spawn(async {
+            let store = TaskStore::new();
+            tokio::time::sleep(Duration::from_secs(1)).await;
+            store.complete(1100).unwrap();
+            store.fail(1101).unwrap();
+            tokio::time::sleep(Duration::from_secs(1)).await;
+            store.complete(1102).unwrap();
+        });
+
+        let waiter = tokio::spawn(async {
+            TaskStore::new().wait_for_all(&[1100, 1101, 1102], Duration::from_secs(5)).await
+        });
+
+        tokio::time::advance(Duration::from_secs(3)).await;
+        worker.await.unwrap();
+        assert!(waiter.await.unwrap().is_ok());
+    }
+
+    /// Test per verificare che `wait_for_all` restituisca `Timeout` se un task non viene concluso
+    #[tokio::test(start_paused = true)]
+    async fn test_wait_for_all_timeout() {
+        use solid_arx_lib::crud::crud_ops::CrudError;
+
+        insert_task(1110);
+        insert_task(1111);
+        TaskStore::new().complete(1110).unwrap();
+
+        let waiter = tokio::spawn(async {
+            TaskStore::new().wait_for_all(&[1110, 1111], Duration::from_secs(2)).await
+        });
+
+        tokio::time::advance(Duration::from_secs(3)).await;
+        assert!(matches!(waiter.await.unwrap(), Err(CrudError::Timeout)));
+    }
+
+    /// Test per verificare la backpressure di `submit` e l'attesa di `submit_async`
+    #[tokio::test]
+    async fn test_submit_backpressure() {
+        use solid_arx_lib::crud::crud_ops::CrudError;
+        use solid_arx_lib::crud::stores::task_store::TaskStoreConfig;
+
+        let new_task = |id: u32| Task::new(
+            id,
+            format!("Task {}", id),
+            #[cfg(feature = "automation")] None,
+            #[cfg(feature = "desktop")] None,
+            #[cfg(feature = "embedded")] None,
+            vec![0u8; 8].into_boxed_slice(),
+        );
+
+        let store = TaskStore::with_config(TaskStoreConfig { max_pending: 2 });
+        assert_eq!(store.submit(new_task(1200)).unwrap(), 1200);
+        assert_eq!(store.submit(new_task(1201)).unwrap(), 1201);
+        assert!(matches!(store.submit(new_task(1202)), Err(CrudError::QueueFull)));
+
+        let waiting_store = store.clone();
+        let waiter = tokio::spawn(async move { waiting_store.submit_async(new_task(1202)).await });
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        assert!(!waiter.is_finished(), "La sottomissione deve attendere un posto libero");
+
+        store.complete(1200).unwrap();
+        assert_eq!(waiter.await.unwrap().unwrap(), 1202);
+        assert!(TASKS_IN_MEMORY.lock().unwrap().contains_key(&1202));
+
+        // Le conclusioni senza nessuno in attesa non lasciano permessi residui
+        store.complete(1200).unwrap();
+        store.complete(1200).unwrap();
+        let waiting_store = store.clone();
+        let waiter = tokio::spawn(async move { waiting_store.submit_async(new_task(1203)).await });
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        assert!(!waiter.is_finished(), "La coda è ancora piena con 1201 e 1202 in attesa");
+
+        // Anche il passaggio da `Pending` a `Running` libera un posto
+        store.start(1201).unwrap();
+        assert_eq!(waiter.await.unwrap().unwrap(), 1203);
+    }
+
+    /// Test per verificare la risoluzione di un'inversione di priorità e il ripristino al completamento
+    #[test]
+    fn test_priority_inheritance() {
+        use solid_arx_lib::crud::stores::task_store::TaskPriority;
+
+        // 1300 (High) dipende da 1301 (Low), che a sua volta dipende da 1302 (Normal)
+        for id in 1300..1304 {
+            insert_task(id);
+        }
+        let store = TaskStore::new();
+        store.set_schedule(1300, TaskPriority::High, vec![1301]).unwrap();
+        store.set_schedule(1301, TaskPriority::Low, vec![1302]).unwrap();
+
+        assert_eq!(store.apply_priority_inheritance().unwrap(), 2);
+        assert_eq!(store.schedule(1301).unwrap().priority, TaskPriority::High);
+        assert_eq!(store.schedule(1301).unwrap().boosted_from, Some(TaskPriority::Low.level()));
+        assert_eq!(store.schedule(1302).unwrap().priority, TaskPriority::High);
+        assert!(store.schedule(1303).is_none(), "Un task indipendente non va elevato");
+
+        // Una seconda applicazione non eleva nuovamente gli stessi task
+        assert_eq!(store.apply_priority_inheritance().unwrap(), 0);
+
+        store.complete(1300).unwrap();
+        assert_eq!(store.schedule(1301).unwrap().priority, TaskPriority::Low);
+        assert_eq!(store.schedule(1301).unwrap().boosted_from, None);
+        assert_eq!(store.schedule(1302).unwrap().priority, TaskPriority::Normal);
+    }
+
+    /// Test per verificare l'invecchiamento della priorità dei task in attesa
+    #[tokio::test(start_paused = true)]
+    async fn test_age_priorities() {
+        use solid_arx_lib::crud::stores::task_store::TaskPriority;
+
+        let store = TaskStore::new();
+        insert_task(1400);
+        store.set_schedule(1400, TaskPriority::Low, Vec::new()).unwrap();
+
+        tokio::time::advance(Duration::from_secs(5)).await;
+        assert!(store.age_priorities(10.0).unwrap() >= 1);
+        assert_eq!(store.schedule(1400).unwrap().effective_priority, 50);
+
+        // Il valore massimo è `u8::MAX`
+        store.age_priorities(1000.0).unwrap();
+        assert_eq!(store.schedule(1400).unwrap().effective_priority, u8::MAX);
+
+        assert!(store.age_priorities(-1.0).is_err());
+    }
+
+    /// Test per verificare che lo stato dello scheduler impostato prima di `submit` sopravviva
+    /// alla manutenzione e venga eliminato solo con l'archiviazione del task
+    #[test]
+    fn test_schedule_before_submit() {
+        use solid_arx_lib::crud::stores::task_store::TaskPriority;
+
+        let store = TaskStore::new();
+        store.set_schedule(1450, TaskPriority::High, Vec::new()).unwrap();
+        store.apply_priority_inheritance().unwrap();
+        store.age_priorities(1.0).unwrap();
+        assert_eq!(store.schedule(1450).unwrap().priority, TaskPriority::High);
+
+        insert_task(1450);
+        store.complete(1450).unwrap();
+        {
+            let mut tasks = TASKS_IN_MEMORY.lock().unwrap();
+            let task = tasks.get_mut(&1450).unwrap();
+            task.completed_at = task.completed_at.map(|at| at - 60_000);
+        }
+        store.archive_completed(Duration::from_secs(30)).unwrap();
+        assert!(store.schedule(1450).is_none());
+    }
+
+    /// Test per verificare che lo stream di `run_streaming` consegni in ordine i blocchi inviati dall'handler
+    #[tokio::test]
+    async fn test_run_streaming() {
+        use tokio_stream::StreamExt;
+        use solid_arx_lib::crud::models::default::task::model::TaskStatus;
+        use solid_arx_lib::crud::stores::task_executor::TaskExecutor;
+
+        insert_task(1500);
+        let mut executor = TaskExecutor::new();
+        executor.register_handler(1500, |_task, output| async move {
+            for chunk in ["compilazione", "collegamento", "completato"] {
+                output.send(chunk.as_bytes().to_vec()).await.map_err(|e| e.to_string())?;
+            }
+            Ok::<(), String>(())
+        });
+
+        let chunks: Vec<Vec<u8>> = executor.run_streaming(1500).unwrap().map(|chunk| chunk.unwrap()).collect().await;
+        assert_eq!(chunks, vec![b"compilazione".to_vec(), b"collegamento".to_vec(), b"completato".to_vec()]);
+        assert_eq!(TASKS_IN_MEMORY.lock().unwrap()[&1500].status, TaskStatus::Completed);
+
+        // Senza handler registrato il task non viene eseguito
+        insert_task(1501);
+        assert!(executor.run_streaming(1501).is_err());
+    }
+
+    /// Test per verificare le politiche di conflitto di `insert_tasks_bulk` e la lettura con `get_tasks_bulk`
+    #[test]
+    fn test_insert_tasks_bulk() {
+        use solid_arx_lib::core::memory_management::{get_tasks_bulk, insert_tasks_bulk, ConflictResolution};
+        use solid_arx_lib::core::system_core::CoreError;
+
+        let new_task = |id: u32, description: &str| Task::new(
+            id,
+            description.to_string(),
+            #[cfg(feature = "automation")] None,
+            #[cfg(feature = "desktop")] None,
+            #[cfg(feature = "embedded")] None,
+            vec![0u8; 8].into_boxed_slice(),
+        );
+
+        insert_task(9100);
+        let inserted = insert_tasks_bulk(vec![new_task(9100, "Nuovo"), new_task(9101, "Nuovo"), new_task(9101, "Doppio")], ConflictResolution::Skip).unwrap();
+        assert_eq!(inserted, 1);
+        let tasks = get_tasks_bulk(&[9100, 9101, 9199]);
+        assert_eq!(tasks.len(), 2);
+        assert_eq!(tasks[&9100].description, "Task 9100");
+        assert_eq!(tasks[&9101].description, "Nuovo");
+
+        let inserted = insert_tasks_bulk(vec![new_task(9100, "Sovrascritto"), new_task(9102, "Nuovo")], ConflictResolution::Overwrite).unwrap();
+        assert_eq!(inserted, 2);
+        assert_eq!(get_tasks_bulk(&[9100])[&9100].description, "Sovrascritto");
+
+        // Con `Error` nessun task del lotto viene inserito
+        let result = insert_tasks_bulk(vec![new_task(9103, "Nuovo"), new_task(9102, "Conflitto")], ConflictResolution::Error);
+        assert!(matches!(result, Err(CoreError::InvalidOperation(_, _))));
+        assert!(get_tasks_bulk(&[9103]).is_empty());
+    }
+}
+
+#[cfg(all(test, feature = "crud", feature = "embedded"))]
+mod device_tests {
+    use solid_arx_lib::core::memory_management::DEVICES_IN_MEMORY;
+    use solid_arx_lib::crud::models::default::{command::model::Command, device::model::Device};
+    use solid_arx_lib::crud::stores::device_store::DeviceStore;
+
+    /// Crea un dispositivo e lo inserisce in `DEVICES_IN_MEMORY`
+    fn insert_device(id: u32) {
+        let device = Device::new(id, format!("Device {}", id), vec![0u8; 8].into_boxed_slice());
+        DEVICES_IN_MEMORY.lock().unwrap().insert(id, device);
+    }
+
+    /// Crea un comando indirizzato al dispositivo specificato
+    fn command(id: u32, device_id: u32) -> Command {
+        Command::new(id, device_id, "ping".to_string(), "2024-01-01".to_string(), vec![0u8; 8].into_boxed_slice())
+    }
+
+    /// Test per verificare che `upsert_devices` distingua dispositivi inseriti, aggiornati e invariati
+    #[test]
+    fn test_upsert_devices() {
+        use solid_arx_lib::core::memory_management::{upsert_devices, UpsertReport};
+
+        let device = |id: u32, name: &str| Device::new(id, name.to_string(), vec![0u8; 8].into_boxed_slice());
+        let report = upsert_devices(vec![device(2300, "Sensore"), device(2301, "Attuatore")]).unwrap();
+        assert_eq!(report, UpsertReport { inserted: 2, updated: 0, unchanged: 0 });
+
+        let report = upsert_devices(vec![device(2300, "Sensore"), device(2301, "Attuatore v2"), device(2302, "Gateway")]).unwrap();
+        assert_eq!(report, UpsertReport { inserted: 1, updated: 1, unchanged: 1 });
+        assert_eq!(DEVICES_IN_MEMORY.lock().unwrap()[&2301].name, "Attuatore v2");
+    }
+
+    /// Test per verificare l'ordine FIFO della coda dei comandi di un dispositivo
+    #[test]
+    fn test_device_command_queue() {
+        let store = DeviceStore::new();
+        insert_device(2000);
+
+        for id in 1..=3 {
+            store.enqueue_command(2000, command(id, 2000)).unwrap();
+        }
+        assert_eq!(store.pending_command_count(2000), 3);
+
+        let dequeued: Vec<u32> = (0..3).map(|_| store.dequeue_command(2000).unwrap().unwrap().id).collect();
+        assert_eq!(dequeued, vec![1, 2, 3]);
+        assert_eq!(store.pending_command_count(2000), 0);
+        assert!(store.dequeue_command(2000).unwrap().is_none());
+
+        // Un dispositivo inesistente non accetta comandi
+        assert!(store.enqueue_command(2999, command(4, 2999)).is_err());
+    }
+
+    /// Test per verificare che l'aggiornamento applicato modifichi la versione del firmware
+    #[test]
+    fn test_firmware_update_workflow() {
+        use semver::Version;
+        use solid_arx_lib::crud::stores::device_store::{FirmwareDownloader, UpdateStatus};
+
+        struct MockDownloader;
+        impl FirmwareDownloader for MockDownloader {
+            fn download(&self, _url: &str) -> Result<Vec<u8>, String> {
+                Ok(vec![0xAB; 16])
+            }
+        }
+
+        let store = DeviceStore::new();
+        insert_device(2100);
+
+        let update_id = store.schedule_update(2100, "https://firmware.example.com/v1.2.0.bin", Version::new(1, 2, 0)).unwrap();
+        assert_eq!(store.firmware_update(update_id).unwrap().status, UpdateStatus::Pending);
+
+        store.apply_update(update_id, &MockDownloader).unwrap();
+
+        let update = store.firmware_update(update_id).unwrap();
+        assert_eq!(update.status, UpdateStatus::Completed);
+        assert!(update.completed_at.is_some());
+        assert_eq!(DEVICES_IN_MEMORY.lock().unwrap().get(&2100).unwrap().firmware_version, "1.2.0");
+
+        // Un aggiornamento già applicato non può essere ripetuto
+        assert!(store.apply_update(update_id, &MockDownloader).is_err());
+    }
+
+    /// Test per verificare l'acquisizione della telemetria CSV e lo scarto delle righe malformate
+    #[test]
+    fn test_ingest_csv_telemetry() {
+        use solid_arx_lib::core::memory_management::SENSOR_DATA_IN_MEMORY;
+        use solid_arx_lib::crud::models::default::sensor_data::model::SensorData;
+
+        let store = DeviceStore::new();
+        insert_device(2200);
+        // Lettura con ID elevato: gli ID assegnati non si sovrappongono a quelli degli altri test
+        let sentinel = SensorData::new(900_000, 2201, "2024-01-01T00:00:00".to_string(), "0".to_string(), vec![0u8; 8].into_boxed_slice());
+        SENSOR_DATA_IN_MEMORY.lock().unwrap().insert(900_000, sentinel);
+
+        let mut csv = String::from("timestamp_epoch,value,unit\n");
+        for i in 0..1000 {
+            csv.push_str(&format!("{},{}.5,C\n", 1_700_000_000 + i, i % 40));
+            if i % 250 == 0 {
+                // Righe malformate: timestamp, valore e unità non validi, colonne mancanti
+                csv.push_str("not-a-time,1.0,C\n");
+                csv.push_str("1700000000,abc,C\n");
+                csv.push_str("1700000000,1.0,\n");
+                csv.push_str("1700000000,1.0\n");
+            }
+        }
+
+        let ingested = store.ingest_csv_telemetry(2200, csv.as_bytes()).unwrap();
+        assert_eq!(ingested, 1000);
+
+        let readings = SENSOR_DATA_IN_MEMORY.lock().unwrap();
+        let stored: Vec<&SensorData> = readings.values().filter(|d| d.device_id == 2200).collect();
+        assert_eq!(stored.len(), 1000);
+        let first = stored.iter().find(|d| d.timestamp == "2023-11-14T22:13:20").unwrap();
+        assert_eq!(first.value, 0.5);
+        assert_eq!(first.data, "0.5 C");
+        drop(readings);
+
+        // Un dispositivo inesistente non accetta telemetria
+        assert!(store.ingest_csv_telemetry(2999, csv.as_bytes()).is_err());
+    }
+}
+
+#[cfg(all(test, feature = "crud", feature = "automation"))]
+mod macro_tests {
+    use solid_arx_lib::core::memory_management::MACROS_IN_MEMORY;
+    use solid_arx_lib::crud::models::default::macro_script::model::{ExecutionFrequency, Macro, MacroStatus};
+    use solid_arx_lib::crud::stores::macro_store::MacroStore;
+
+    /// Test per verificare che `checkout` ripristini il corpo del primo commit
+    #[test]
+    fn test_macro_commit_and_checkout() {
+        let store = MacroStore::new();
+        let original = vec!["echo start".to_string(), "echo stop".to_string()];
+        let macro_script = Macro::new(3000, "deploy".to_string(), original.clone(), ExecutionFrequency::Once, MacroStatus::Active, vec![0u8; 8].into_boxed_slice());
+        MACROS_IN_MEMORY.lock().unwrap().insert(3000, macro_script);
+
+        let first = store.commit(3000, "versione iniziale").unwrap();
+
+        MACROS_IN_MEMORY.lock().unwrap().get_mut(&3000).unwrap().commands.push("echo cleanup".to_string());
+        let second = store.commit(3000, "aggiunto cleanup").unwrap();
+        assert_ne!(first.hash, second.hash);
+
+        store.checkout(3000, &first.hash).unwrap();
+        assert_eq!(MACROS_IN_MEMORY.lock().unwrap().get(&3000).unwrap().commands, original);
+        assert_eq!(store.history(3000).len(), 2);
+    }
+}
+
+#[cfg(all(test, feature = "crud", feature = "embedded"))]
+mod sensor_data_tests {
+    use std::sync::mpsc;
+    use solid_arx_lib::crud::models::default::sensor_data::model::SensorData;
+    use solid_arx_lib::crud::stores::sensor_data_store::SensorDataStore;
+
+    /// Crea una lettura del sensore con il valore indicato
+    fn reading(id: u32, sensor_id: u32, value: f64) -> SensorData {
+        SensorData::new(id, sensor_id, "2024-01-01T00:00:00".to_string(), value.to_string(), vec![0u8; 8].into_boxed_slice())
+    }
+
+    /// Test per verificare che arrivino sul canale solo le letture fuori soglia
+    #[test]
+    fn test_alert_on_threshold() {
+        let store = SensorDataStore::new();
+        let (tx, rx) = mpsc::channel();
+        let handle = store.alert_on_threshold(4000, 10.0, 20.0, tx);
+
+        store.insert(reading(4001, 4000, 15.0)).unwrap();
+        store.insert(reading(4002, 4000, 25.0)).unwrap();
+        store.insert(reading(4003, 4000, 5.0)).unwrap();
+        store.insert(reading(4004, 4999, 50.0)).unwrap();
+        // I dati non numerici non hanno valore e non fanno scattare l'allarme
+        let unparsable = SensorData::new(4006, 4000, "2024-01-01T00:00:00".to_string(), "n/d".to_string(), vec![0u8; 8].into_boxed_slice());
+        assert!(unparsable.value.is_nan());
+        store.insert(unparsable).unwrap();
+
+        let alerts: Vec<u32> = rx.try_iter().map(|d| d.id).collect();
+        assert_eq!(alerts, vec![4002, 4003]);
+
+        // Rilasciato l'handle l'allarme non è più attivo
+        drop(handle);
+        store.insert(reading(4005, 4000, 100.0)).unwrap();
+        assert!(rx.try_recv().is_err());
+    }
+
+    /// Test per verificare la media mobile sui valori di un sensore
+    #[test]
+    fn test_apply_moving_average() {
+        use solid_arx_lib::crud::crud_ops::CrudError;
+
+        let store = SensorDataStore::new();
+        let values = [10.0, 20.0, 30.0, 40.0, 50.0, 60.0];
+        for (i, value) in values.iter().enumerate() {
+            let mut data = reading(4100 + i as u32, 4100, *value);
+            data.timestamp = format!("2024-01-01T00:00:0{}", i);
+            store.insert(data).unwrap();
+        }
+
+        let window = 3;
+        let averaged: Vec<f64> = store
+            .apply_moving_average(window)
+            .unwrap()
+            .into_iter()
+            .filter(|d| d.sensor_id == 4100)
+            .map(|d| d.value)
+            .collect();
+
+        assert_eq!(averaged.len(), values.len());
+        assert_eq!(averaged[0], 10.0, "Il primo valore resta invariato");
+        assert_eq!(averaged[1], 15.0, "Il secondo valore è la media dei primi due");
+        for i in window..values.len() {
+            let expected = values[i + 1 - window..=i].iter().sum::<f64>() / window as f64;
+            assert_eq!(averaged[i], expected);
+        }
+
+        assert!(matches!(store.apply_moving_average(0), Err(CrudError::ValidationFailed(_))));
+    }
+
+    /// Test per verificare l'ordinamento e gli estremi di `query_sensor_data_range`
+    #[test]
+    fn test_query_sensor_data_range() {
+        use solid_arx_lib::core::memory_management::{insert_sensor_data_indexed, query_sensor_data_range, SENSOR_DATA_IN_MEMORY};
+
+        // Timestamp nel 2100 per non interferire con le letture degli altri test
+        let at = |id: u32, timestamp: &str| SensorData::new(id, 4100, timestamp.to_string(), "1".to_string(), vec![0u8; 8].into_boxed_slice());
+        insert_sensor_data_indexed(at(4103, "2100-01-01T00:00:10")).unwrap();
+        insert_sensor_data_indexed(at(4102, "2100-01-01T00:00:00")).unwrap();
+        insert_sensor_data_indexed(at(4101, "2100-01-01T00:00:00")).unwrap();
+        insert_sensor_data_indexed(at(4104, "2100-01-01T00:01:00")).unwrap();
+        assert!(insert_sensor_data_indexed(at(4105, "ieri")).is_err());
+
+        let start = 4_102_444_800; // 2100-01-01T00:00:00 UTC
+        let ids: Vec<u32> = query_sensor_data_range(start, start + 10).iter().map(|d| d.id).collect();
+        assert_eq!(ids, vec![4101, 4102, 4103]);
+
+        // La reinserzione sposta la lettura nell'indice
+        insert_sensor_data_indexed(at(4101, "2100-01-01T00:00:30")).unwrap();
+        let ids: Vec<u32> = query_sensor_data_range(start, start + 60).iter().map(|d| d.id).collect();
+        assert_eq!(ids, vec![4102, 4103, 4101, 4104]);
+        assert!(query_sensor_data_range(start + 10, start).is_empty());
+
+        // Anche le sostituzioni tramite `SensorDataStore` aggiornano l'indice
+        SensorDataStore::new().insert(at(4102, "2100-01-01T00:02:00")).unwrap();
+        let ids: Vec<u32> = query_sensor_data_range(start, start + 60).iter().map(|d| d.id).collect();
+        assert_eq!(ids, vec![4103, 4101, 4104]);
+
+        // Una lettura sostituita senza aggiornare l'indice non ricompare nell'intervallo precedente
+        SENSOR_DATA_IN_MEMORY.lock().unwrap().insert(4103, at(4103, "2100-01-01T00:05:00"));
+        let ids: Vec<u32> = query_sensor_data_range(start, start + 60).iter().map(|d| d.id).collect();
+        assert_eq!(ids, vec![4101, 4104]);
+    }
+}
+
+#[cfg(all(test, feature = "crud", feature = "automation"))]
+mod job_tests {
+    use std::thread;
+    use serde_json::json;
+    use solid_arx_lib::core::memory_management::JOBS_IN_MEMORY;
+    use solid_arx_lib::crud::crud_ops::CrudError;
+    use solid_arx_lib::crud::models::default::job::model::Job;
+    use solid_arx_lib::crud::stores::job_store::JobStore;
+
+    /// Crea un job e lo inserisce in `JOBS_IN_MEMORY`
+    fn insert_job(id: u32) {
+        let job = Job::new(id, format!("Job {}", id), "job parallelo".to_string(), vec![0u8; 8].into_boxed_slice());
+        JOBS_IN_MEMORY.lock().unwrap().insert(id, job);
+    }
+
+    /// Test per verificare che i risultati di job paralleli vengano aggregati per ID
+    #[test]
+    fn test_aggregate_outputs() {
+        let ids = [5000, 5001, 5002];
+        for id in ids {
+            insert_job(id);
+        }
+
+        let workers: Vec<_> = ids
+            .iter()
+            .map(|&id| thread::spawn(move || JobStore::new().complete(id, json!({ "rows": id - 5000 })).unwrap()))
+            .collect();
+        for worker in workers {
+            worker.join().unwrap();
+        }
+
+        let store = JobStore::new();
+        let aggregated = store.aggregate_outputs(&ids).unwrap();
+        assert_eq!(aggregated, json!({
+            "5000": { "rows": 0 },
+            "5001": { "rows": 1 },
+            "5002": { "rows": 2 },
+        }));
+
+        // Un job non completato non può essere aggregato
+        insert_job(5003);
+        assert!(matches!(store.aggregate_outputs(&[5000, 5003]), Err(CrudError::NotFound(5003))));
+    }
+
+    /// Test per verificare l'esportazione per il diagramma di Gantt di tre job sequenziali
+    #[test]
+    fn test_to_gantt_json() {
+        let ids = [5100, 5101, 5102];
+        {
+            let mut jobs = JOBS_IN_MEMORY.lock().unwrap();
+            for (step, id) in ids.iter().enumerate() {
+                let mut job = Job::new(*id, format!("Fase {}", step), "job sequenziale".to_string(), vec![0u8; 8].into_boxed_slice());
+                job.started_at = Some(1_000 * step as i64);
+                job.completed_at = Some(1_000 * step as i64 + 900);
+                job.dependencies = if step == 0 { Vec::new() } else { vec![id - 1] };
+                jobs.insert(*id, job);
+            }
+        }
+
+        let gantt = JobStore::new().to_gantt_json(&ids).unwrap();
+        assert_eq!(gantt, json!([
+            { "id": 5100, "label": "Fase 0", "start": 0, "end": 900, "dependencies": [] },
+            { "id": 5101, "label": "Fase 1", "start": 1000, "end": 1900, "dependencies": [5100] },
+            { "id": 5102, "label": "Fase 2", "start": 2000, "end": 2900, "dependencies": [5101] },
+        ]));
+
+        // Un job mai avviato non ha tempistiche da esportare
+        insert_job(5103);
+        assert!(matches!(JobStore::new().to_gantt_json(&[5103]), Err(CrudError::ValidationFailed(_))));
+    }
+
+    /// Test per verificare l'esclusività, il rilascio e la scadenza dei lock dei job
+    #[tokio::test(start_paused = true)]
+    async fn test_job_lock() {
+        use std::time::Duration;
+
+        let store = JobStore::new();
+        insert_job(5200);
+        insert_job(5201);
+
+        // Job diversi possono essere bloccati contemporaneamente
+        let first = store.acquire_lock(5200, Duration::from_secs(30), "worker-a").unwrap();
+        let other = store.acquire_lock(5201, Duration::from_secs(30), "worker-b").unwrap();
+        assert_eq!(first.holder, "worker-a");
+
+        // Lo stesso job non può essere bloccato da un secondo worker
+        assert!(matches!(store.acquire_lock(5200, Duration::from_secs(30), "worker-b"), Err(CrudError::Conflict(5200))));
+
+        // Al rilascio il lock torna disponibile
+        drop(other);
+        let relocked = store.acquire_lock(5201, Duration::from_secs(30), "worker-c").unwrap();
+        assert_eq!(relocked.holder, "worker-c");
+
+        // Scaduto il TTL il lock può essere acquisito da un altro worker
+        tokio::time::advance(Duration::from_secs(31)).await;
+        assert!(first.is_expired());
+        let taken_over = store.acquire_lock(5200, Duration::from_secs(30), "worker-b").unwrap();
+
+        // Il rilascio del lock scaduto non rimuove quello del nuovo detentore
+        drop(first);
+        assert!(matches!(store.acquire_lock(5200, Duration::from_secs(30), "worker-d"), Err(CrudError::Conflict(5200))));
+        drop(taken_over);
+        assert!(store.acquire_lock(5200, Duration::from_secs(30), "worker-d").is_ok());
+
+        assert!(matches!(store.acquire_lock(5299, Duration::from_secs(30), "worker-a"), Err(CrudError::NotFound(5299))));
+    }
+}
+
+#[cfg(all(test, feature = "crud", any(feature = "automation", feature = "embedded")))]
+mod configuration_tests {
+    use solid_arx_lib::core::memory_management::CONFIGURATIONS_IN_MEMORY;
+    use solid_arx_lib::crud::models::default::configuration::model::Configuration;
+    use solid_arx_lib::crud::stores::configuration_store::ConfigurationStore;
+
+    /// Test per verificare che l'esportazione `.env` preservi esattamente chiave e valore
+    #[test]
+    fn test_dotenv_round_trip() {
+        let store = ConfigurationStore::new();
+        let key = "log level=debug\nlegacy".to_string();
+        let value = "a b = \"c\"\n\tpath\\dir # non commento".to_string();
+        let configuration = Configuration::new(
+            6000,
+            #[cfg(feature = "embedded")] None,
+            key.clone(),
+            value.clone(),
+            vec![0u8; 8].into_boxed_slice(),
+        );
+        CONFIGURATIONS_IN_MEMORY.lock().unwrap().insert(6000, configuration);
+
+        let dotenv = store.to_dotenv(6000).unwrap();
+        assert!(dotenv.ends_with('\n'));
+        assert_eq!(dotenv.lines().count(), 1, "I newline devono essere rappresentati con escape");
+
+        let parsed = store.from_dotenv(&dotenv).unwrap();
+        assert_eq!(parsed.key, key);
+        assert_eq!(parsed.value, value);
+
+        // Le voci senza caratteri speciali non vengono racchiuse tra apici
+        CONFIGURATIONS_IN_MEMORY.lock().unwrap().insert(6001, Configuration::new(
+            6001,
+            #[cfg(feature = "embedded")] None,
+            "PORT".to_string(),
+            "8080".to_string(),
+            vec![0u8; 8].into_boxed_slice(),
+        ));
+        assert_eq!(store.to_dotenv(6001).unwrap(), "PORT=8080\n");
+        assert!(store.from_dotenv("A=1\nB=2\n").is_err());
+    }
+}
+
+#[cfg(all(test, feature = "crud", feature = "embedded"))]
+mod log_event_tests {
+    use solid_arx_lib::core::memory_management::{append_log, drain_logs, peek_logs, LogRingBuffer};
+    use solid_arx_lib::crud::models::default::log_event::model::LogEvent;
+
+    /// Crea un evento con la descrizione indicata
+    fn event(description: &str) -> LogEvent {
+        LogEvent::new(0, 1, "info".to_string(), "2024-01-01T00:00:00".to_string(), description.to_string(), vec![0u8; 8].into_boxed_slice())
+    }
+
+    /// Test per verificare la sovrascrittura degli eventi più vecchi e le chiavi monotone
+    #[test]
+    fn test_log_ring_buffer_overwrite() {
+        let mut ring = LogRingBuffer::new(3);
+        let keys: Vec<u32> = ["a", "b", "c", "d"].iter().map(|d| ring.push(event(d))).collect();
+        assert_eq!(keys, vec![0, 1, 2, 3]);
+        assert_eq!(ring.len(), 3);
+        assert!(ring.get(0).is_none(), "L'evento più vecchio viene sovrascritto");
+
+        let last: Vec<String> = ring.peek_last(2).into_iter().map(|e| e.description).collect();
+        assert_eq!(last, vec!["c", "d"]);
+        let drained: Vec<u32> = ring.drain().map(|e| e.id).collect();
+        assert_eq!(drained, vec![1, 2, 3]);
+        assert!(ring.is_empty());
+    }
+
+    /// Test per verificare `append_log`, `peek_logs` e `drain_logs` su `LOG_EVENTS_IN_MEMORY`
+    #[test]
+    fn test_append_peek_drain_logs() {
+        for description in ["avvio", "connesso", "arresto"] {
+            append_log(event(description)).unwrap();
+        }
+        let peeked: Vec<String> = peek_logs(2).into_iter().map(|e| e.description).collect();
+        assert_eq!(peeked, vec!["connesso", "arresto"]);
+
+        let drained = drain_logs();
+        assert_eq!(drained.len(), 3);
+        assert!(drained.windows(2).all(|pair| pair[0].id < pair[1].id));
+        assert!(peek_logs(10).is_empty());
+    }
+}
+
+#[cfg(all(test, feature = "crud", feature = "embedded"))]
+mod command_queue_tests {
+    use std::collections::HashMap;
+    use solid_arx_lib::core::memory_management::{dequeue_command, enqueue_command, migrate_commands};
+    use solid_arx_lib::crud::models::default::command::model::{Command, CommandPriority};
+
+    /// Crea un comando con la priorità indicata
+    fn command(id: u32, priority: CommandPriority) -> Command {
+        let mut command = Command::new(id, 1, "ping".to_string(), "2024-01-01".to_string(), vec![0u8; 8].into_boxed_slice());
+        command.priority = priority;
+        command
+    }
+
+    /// Test per verificare l'estrazione per priorità e, a parità, per ordine di inserimento
+    #[test]
+    fn test_command_priority_order() {
+        enqueue_command(command(7001, CommandPriority::Low)).unwrap();
+        enqueue_command(command(7002, CommandPriority::Normal)).unwrap();
+        enqueue_command(command(7003, CommandPriority::Critical)).unwrap();
+        enqueue_command(command(7004, CommandPriority::Normal)).unwrap();
+
+        let migrated: HashMap<u32, Command> = [(7006, command(7006, CommandPriority::High)), (7005, command(7005, CommandPriority::High))].into();
+        assert_eq!(migrate_commands(migrated).unwrap(), 2);
+
+        let order: Vec<u32> = std::iter::from_fn(dequeue_command).map(|c| c.id).collect();
+        assert_eq!(order, vec![7003, 7005, 7006, 7002, 7004, 7001]);
+    }
+}
@@ -0,0 +1,59 @@
+/// # Unit Tests per la strategia `CustomEmbedded` senza heap
+///
+/// Questo modulo verifica che `allocate` e `deallocate` con la strategia `CustomEmbedded`
+/// non utilizzino l'allocatore globale. Il test ha un proprio eseguibile perché installa
+/// un `#[global_allocator]` che conta le allocazioni del thread corrente.
+///
+/// cargo test --features "embedded" --test test_embedded_zero_heap
+
+#[cfg(all(feature = "embedded", not(feature = "tracking")))]
+mod tests {
+    use solid_arx_lib::config::{global_config::ApplicationType, memory_config::MemoryConfig};
+    use solid_arx_lib::core::memory_management::{AllocationStrategy, MemoryManager};
+    use std::alloc::{GlobalAlloc, Layout, System};
+    use std::cell::Cell;
+
+    /// Allocatore di sistema che conta le allocazioni eseguite dal thread corrente.
+    struct CountingAllocator;
+
+    thread_local! {
+        static HEAP_ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+    }
+
+    unsafe impl GlobalAlloc for CountingAllocator {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            let _ = HEAP_ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+            System.alloc(layout)
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            System.dealloc(ptr, layout)
+        }
+
+        unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+            let _ = HEAP_ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+            System.realloc(ptr, layout, new_size)
+        }
+    }
+
+    #[global_allocator]
+    static GLOBAL: CountingAllocator = CountingAllocator;
+
+    /// Test per verificare che `CustomEmbedded` serva i buffer dal pool statico senza allocare sull'heap
+    #[test]
+    fn test_custom_embedded_zero_heap() {
+        let mut manager = MemoryManager::new(ApplicationType::EmbeddedSystem, MemoryConfig::new(1024, 128, 1)).unwrap();
+        let free_before = manager.total_free_bytes();
+
+        HEAP_ALLOCATIONS.with(|count| count.set(0));
+        for _ in 0..16 {
+            let mut buffer = manager.allocate(Some(AllocationStrategy::CustomEmbedded), 128).unwrap();
+            buffer[0] = 0xAB;
+            manager.deallocate(buffer).unwrap();
+        }
+        let allocations = HEAP_ALLOCATIONS.with(|count| count.get());
+
+        assert_eq!(allocations, 0, "CustomEmbedded non dovrebbe allocare sull'heap");
+        assert_eq!(manager.total_free_bytes(), free_before, "Le regioni dovrebbero tornare al pool statico");
+    }
+}
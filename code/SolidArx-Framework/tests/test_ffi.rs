@@ -0,0 +1,32 @@
+/// # Unit Tests per `ffi/memory.rs`
+///
+/// Le funzioni esportate vengono chiamate da codice C compilato da `build.rs`:
+/// cargo test --features "ffi"
+
+#[cfg(all(test, feature = "ffi"))]
+mod tests {
+    use std::os::raw::c_int;
+    use solid_arx_lib::ffi::memory::{arx_allocate, arx_deallocate, arx_init_memory, arx_pool_free, ARX_STRATEGY_POOL};
+
+    extern "C" {
+        fn arx_ffi_roundtrip() -> c_int;
+    }
+
+    /// Test per verificare allocazione, scrittura e rilascio tramite puntatore grezzo da C
+    #[test]
+    fn test_c_roundtrip() {
+        // Senza inizializzazione non viene restituita memoria
+        assert!(arx_allocate(16, ARX_STRATEGY_POOL).is_null());
+
+        // SAFETY: la funzione C utilizza solo l'interfaccia esportata da `ffi::memory`.
+        assert_eq!(unsafe { arx_ffi_roundtrip() }, 0);
+
+        // Lo stato globale resta utilizzabile anche da Rust
+        assert_eq!(arx_init_memory(64, 4 * 64), 0);
+        let ptr = arx_allocate(64, ARX_STRATEGY_POOL);
+        assert!(!ptr.is_null());
+        assert_eq!(arx_pool_free(), 3);
+        arx_deallocate(ptr, 64);
+        assert_eq!(arx_pool_free(), 4);
+    }
+}
@@ -0,0 +1,38 @@
+/// # Unit Tests per l'inoltro dei `LogEvent` in `crud/stores/log_event_store.rs`
+///
+/// Verificano l'invio dei batch di eventi tramite un mock server HTTP.
+///
+/// Eseguire con:
+/// cargo test --features "crud","embedded","log-forwarding"
+
+#[cfg(all(test, feature = "crud", feature = "embedded", feature = "log-forwarding"))]
+mod tests {
+    use std::time::Duration;
+    use httpmock::prelude::*;
+    use solid_arx_lib::core::memory_management::LOG_EVENTS_IN_MEMORY;
+    use solid_arx_lib::crud::models::default::log_event::model::LogEvent;
+    use solid_arx_lib::crud::stores::log_event_store::LogEventStore;
+
+    /// Test per verificare che 12 eventi vengano inoltrati in 2 batch da 6
+    #[tokio::test]
+    async fn test_forward_in_batches() {
+        let server = MockServer::start_async().await;
+        let mock = server.mock_async(|when, then| {
+            when.method(POST).path("/logs").header("content-type", "application/json");
+            then.status(200);
+        }).await;
+
+        let store = LogEventStore::new();
+        for id in 6000..6012 {
+            let event = LogEvent::new(id, 1, "info".to_string(), "2024-01-01T00:00:00".to_string(), format!("evento {}", id), vec![0u8; 8].into_boxed_slice());
+            store.insert(event).unwrap();
+        }
+
+        let handle = store.forward_to(&server.url("/logs"), 6, Duration::from_millis(50));
+        tokio::time::sleep(Duration::from_millis(300)).await;
+        handle.stop().await;
+
+        mock.assert_hits_async(2).await;
+        assert!(LOG_EVENTS_IN_MEMORY.lock().unwrap().keys().all(|id| !(6000..6012).contains(id)));
+    }
+}
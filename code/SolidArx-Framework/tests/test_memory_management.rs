@@ -0,0 +1,1453 @@
+/// # Unit Tests per `memory_management.rs`
+///
+/// Questo modulo verifica le strategie di allocazione del `MemoryManager` e le
+/// strutture di supporto definite in `memory_management.rs`.
+///
+/// Alcuni test dipendono dalle feature attive, ad esempio:
+/// cargo test --features "embedded"
+
+#[cfg(test)]
+mod tests {
+    use solid_arx_lib::config::{global_config::ApplicationType, memory_config::MemoryConfig};
+    use solid_arx_lib::core::memory_management::MemoryManager;
+    #[cfg(feature = "embedded")]
+    use solid_arx_lib::core::memory_management::EmbeddedPool;
+    #[cfg(feature = "embedded")]
+    use solid_arx_lib::core::system_core::CoreError;
+
+    /// Test per verificare che l'`EmbeddedPool` rispetti la capacità fissata in compile time
+    #[cfg(feature = "embedded")]
+    #[test]
+    fn test_embedded_pool_capacity_and_overflow() {
+        let mut pool: EmbeddedPool<256> = EmbeddedPool::new();
+        assert_eq!(pool.capacity(), 256);
+
+        let (first, slice) = pool.allocate(200).unwrap();
+        assert_eq!(slice.len(), 200);
+
+        // Oltre la capacità residua l'allocazione deve fallire
+        let overflow = pool.allocate(100);
+        assert!(matches!(overflow, Err(CoreError::ResourceAllocationError(_, _))));
+
+        // Liberata la prima regione lo spazio torna disponibile
+        pool.deallocate(first).unwrap();
+        let (reused, _) = pool.allocate(100).unwrap();
+        assert_eq!(reused.offset, 0, "La regione liberata dovrebbe essere riutilizzata");
+    }
+
+    /// Test per verificare che l'`EmbeddedPool` fonda le regioni libere adiacenti
+    #[cfg(feature = "embedded")]
+    #[test]
+    fn test_embedded_pool_coalescing() {
+        let mut pool: EmbeddedPool<256> = EmbeddedPool::new();
+        let (first, _) = pool.allocate(64).unwrap();
+        let (second, _) = pool.allocate(64).unwrap();
+        let (_third, _) = pool.allocate(128).unwrap();
+        assert_eq!(pool.total_free_bytes(), 0);
+
+        // Le due regioni liberate sono adiacenti e devono formare un'unica area
+        pool.deallocate(second).unwrap();
+        pool.deallocate(first).unwrap();
+        assert_eq!(pool.largest_free_contiguous_bytes(), 128);
+
+        let (merged, slice) = pool.allocate(128).unwrap();
+        assert_eq!(merged.offset, 0, "Le regioni adiacenti dovrebbero essere fuse");
+        assert!(slice.iter().all(|&b| b == 0));
+    }
+
+    /// Test per verificare che `GET /memory/json` restituisca le statistiche del pool
+    #[cfg(feature = "http-dashboard")]
+    #[test]
+    fn test_dashboard_memory_json() {
+        use std::io::{Read, Write};
+        use std::net::TcpStream;
+
+        let mut manager = MemoryManager::new(ApplicationType::WebApp, MemoryConfig::new(4096, 1024, 1)).unwrap();
+        let buffer = manager.allocate(None, 1024).unwrap();
+
+        let handle = manager.start_dashboard("127.0.0.1:0".parse().unwrap()).unwrap();
+        let mut stream = TcpStream::connect(handle.addr()).unwrap();
+        stream.write_all(b"GET /memory/json HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n").unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+
+        let body = response.split("\r\n\r\n").nth(1).expect("Risposta senza body");
+        let json: serde_json::Value = serde_json::from_str(body).expect("Il body deve essere JSON valido");
+        assert_eq!(json["pool_capacity"], 4);
+        assert_eq!(json["free_buffers"], 3);
+        assert_eq!(json["buffer_size"], 1024);
+        assert_eq!(json["total_allocations"], 1);
+
+        manager.deallocate(buffer).unwrap();
+        handle.stop();
+    }
+
+    /// Test per verificare che ogni allocazione finisca nel bucket corretto dell'istogramma
+    #[cfg(feature = "tracking")]
+    #[test]
+    fn test_allocation_histogram() {
+        let mut manager = MemoryManager::new(ApplicationType::DesktopApp, MemoryConfig::default()).unwrap();
+        for size in [64, 256, 1024, 4096, 4096] {
+            manager.allocate(None, size).unwrap();
+        }
+
+        let histogram = manager.histogram();
+        assert_eq!(histogram.count_for(64), 1);
+        assert_eq!(histogram.count_for(256), 1);
+        assert_eq!(histogram.count_for(1024), 1);
+        assert_eq!(histogram.count_for(4096), 2);
+        assert_eq!(histogram.count_for(128), 0);
+        assert_eq!(manager.most_common_size(), 4096);
+    }
+
+    /// Test per verificare che con `lazy_pool` i buffer vengano allocati solo alla prima `allocate`
+    #[test]
+    fn test_lazy_pool_initialization() {
+        let mut config = MemoryConfig::new(4096, 1024, 1);
+        config.lazy_pool = true;
+        let mut manager = MemoryManager::new(ApplicationType::WebApp, config).unwrap();
+
+        let stats = manager.memory_stats();
+        assert_eq!(stats.pool_capacity, 0, "Il pool non deve essere allocato alla creazione");
+        assert_eq!(stats.free_buffers, 0);
+
+        let buffer = manager.allocate(None, 1024).unwrap();
+        let stats = manager.memory_stats();
+        assert_eq!(stats.pool_capacity, 4);
+        assert_eq!(stats.free_buffers, 3);
+        assert_eq!(buffer.len(), 1024);
+    }
+
+    /// Test per verificare che l'allocazione registri il percorso dei contesti attivi
+    #[cfg(feature = "tracking")]
+    #[test]
+    fn test_allocation_context_stack() {
+        let mut manager = MemoryManager::new(ApplicationType::DesktopApp, MemoryConfig::default()).unwrap();
+
+        MemoryManager::push_context("request");
+        MemoryManager::push_context("db_fetch");
+        manager.allocate(None, 128).unwrap();
+        assert_eq!(MemoryManager::pop_context().as_deref(), Some("db_fetch"));
+        manager.allocate(None, 64).unwrap();
+        MemoryManager::pop_context();
+
+        let records = manager.allocation_records();
+        assert_eq!(records[0].context, "request > db_fetch");
+        assert_eq!(records[1].context, "request");
+        assert_eq!(MemoryManager::current_context(), "");
+    }
+
+    /// Test per verificare i colori dei buffer liberi e in uso nel grafo DOT
+    #[cfg(feature = "tracking")]
+    #[test]
+    fn test_to_dot_graph_colors() {
+        // Pool di due buffer, di cui uno in uso
+        let mut manager = MemoryManager::new(ApplicationType::WebApp, MemoryConfig::new(2048, 1024, 1)).unwrap();
+        let _checked_out = manager.allocate(None, 1024).unwrap();
+
+        let dot = manager.to_dot_graph();
+        assert!(dot.starts_with("digraph memory {"));
+        assert_eq!(dot.matches("color=red").count(), 1);
+        assert_eq!(dot.matches("color=green").count(), 1);
+        assert!(dot.contains("[label=\"allocate\"]"));
+    }
+
+    /// Test per verificare copia, troncamento e azzeramento in `resize_buffer`
+    #[test]
+    fn test_resize_buffer() {
+        let mut manager = MemoryManager::new(ApplicationType::DesktopApp, MemoryConfig::default()).unwrap();
+
+        // Ridimensionamento verso l'alto: i byte aggiunti sono azzerati
+        let mut buffer = manager.allocate(None, 4).unwrap();
+        buffer.copy_from_slice(&[1, 2, 3, 4]);
+        let grown = manager.resize_buffer(buffer, 8).unwrap();
+        assert_eq!(&grown[..], &[1, 2, 3, 4, 0, 0, 0, 0]);
+
+        // Ridimensionamento verso il basso: il contenuto viene troncato
+        let shrunk = manager.resize_buffer(grown, 2).unwrap();
+        assert_eq!(&shrunk[..], &[1, 2]);
+
+        // Stessa dimensione: il contenuto viene solo copiato
+        let same = manager.resize_buffer(shrunk, 2).unwrap();
+        assert_eq!(&same[..], &[1, 2]);
+
+        // La variante per `Box<[u8]>` si comporta allo stesso modo
+        let boxed: Box<[u8]> = vec![1, 2, 3, 4].into_boxed_slice();
+        let grown = manager.resize_boxed_buffer(boxed, 6).unwrap();
+        assert_eq!(&grown[..], &[1, 2, 3, 4, 0, 0]);
+        assert_eq!(&manager.resize_boxed_buffer(grown, 3).unwrap()[..], &[1, 2, 3]);
+
+        // Con `PoolBased` il buffer del pool viene copiato nel `Box<[u8]>` e torna al pool
+        let mut pooled = MemoryManager::new(ApplicationType::WebApp, MemoryConfig::new(4 * 64, 64, 1)).unwrap();
+        let resized = pooled.resize_boxed_buffer(vec![5u8; 32].into_boxed_slice(), 64).unwrap();
+        assert_eq!(resized.len(), 64);
+        assert!(resized[..32].iter().all(|byte| *byte == 5) && resized[32..].iter().all(|byte| *byte == 0));
+        assert_eq!(pooled.memory_stats().free_buffers, pooled.memory_stats().pool_capacity);
+    }
+
+    /// Test per verificare che ogni metodo del `MemoryObserver` venga chiamato il numero corretto di volte
+    #[test]
+    fn test_memory_observer_counts() {
+        use std::sync::Arc;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use solid_arx_lib::core::memory_management::{AllocationStrategy, MemoryObserver};
+
+        #[derive(Default)]
+        struct CountingObserver {
+            allocations: AtomicUsize,
+            deallocations: AtomicUsize,
+            exhaustions: AtomicUsize,
+        }
+
+        impl MemoryObserver for CountingObserver {
+            fn on_allocate(&self, _size: usize, _strategy: &AllocationStrategy) {
+                self.allocations.fetch_add(1, Ordering::SeqCst);
+            }
+            fn on_deallocate(&self, _size: usize) {
+                self.deallocations.fetch_add(1, Ordering::SeqCst);
+            }
+            fn on_pool_exhaustion(&self) {
+                self.exhaustions.fetch_add(1, Ordering::SeqCst);
+            }
+        }
+
+        // Pool di due buffer: la terza allocazione esaurisce il pool
+        let mut manager = MemoryManager::new(ApplicationType::WebApp, MemoryConfig::new(2048, 1024, 1)).unwrap();
+        let observer = Arc::new(CountingObserver::default());
+        manager.observe(observer.clone());
+
+        let buffers: Vec<_> = (0..3).map(|_| manager.allocate(None, 1024).unwrap()).collect();
+        for buffer in buffers.into_iter().take(2) {
+            manager.deallocate(buffer).unwrap();
+        }
+
+        assert_eq!(observer.allocations.load(Ordering::SeqCst), 3);
+        assert_eq!(observer.deallocations.load(Ordering::SeqCst), 2);
+        assert_eq!(observer.exhaustions.load(Ordering::SeqCst), 1);
+    }
+
+    /// Test per verificare con un pattern canary quando ciascuna politica azzera i buffer del pool
+    #[test]
+    fn test_zeroing_policy_canary() {
+        use solid_arx_lib::config::memory_config::ZeroingPolicy;
+
+        let cases = [
+            (ZeroingPolicy::NeverZero, false),
+            (ZeroingPolicy::ZeroOnAllocate, true),
+            (ZeroingPolicy::ZeroOnDeallocate, true),
+            (ZeroingPolicy::ZeroOnBoth, true),
+        ];
+
+        for (policy, zeroed) in cases {
+            // Pool di un solo buffer, così da riottenere sempre lo stesso
+            let mut manager = MemoryManager::new(ApplicationType::WebApp, MemoryConfig::new(1024, 1024, 1)).unwrap();
+            manager.zeroing_policy(policy);
+
+            let mut buffer = manager.allocate(None, 1024).unwrap();
+            buffer.fill(0xAA);
+            manager.deallocate(buffer).unwrap();
+
+            let reused = manager.allocate(None, 1024).unwrap();
+            let expected = if zeroed { 0x00 } else { 0xAA };
+            assert!(reused.iter().all(|b| *b == expected), "Contenuto inatteso con la politica {:?}", policy);
+        }
+    }
+
+    /// Test per verificare che l'istantanea compressa sia più piccola e ripristini lo stesso pool
+    #[cfg(feature = "compression")]
+    #[test]
+    fn test_freeze_thaw_compressed() {
+        use solid_arx_lib::config::memory_config::ZeroingPolicy;
+
+        let dir = std::env::temp_dir();
+        let raw_path = dir.join(format!("arx_pool_{}.raw", std::process::id()));
+        let compressed_path = dir.join(format!("arx_pool_{}.zst", std::process::id()));
+
+        // Pool di 100 buffer da 1 KB con contenuto noto
+        let mut manager = MemoryManager::new(ApplicationType::WebApp, MemoryConfig::new(100 * 1024, 1024, 1)).unwrap();
+        let mut buffers: Vec<_> = (0..100).map(|_| manager.allocate(None, 1024).unwrap()).collect();
+        for (i, buffer) in buffers.iter_mut().enumerate() {
+            buffer.iter_mut().enumerate().for_each(|(j, b)| *b = ((i + j) % 7) as u8);
+        }
+        for buffer in buffers {
+            manager.deallocate(buffer).unwrap();
+        }
+
+        manager.freeze(&raw_path).unwrap();
+        manager.freeze_compressed(&compressed_path, 3).unwrap();
+        let raw_size = std::fs::metadata(&raw_path).unwrap().len();
+        let compressed_size = std::fs::metadata(&compressed_path).unwrap().len();
+        assert!(compressed_size < raw_size, "{} >= {}", compressed_size, raw_size);
+
+        let mut restored = MemoryManager::new(ApplicationType::WebApp, MemoryConfig::new(1024, 1024, 1)).unwrap();
+        restored.thaw_compressed(&compressed_path).unwrap();
+        assert_eq!(restored.memory_stats().free_buffers, 100);
+
+        // Senza azzeramento i buffer restituiti dal pool mantengono il contenuto salvato
+        manager.zeroing_policy(ZeroingPolicy::NeverZero);
+        restored.zeroing_policy(ZeroingPolicy::NeverZero);
+        for _ in 0..100 {
+            assert_eq!(manager.allocate(None, 1024).unwrap(), restored.allocate(None, 1024).unwrap());
+        }
+
+        let _ = std::fs::remove_file(raw_path);
+        let _ = std::fs::remove_file(compressed_path);
+    }
+
+    /// Test per verificare che `thaw` non si fidi del numero di buffer dichiarato e conteggi i buffer in uso
+    #[test]
+    fn test_thaw_untrusted_count_and_checked_out_buffers() {
+        let path = std::env::temp_dir().join(format!("arx_pool_header_{}.raw", std::process::id()));
+        let mut manager = MemoryManager::new(ApplicationType::WebApp, MemoryConfig::new(4 * 64, 64, 1)).unwrap();
+
+        // L'intestazione dichiara un numero enorme di buffer ma il file ne contiene uno solo
+        let mut contents = b"ARXP".to_vec();
+        contents.extend_from_slice(&64u64.to_le_bytes());
+        contents.extend_from_slice(&(u64::MAX / 128).to_le_bytes());
+        contents.extend_from_slice(&[7u8; 64]);
+        std::fs::write(&path, &contents).unwrap();
+        assert!(manager.thaw(&path).is_err());
+        assert_eq!(manager.stats().total_buffers, 4);
+
+        // Il buffer in uso durante `thaw` resta conteggiato nella capacità e torna nel nuovo pool
+        let checked_out = manager.allocate(None, 64).unwrap();
+        manager.freeze(&path).unwrap();
+        manager.thaw(&path).unwrap();
+        assert_eq!(manager.stats().total_buffers, 4);
+        assert_eq!(manager.stats().available_buffers, 3);
+        manager.deallocate(checked_out).unwrap();
+        assert_eq!(manager.stats().available_buffers, 4);
+
+        let _ = std::fs::remove_file(path);
+    }
+
+    /// Test per verificare il prefetch dei buffer del pool e il rifiuto degli ID inesistenti
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_prefetch_pool_buffers() {
+        let mut manager = MemoryManager::new(ApplicationType::WebApp, MemoryConfig::new(64 * 1024, 16 * 1024, 1)).unwrap();
+        manager.prefetch(&[0, 1, 2, 3]).unwrap();
+
+        // Le pagine suggerite restano accessibili con il loro contenuto
+        let buffer = manager.allocate(None, 16 * 1024).unwrap();
+        assert!(buffer.iter().all(|b| *b == 0));
+
+        assert!(manager.prefetch(&[42]).is_err());
+
+        // Per le strategie senza pool il prefetch non ha effetto
+        let standard = MemoryManager::new(ApplicationType::DesktopApp, MemoryConfig::default()).unwrap();
+        assert!(standard.prefetch(&[42]).is_ok());
+    }
+
+    /// Test per verificare che cedendo il controllo sotto pressione gli altri task avanzino
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_yield_if_under_pressure() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::sync::Arc;
+
+        let mut manager = MemoryManager::new(ApplicationType::WebApp, MemoryConfig::new(4 * 1024, 1024, 1)).unwrap();
+        assert!(!manager.yield_if_under_pressure(0.5), "Un pool pieno non è sotto pressione");
+
+        let progress = Arc::new(AtomicUsize::new(0));
+        let background = {
+            let progress = Arc::clone(&progress);
+            tokio::spawn(async move {
+                loop {
+                    progress.fetch_add(1, Ordering::Relaxed);
+                    tokio::task::yield_now().await;
+                }
+            })
+        };
+
+        // Ciclo stretto di allocazioni: senza cedere il controllo il task in background non verrebbe mai eseguito
+        let hot_loop = tokio::spawn(async move {
+            let mut held = Vec::new();
+            let mut yields = 0;
+            for _ in 0..100 {
+                held.push(manager.allocate(None, 1024).unwrap());
+                if manager.yield_if_under_pressure(0.5) {
+                    yields += 1;
+                    tokio::task::yield_now().await;
+                }
+            }
+            yields
+        });
+
+        let yields = hot_loop.await.unwrap();
+        background.abort();
+        assert!(yields > 0);
+        assert!(progress.load(Ordering::Relaxed) > 0, "Il task in background deve avanzare durante il ciclo di allocazioni");
+    }
+
+    /// Test per verificare il prestito di buffer tra gestori e la loro restituzione
+    #[test]
+    fn test_lend_and_recall_buffers() {
+        use std::sync::Arc;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use solid_arx_lib::core::memory_management::{AllocationStrategy, MemoryObserver};
+
+        struct ExhaustionCounter(AtomicUsize);
+        impl MemoryObserver for ExhaustionCounter {
+            fn on_allocate(&self, _size: usize, _strategy: &AllocationStrategy) {}
+            fn on_deallocate(&self, _size: usize) {}
+            fn on_pool_exhaustion(&self) {
+                self.0.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+
+        let mut busy = MemoryManager::new(ApplicationType::WebApp, MemoryConfig::new(2 * 1024, 1024, 1)).unwrap();
+        let mut idle = MemoryManager::new(ApplicationType::WebApp, MemoryConfig::new(8 * 1024, 1024, 1)).unwrap();
+        let exhaustion = Arc::new(ExhaustionCounter(AtomicUsize::new(0)));
+        busy.observe(exhaustion.clone());
+
+        let held = vec![busy.allocate(None, 1024).unwrap(), busy.allocate(None, 1024).unwrap()];
+        assert_eq!(busy.memory_stats().free_buffers, 0);
+
+        // Il prestito è limitato ai buffer liberi del gestore inattivo
+        assert_eq!(idle.lend(20, &mut busy).unwrap(), 8);
+        assert_eq!(idle.memory_stats().pool_capacity, 0);
+        assert_eq!(busy.memory_stats().pool_capacity, 10);
+
+        let borrowed = busy.allocate(None, 1024).unwrap();
+        assert_eq!(exhaustion.0.load(Ordering::Relaxed), 0, "L'allocazione deve usare un buffer prestato");
+        busy.deallocate(borrowed).unwrap();
+
+        assert_eq!(busy.recall(&mut idle, 20).unwrap(), 8);
+        assert_eq!(idle.memory_stats().pool_capacity, 8);
+        assert_eq!(idle.memory_stats().free_buffers, 8);
+        assert_eq!(busy.memory_stats().pool_capacity, 2);
+        assert_eq!(busy.memory_stats().free_buffers, 0);
+        drop(held);
+    }
+
+    /// Test per verificare l'aritmetica della stima della memoria occupata dal pool
+    #[test]
+    fn test_estimate_memory_usage() {
+        use solid_arx_lib::core::memory_management::ManagedBuffer;
+        use std::collections::VecDeque;
+        use std::mem::size_of;
+
+        let estimate = MemoryConfig::new(64 * 1024, 1024, 1).estimate_memory_usage();
+        assert_eq!(estimate.pool_bytes, 64 * 1024);
+        assert_eq!(estimate.overhead_bytes, size_of::<VecDeque<ManagedBuffer>>() + 64 * size_of::<ManagedBuffer>());
+        assert_eq!(estimate.total_bytes, estimate.pool_bytes + estimate.overhead_bytes);
+
+        #[cfg(feature = "system-check")]
+        assert!(estimate.percentage_of_system > 0.0 && estimate.percentage_of_system < 100.0);
+        #[cfg(not(feature = "system-check"))]
+        assert_eq!(estimate.percentage_of_system, 0.0);
+    }
+
+    /// Test per verificare che un buffer riciclato con i buffer liberi protetti venga letto azzerato
+    #[cfg(all(target_os = "linux", feature = "lazy-zero"))]
+    #[test]
+    fn test_protect_and_zero_on_reuse() {
+        use solid_arx_lib::config::memory_config::ZeroingPolicy;
+
+        // Un solo buffer nel pool, così da riottenere lo stesso buffer dopo la deallocazione
+        let mut manager = MemoryManager::new(ApplicationType::WebApp, MemoryConfig::new(64 * 1024, 64 * 1024, 1)).unwrap();
+        manager.zeroing_policy(ZeroingPolicy::ProtectAndZeroOnReuse);
+
+        let mut buffer = manager.allocate(None, 64 * 1024).unwrap();
+        buffer.fill(0xA5);
+        let address = buffer.as_ptr();
+        manager.deallocate(buffer).unwrap();
+
+        let mut recycled = manager.allocate(None, 64 * 1024).unwrap();
+        assert_eq!(recycled.as_ptr(), address, "Il pool deve restituire lo stesso buffer");
+        assert!(recycled.iter().all(|b| *b == 0), "La prima lettura deve vedere solo zeri");
+
+        // Dopo l'allocazione le pagine restano accessibili e mantengono i dati scritti
+        recycled.fill(0x3C);
+        assert!(recycled.iter().all(|b| *b == 0x3C));
+        manager.deallocate(recycled).unwrap();
+    }
+
+    /// Test per verificare che la catena dei contesti di `CoreError` venga mostrata in ordine
+    #[test]
+    fn test_core_error_context_chain() {
+        use solid_arx_lib::core::system_core::CoreError;
+
+        let error = CoreError::ResourceAllocationError("Pool non disponibile".to_string(), Vec::new())
+            .with_context("read_pool")
+            .with_context("MemoryManager::thaw");
+        assert_eq!(error.context(), ["read_pool".to_string(), "MemoryManager::thaw".to_string()]);
+        assert_eq!(error.to_string(), "[read_pool > MemoryManager::thaw]: ResourceAllocationError: Pool non disponibile");
+
+        // Senza contesti il messaggio resta invariato
+        let plain = CoreError::GenericError("errore".to_string(), Vec::new());
+        assert_eq!(plain.to_string(), "Error: errore");
+
+        // Gli errori del MemoryManager riportano l'operazione che li ha prodotti
+        let mut manager = MemoryManager::new(ApplicationType::WebApp, MemoryConfig::new(1024, 1024, 1)).unwrap();
+        let missing = std::env::temp_dir().join("arx_context_missing.bin");
+        let _ = std::fs::remove_file(&missing);
+        let error = manager.thaw(&missing).unwrap_err();
+        assert!(error.to_string().starts_with("[MemoryManager::thaw]: "), "{}", error);
+    }
+
+    /// Test per verificare che 1000 task asincroni si contendano un pool di 10 buffer senza deadlock
+    #[cfg(feature = "async")]
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn test_allocate_async_back_pressure() {
+        use std::sync::Arc;
+        use std::time::Duration;
+
+        let manager = MemoryManager::new(ApplicationType::WebApp, MemoryConfig::new(10 * 64, 64, 1)).unwrap();
+        let manager = Arc::new(manager);
+
+        let handles: Vec<_> = (0..1000)
+            .map(|_| {
+                let manager = Arc::clone(&manager);
+                tokio::spawn(async move {
+                    let buffer = manager.allocate_async().await.unwrap();
+                    assert_eq!(buffer.len(), 64, "Il buffer deve provenire dal pool");
+                    tokio::task::yield_now().await;
+                    manager.deallocate_async(buffer).await.unwrap();
+                })
+            })
+            .collect();
+
+        let all = async {
+            for handle in handles {
+                handle.await.unwrap();
+            }
+        };
+        tokio::time::timeout(Duration::from_secs(30), all).await.expect("Deadlock nell'allocazione asincrona");
+
+        // Tutti i buffer devono essere tornati al pool
+        assert_eq!(manager.total_free_bytes(), 10 * 64);
+    }
+
+    /// Test per verificare che `max_allocation_size` valga anche per le allocazioni allineate e su pagine huge
+    #[test]
+    fn test_max_allocation_size_all_paths() {
+        use solid_arx_lib::core::system_core::CoreError;
+
+        let mut config = MemoryConfig::new(1024, 1024, 1);
+        config.max_allocation_size = Some(1_000_000);
+        let mut manager = MemoryManager::new(ApplicationType::DesktopApp, config).unwrap();
+
+        assert!(matches!(
+            manager.allocate_aligned(2 * 1024 * 1024, 64),
+            Err(CoreError::AllocationTooLarge { requested: 2_097_152, max: 1_000_000, .. })
+        ));
+        assert!(matches!(
+            manager.allocate_huge_page(2 * 1024 * 1024),
+            Err(CoreError::AllocationTooLarge { requested: 2_097_152, max: 1_000_000, .. })
+        ));
+        assert_eq!(manager.allocate_aligned(500 * 1024, 64).unwrap().len(), 500 * 1024);
+    }
+
+    /// Test per verificare i comandi del server IPC della memoria
+    #[cfg(all(unix, feature = "ipc"))]
+    #[test]
+    fn test_ipc_server_stats() {
+        use std::sync::{Arc, Mutex};
+        use solid_arx_lib::monitoring::ipc::{send_command, IpcCommand, IpcResponse};
+
+        let manager = MemoryManager::new(ApplicationType::WebApp, MemoryConfig::new(4 * 256, 256, 1)).unwrap();
+        let manager = Arc::new(Mutex::new(manager));
+        let buffer = manager.lock().unwrap().allocate(None, 256).unwrap();
+
+        let socket = std::env::temp_dir().join(format!("arx_ipc_{}.sock", std::process::id()));
+        let server = MemoryManager::start_ipc_server(Arc::clone(&manager), &socket).unwrap();
+
+        let IpcResponse::Stats(stats) = send_command(&socket, IpcCommand::Stats).unwrap() else {
+            panic!("Risposta inattesa al comando stats");
+        };
+        assert_eq!(stats, manager.lock().unwrap().memory_stats());
+        assert_eq!(stats.pool_capacity, 4);
+        assert_eq!(stats.free_buffers, 3);
+        assert_eq!(stats.total_allocations, 1);
+
+        // La risposta grezza è JSON delimitato da newline
+        let raw = serde_json::to_string(&IpcResponse::Stats(stats.clone())).unwrap();
+        assert!(raw.starts_with("{\"stats\":"));
+
+        let IpcResponse::Stats(drained) = send_command(&socket, IpcCommand::Drain).unwrap() else { panic!() };
+        assert_eq!((drained.pool_capacity, drained.free_buffers), (1, 0));
+        let IpcResponse::Stats(refilled) = send_command(&socket, IpcCommand::Refill).unwrap() else { panic!() };
+        assert_eq!((refilled.pool_capacity, refilled.free_buffers), (4, 3));
+        let IpcResponse::Stats(reset) = send_command(&socket, IpcCommand::Reset).unwrap() else { panic!() };
+        assert_eq!(reset.total_allocations, 0);
+
+        manager.lock().unwrap().deallocate(buffer).unwrap();
+        server.stop();
+        assert!(!socket.exists());
+    }
+
+    /// Test per verificare che il server IPC non rimuova file che non sono socket e crei il socket con permessi 0600
+    #[cfg(all(unix, feature = "ipc"))]
+    #[test]
+    fn test_ipc_server_socket_path() {
+        use std::os::unix::fs::PermissionsExt;
+        use solid_arx_lib::monitoring::ipc::{send_command, IpcCommand, IpcResponse};
+        use std::sync::{Arc, Mutex};
+
+        let manager = MemoryManager::new(ApplicationType::WebApp, MemoryConfig::new(4 * 256, 256, 1)).unwrap();
+        let manager = Arc::new(Mutex::new(manager));
+
+        let file = std::env::temp_dir().join(format!("arx_ipc_file_{}.sock", std::process::id()));
+        std::fs::write(&file, b"dati").unwrap();
+        assert!(MemoryManager::start_ipc_server(Arc::clone(&manager), &file).is_err());
+        assert_eq!(std::fs::read(&file).unwrap(), b"dati");
+        std::fs::remove_file(&file).unwrap();
+
+        let socket = std::env::temp_dir().join(format!("arx_ipc_mode_{}.sock", std::process::id()));
+        let server = MemoryManager::start_ipc_server(Arc::clone(&manager), &socket).unwrap();
+        assert_eq!(std::fs::metadata(&socket).unwrap().permissions().mode() & 0o777, 0o600);
+        // La directory temporanea usata per il `bind` viene rimossa dopo lo spostamento del socket
+        let private_dir = std::env::temp_dir().join(format!(".arx_ipc_mode_{}.sock.{}", std::process::id(), std::process::id()));
+        assert!(!private_dir.exists());
+        assert!(matches!(send_command(&socket, IpcCommand::Stats), Ok(IpcResponse::Stats(_))));
+        server.stop();
+    }
+
+    /// Test per verificare il nuovo tentativo di allocazione dopo la compattazione dell'heap
+    #[cfg(feature = "compact-retry")]
+    #[test]
+    fn test_compact_and_retry() {
+        use std::sync::Arc;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use solid_arx_lib::core::memory_management::{AllocationStrategy, HeapAllocator};
+        use solid_arx_lib::core::system_core::CoreError;
+
+        /// Allocatore che fallisce finché l'heap non viene compattato
+        #[derive(Default)]
+        struct FragmentedHeap {
+            attempts: AtomicUsize,
+            compactions: AtomicUsize,
+        }
+        impl HeapAllocator for FragmentedHeap {
+            fn try_allocate(&self, size: usize) -> Option<Box<[u8]>> {
+                self.attempts.fetch_add(1, Ordering::SeqCst);
+                if self.compactions.load(Ordering::SeqCst) == 0 {
+                    None
+                } else {
+                    Some(vec![0u8; size].into_boxed_slice())
+                }
+            }
+            fn defragment(&self) {
+                self.compactions.fetch_add(1, Ordering::SeqCst);
+            }
+        }
+
+        let heap = Arc::new(FragmentedHeap::default());
+        let mut manager = MemoryManager::new(ApplicationType::DesktopApp, MemoryConfig::new(1024, 1024, 1)).unwrap();
+        manager.set_heap_allocator(heap.clone());
+
+        let buffer = manager.allocate(Some(AllocationStrategy::Standard), 4096).unwrap();
+        assert_eq!(buffer.len(), 4096);
+        assert_eq!(heap.attempts.load(Ordering::SeqCst), 2);
+        assert_eq!(heap.compactions.load(Ordering::SeqCst), 1);
+
+        // Senza `compact_and_retry` l'errore viene propagato al primo fallimento
+        let heap = Arc::new(FragmentedHeap::default());
+        let mut config = MemoryConfig::new(1024, 1024, 1);
+        config.compact_and_retry = false;
+        let mut manager = MemoryManager::new(ApplicationType::DesktopApp, config).unwrap();
+        manager.set_heap_allocator(heap.clone());
+        let result = manager.allocate(Some(AllocationStrategy::Standard), 4096);
+        assert!(matches!(result, Err(CoreError::ResourceAllocationError(_, _))));
+        assert_eq!(heap.compactions.load(Ordering::SeqCst), 0);
+    }
+
+    /// Test per verificare la crescita di `GrowingPool`, il limite `max_size` e l'errore a pool esaurito
+    #[test]
+    fn test_growing_pool() {
+        use solid_arx_lib::core::memory_management::AllocationStrategy;
+        use solid_arx_lib::core::system_core::CoreError;
+
+        let strategy = AllocationStrategy::GrowingPool { initial_size: 2, max_size: 10, growth_factor: 2.0 };
+        let mut config = MemoryConfig::new(2 * 64, 64, 1);
+        config.dynamic_fallback = false;
+        let mut manager = MemoryManager::with_strategy(strategy.clone(), config).unwrap();
+        assert_eq!(manager.memory_stats().pool_capacity, 2);
+
+        // La capacità raddoppia ad ogni esaurimento fino al limite: 2 -> 4 -> 8 -> 10
+        let mut buffers = Vec::new();
+        let mut capacities = Vec::new();
+        for _ in 0..10 {
+            buffers.push(manager.allocate(None, 64).unwrap());
+            capacities.push(manager.memory_stats().pool_capacity);
+        }
+        assert_eq!(capacities, vec![2, 2, 4, 4, 8, 8, 8, 8, 10, 10]);
+
+        // Raggiunto `max_size` senza allocazione dinamica l'allocazione fallisce
+        let exhausted = manager.allocate(None, 64);
+        assert!(matches!(exhausted, Err(CoreError::PoolExhausted(_, _))));
+
+        // I buffer restituiti tornano disponibili senza ulteriore crescita
+        manager.deallocate(buffers.pop().unwrap()).unwrap();
+        assert!(manager.allocate(None, 64).is_ok());
+        assert_eq!(manager.memory_stats().pool_capacity, 10);
+
+        // Con l'allocazione dinamica attiva si ricade sull'heap
+        let mut manager = MemoryManager::with_strategy(strategy, MemoryConfig::new(2 * 64, 64, 1)).unwrap();
+        let fallback: Vec<_> = (0..11).map(|_| manager.allocate(None, 64).unwrap()).collect();
+        assert_eq!(fallback.len(), 11);
+        assert_eq!(manager.memory_stats().pool_capacity, 10);
+
+        let invalid = AllocationStrategy::GrowingPool { initial_size: 4, max_size: 2, growth_factor: 2.0 };
+        assert!(matches!(MemoryManager::with_strategy(invalid, MemoryConfig::default()), Err(CoreError::ConfigurationError(_, _))));
+    }
+
+    /// Test per verificare l'allineamento dei buffer di `allocate_aligned` e il vincolo della strategia `CustomEmbedded`
+    #[test]
+    fn test_allocate_aligned() {
+        use solid_arx_lib::core::system_core::CoreError;
+
+        let mut manager = MemoryManager::new(ApplicationType::DesktopApp, MemoryConfig::default()).unwrap();
+        for align in [16, 32, 64] {
+            let mut buffer = manager.allocate_aligned(100, align).unwrap();
+            assert_eq!(buffer.as_ptr() as usize % align, 0);
+            assert_eq!(buffer.len(), 100);
+            assert_eq!(buffer.alignment(), align);
+            assert!(buffer.iter().all(|byte| *byte == 0));
+            buffer[99] = 0xAB;
+            assert_eq!(buffer[99], 0xAB);
+        }
+        assert_eq!(manager.allocate_aligned(0, 64).unwrap().len(), 0);
+
+        // L'allineamento deve essere una potenza di due
+        assert!(matches!(manager.allocate_aligned(100, 24), Err(CoreError::ResourceAllocationError(_, _))));
+
+        // La strategia `CustomEmbedded` accetta solo l'allineamento configurato
+        let mut config = MemoryConfig::default();
+        config.required_alignment = Some(32);
+        let mut embedded = MemoryManager::new(ApplicationType::EmbeddedSystem, config).unwrap();
+        let buffer = embedded.allocate_aligned(256, 32).unwrap();
+        assert_eq!(buffer.as_ptr() as usize % 32, 0);
+        assert!(matches!(embedded.allocate_aligned(256, 16), Err(CoreError::ConfigurationError(_, _))));
+    }
+
+    /// Test per verificare il calcolo di `health_score` a partire dallo stato del pool
+    #[test]
+    fn test_health_score() {
+        let mut manager = MemoryManager::new(ApplicationType::WebApp, MemoryConfig::new(4 * 64, 64, 1)).unwrap();
+        assert!((manager.health_score() - 1.0).abs() < 0.01);
+
+        // Metà del pool occupata, nessuna allocazione dinamica: 0.5 * 0.5 + 0.3 * 1.0 + 0.2 * 1.0
+        let mut buffers: Vec<_> = (0..2).map(|_| manager.allocate(None, 64).unwrap()).collect();
+        assert!((manager.health_score() - 0.75).abs() < 0.01);
+
+        // Pool esaurito e 2 allocazioni dinamiche su 6: 0.5 * 0.0 + 0.3 * (1 - 2 / 6) + 0.2 * 1.0
+        buffers.extend((0..4).map(|_| manager.allocate(None, 64).unwrap()));
+        assert!((manager.health_score() - 0.4).abs() < 0.01);
+
+        // Azzerate le statistiche resta solo il contributo del pool vuoto
+        manager.reset_stats();
+        assert!((manager.health_score() - 0.5).abs() < 0.01);
+
+        // Le strategie senza pool non sono penalizzate
+        let standard = MemoryManager::new(ApplicationType::DesktopApp, MemoryConfig::default()).unwrap();
+        assert!((standard.health_score() - 1.0).abs() < 0.01);
+    }
+
+    /// Test per verificare le metriche di utilizzo del pool restituite da `stats`
+    #[test]
+    fn test_pool_stats() {
+        let mut manager = MemoryManager::new(ApplicationType::WebApp, MemoryConfig::new(5 * 64, 64, 1)).unwrap();
+
+        // Dieci allocazioni da un pool di cinque: le ultime cinque sono dinamiche
+        let buffers: Vec<_> = (0..10).map(|_| manager.allocate(None, 64).unwrap()).collect();
+        let stats = manager.stats();
+        assert_eq!(stats.total_buffers, 5);
+        assert_eq!(stats.available_buffers, 0);
+        assert_eq!(stats.dynamic_fallback_count, 5);
+        assert_eq!(stats.peak_usage, 5);
+
+        for buffer in buffers.into_iter().take(3) {
+            manager.deallocate(buffer).unwrap();
+        }
+        assert_eq!(manager.stats().available_buffers, 3);
+        assert_eq!(manager.stats().peak_usage, 5);
+
+        // L'azzeramento non modifica lo stato del pool
+        manager.reset_stats();
+        let stats = manager.stats();
+        assert_eq!(stats.dynamic_fallback_count, 0);
+        assert_eq!(stats.peak_usage, 0);
+        assert_eq!(stats.total_buffers, 5);
+        assert_eq!(stats.available_buffers, 3);
+    }
+
+    /// Test per verificare che i `MemoryRegistry` di gestori diversi siano isolati tra loro e dalle strutture globali
+    #[cfg(all(feature = "crud", any(feature = "automation", feature = "desktop", feature = "embedded")))]
+    #[test]
+    fn test_memory_registry_isolation() {
+        use std::sync::Arc;
+        use solid_arx_lib::core::memory_management::{MemoryRegistry, TASKS_IN_MEMORY};
+        use solid_arx_lib::crud::models::default::task::model::Task;
+        use solid_arx_lib::crud::stores::task_store::{TaskStore, TaskStoreConfig};
+
+        let mut first = MemoryManager::new(ApplicationType::DesktopApp, MemoryConfig::default()).unwrap();
+        let mut second = MemoryManager::new(ApplicationType::DesktopApp, MemoryConfig::default()).unwrap();
+        assert!(Arc::ptr_eq(&first.registry(), &MemoryRegistry::global()));
+        first.use_registry(Arc::new(MemoryRegistry::new()));
+        second.use_registry(Arc::new(MemoryRegistry::new()));
+
+        let task = Task::new(
+            7000,
+            "Task isolato".to_string(),
+            #[cfg(feature = "automation")] None,
+            #[cfg(feature = "desktop")] None,
+            #[cfg(feature = "embedded")] None,
+            vec![0u8; 8].into_boxed_slice(),
+        );
+        let store = TaskStore::with_registry(TaskStoreConfig::default(), first.registry());
+        store.submit(task).unwrap();
+
+        assert!(first.registry().tasks().lock().unwrap().contains_key(&7000));
+        assert!(second.registry().tasks().lock().unwrap().is_empty());
+        assert!(!TASKS_IN_MEMORY.lock().unwrap().contains_key(&7000));
+    }
+
+    /// Test per verificare che le estensioni di tipi diversi siano recuperabili per tipo senza interferenze
+    #[test]
+    fn test_extensions() {
+        #[derive(Debug, PartialEq)]
+        struct RequestCounter(u64);
+        #[derive(Debug, PartialEq)]
+        struct ServiceName(String);
+
+        let mut manager = MemoryManager::new(ApplicationType::DesktopApp, MemoryConfig::default()).unwrap();
+        assert!(manager.get_extension::<RequestCounter>().is_none());
+
+        assert!(manager.insert_extension(RequestCounter(1)).is_none());
+        assert!(manager.insert_extension(ServiceName("api".to_string())).is_none());
+        assert_eq!(manager.get_extension::<RequestCounter>(), Some(&RequestCounter(1)));
+        assert_eq!(manager.get_extension::<ServiceName>(), Some(&ServiceName("api".to_string())));
+
+        manager.get_extension_mut::<RequestCounter>().unwrap().0 += 1;
+        assert_eq!(manager.get_extension::<RequestCounter>(), Some(&RequestCounter(2)));
+        assert_eq!(manager.get_extension::<ServiceName>(), Some(&ServiceName("api".to_string())));
+
+        // Un nuovo valore dello stesso tipo sostituisce il precedente
+        assert_eq!(manager.insert_extension(RequestCounter(10)), Some(RequestCounter(2)));
+        assert_eq!(manager.get_extension::<RequestCounter>(), Some(&RequestCounter(10)));
+        assert!(manager.get_extension::<u32>().is_none());
+    }
+
+    /// Test per verificare che con jemalloc il pool funzioni e le statistiche riportino la memoria allocata
+    #[cfg(feature = "jemalloc")]
+    #[test]
+    fn test_jemalloc_stats() {
+        let mut manager = MemoryManager::new(ApplicationType::WebApp, MemoryConfig::new(4 * 64, 64, 1)).unwrap();
+        let buffer = manager.allocate(None, 64).unwrap();
+        assert_eq!(manager.stats().available_buffers, 3);
+
+        let stats = manager.jemalloc_stats();
+        assert!(stats.allocated_bytes > 0);
+        assert!(stats.active_bytes >= stats.allocated_bytes);
+        assert!(stats.metadata_bytes > 0);
+
+        manager.deallocate(buffer).unwrap();
+        assert_eq!(manager.stats().available_buffers, 4);
+    }
+
+    /// Test per verificare che `MemoryConfig::validate` rifiuti le configurazioni incoerenti e che `new` la applichi
+    #[test]
+    fn test_memory_config_validate() {
+        use solid_arx_lib::config::memory_config::MAX_EMBEDDED_BUFFER;
+        use solid_arx_lib::core::system_core::CoreError;
+
+        assert!(MemoryConfig::new(4 * 1024, 1024, 1).validate(ApplicationType::WebApp).is_ok());
+        assert!(MemoryConfig::default().validate(ApplicationType::DesktopApp).is_ok());
+
+        let invalid = [
+            (MemoryConfig::new(1024, 0, 1), ApplicationType::DesktopApp, "buffer_size"),
+            (MemoryConfig::new(512, 1024, 1), ApplicationType::ApiBackend, "pool_size"),
+            (MemoryConfig::new(1024, MAX_EMBEDDED_BUFFER + 1, 1), ApplicationType::EmbeddedSystem, "buffer_size"),
+        ];
+        for (config, app_type, field) in invalid {
+            match config.validate(app_type) {
+                Err(CoreError::ConfigurationError(message, _)) => assert!(message.starts_with(field), "{}", message),
+                other => panic!("Configurazione accettata per errore: {:?}", other),
+            }
+        }
+
+        assert!(matches!(
+            MemoryManager::new(ApplicationType::WebApp, MemoryConfig::new(512, 1024, 1)),
+            Err(CoreError::ConfigurationError(_, _))
+        ));
+    }
+
+    /// Test per verificare la crescita e la riduzione del pool, anche con buffer in uso
+    #[test]
+    fn test_resize_pool() {
+        use solid_arx_lib::core::system_core::CoreError;
+
+        let mut manager = MemoryManager::new(ApplicationType::WebApp, MemoryConfig::new(4 * 64, 64, 1)).unwrap();
+        manager.resize_pool(6).unwrap();
+        assert_eq!(manager.stats().total_buffers, 6);
+        assert_eq!(manager.stats().available_buffers, 6);
+
+        // Con tre buffer in uso la riduzione a uno resta in sospeso
+        let buffers: Vec<_> = (0..3).map(|_| manager.allocate(None, 64).unwrap()).collect();
+        manager.resize_pool(1).unwrap();
+        assert_eq!(manager.stats().total_buffers, 3);
+        assert_eq!(manager.stats().available_buffers, 0);
+        assert_eq!(manager.pending_shrink_target(), Some(1));
+
+        // Ogni buffer restituito viene rilasciato finché non si raggiunge la capacità richiesta
+        let mut buffers = buffers.into_iter();
+        manager.deallocate(buffers.next().unwrap()).unwrap();
+        assert_eq!(manager.stats().total_buffers, 2);
+        manager.deallocate(buffers.next().unwrap()).unwrap();
+        assert_eq!(manager.stats().total_buffers, 1);
+        assert_eq!(manager.pending_shrink_target(), None);
+        manager.deallocate(buffers.next().unwrap()).unwrap();
+        assert_eq!(manager.stats().total_buffers, 1);
+        assert_eq!(manager.stats().available_buffers, 1);
+
+        let mut standard = MemoryManager::new(ApplicationType::DesktopApp, MemoryConfig::default()).unwrap();
+        assert!(matches!(standard.resize_pool(4), Err(CoreError::InvalidOperation(_, _))));
+    }
+
+    /// Test per verificare che la callback di pressione venga invocata al superamento della soglia
+    #[test]
+    fn test_pressure_callback() {
+        use std::sync::Arc;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let mut config = MemoryConfig::new(4 * 64, 64, 1);
+        config.pressure_threshold = 0.75;
+        let mut manager = MemoryManager::new(ApplicationType::WebApp, config).unwrap();
+        let fired = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&fired);
+        manager.set_pressure_callback(Box::new(move |stats| {
+            assert_eq!(stats.total_buffers, 4);
+            assert_eq!(stats.available_buffers, 1);
+            counter.fetch_add(1, Ordering::SeqCst);
+        }));
+
+        // Due buffer su quattro in uso: sotto la soglia
+        let mut buffers: Vec<_> = (0..2).map(|_| manager.allocate(None, 64).unwrap()).collect();
+        assert_eq!(fired.load(Ordering::SeqCst), 0);
+
+        // Il terzo buffer porta l'utilizzo al 75%; il quarto non genera un nuovo superamento
+        buffers.push(manager.allocate(None, 64).unwrap());
+        assert_eq!(fired.load(Ordering::SeqCst), 1);
+        buffers.push(manager.allocate(None, 64).unwrap());
+        assert_eq!(fired.load(Ordering::SeqCst), 1);
+
+        // Tornati sotto la soglia, un nuovo superamento invoca di nuovo la callback
+        manager.deallocate(buffers.pop().unwrap()).unwrap();
+        manager.deallocate(buffers.pop().unwrap()).unwrap();
+        buffers.push(manager.allocate(None, 64).unwrap());
+        assert_eq!(fired.load(Ordering::SeqCst), 2);
+
+        let mut invalid = MemoryConfig::new(4 * 64, 64, 1);
+        invalid.pressure_threshold = 1.5;
+        assert!(MemoryManager::new(ApplicationType::WebApp, invalid).is_err());
+    }
+
+    /// Test per verificare che `restore_from_snapshot` ripristini i buffer liberi del pool
+    #[test]
+    fn test_snapshot_restore() {
+        use solid_arx_lib::config::memory_config::ZeroingPolicy;
+
+        let mut config = MemoryConfig::new(4 * 64, 64, 1);
+        config.zeroing_policy = ZeroingPolicy::NeverZero;
+        let mut manager = MemoryManager::new(ApplicationType::WebApp, config).unwrap();
+        let mut buffer = manager.allocate(None, 64).unwrap();
+        buffer.fill(7);
+        manager.deallocate(buffer).unwrap();
+        // Il buffer ancora in uso non fa parte dell'istantanea
+        let _in_use = manager.allocate(None, 64).unwrap();
+
+        let snapshot = manager.snapshot();
+        assert_eq!(snapshot.buffers.len(), 3);
+
+        #[cfg(feature = "serde")]
+        let snapshot: solid_arx_lib::core::memory_management::MemorySnapshot =
+            serde_json::from_str(&serde_json::to_string(&snapshot).unwrap()).unwrap();
+
+        let mut restored = MemoryManager::restore_from_snapshot(snapshot).unwrap();
+        assert_eq!(restored.stats().total_buffers, 3);
+        assert_eq!(restored.stats().available_buffers, 3);
+        let buffers: Vec<_> = (0..3).map(|_| restored.allocate(None, 64).unwrap()).collect();
+        assert!(buffers.iter().any(|buffer| buffer.iter().all(|byte| *byte == 7)));
+
+        let mut invalid = manager.snapshot();
+        invalid.buffers.push(vec![0u8; 32]);
+        assert!(MemoryManager::restore_from_snapshot(invalid).is_err());
+    }
+
+    /// Test per verificare la serializzazione JSON dei tipi di configurazione e la lettura da TOML
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_config_serde_round_trip() {
+        use solid_arx_lib::config::memory_config::{config_from_file, ZeroingPolicy};
+        use solid_arx_lib::core::memory_management::AllocationStrategy;
+
+        let strategy = AllocationStrategy::GrowingPool { initial_size: 4, max_size: 16, growth_factor: 2.0 };
+        let json = serde_json::to_string(&strategy).unwrap();
+        assert!(json.contains("\"type\":\"GrowingPool\""));
+        let restored: AllocationStrategy = serde_json::from_str(&json).unwrap();
+        assert!(matches!(restored, AllocationStrategy::GrowingPool { initial_size: 4, max_size: 16, .. }));
+
+        let app_type: ApplicationType = serde_json::from_str(&serde_json::to_string(&ApplicationType::ApiBackend).unwrap()).unwrap();
+        assert!(matches!(app_type, ApplicationType::ApiBackend));
+
+        let mut config = MemoryConfig::new(8 * 64, 64, 2);
+        config.zeroing_policy = ZeroingPolicy::ZeroOnBoth;
+        let restored: MemoryConfig = serde_json::from_str(&serde_json::to_string(&config).unwrap()).unwrap();
+        assert_eq!(restored.pool_size, 8 * 64);
+        assert_eq!(restored.buffer_size, 64);
+        assert_eq!(restored.memory_scale, 2);
+        assert_eq!(restored.zeroing_policy, ZeroingPolicy::ZeroOnBoth);
+
+        // I campi assenti nel file assumono i valori di default
+        let path = std::env::temp_dir().join(format!("arx_memory_config_{}.toml", std::process::id()));
+        std::fs::write(&path, "pool_size = 2048\nbuffer_size = 256\n").unwrap();
+        let from_file = config_from_file(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(from_file.pool_size, 2048);
+        assert_eq!(from_file.buffer_size, 256);
+        assert!(from_file.dynamic_fallback);
+        assert!(config_from_file(&path).is_err());
+    }
+
+    /// Test per verificare le politiche LRU, LFU e TTL dell'`EvictingMap` e la callback di rimozione
+    #[test]
+    fn test_evicting_map_policies() {
+        use std::sync::{Arc, Mutex};
+        use std::time::Duration;
+        use solid_arx_lib::core::evicting_map::{EvictingMap, EvictionPolicy};
+
+        // LRU: la lettura di 1 rende 2 la voce meno recente
+        let evicted = Arc::new(Mutex::new(Vec::new()));
+        let log = Arc::clone(&evicted);
+        let mut lru: EvictingMap<u32, &str> = EvictingMap::new(EvictionPolicy::LRU { max_entries: 2 });
+        lru.set_on_evict(Box::new(move |key, value| log.lock().unwrap().push((key, value.to_string()))));
+        lru.insert(1, "uno");
+        lru.insert(2, "due");
+        assert_eq!(lru.get(&1), Some(&"uno"));
+        lru.insert(3, "tre");
+        assert!(!lru.contains_key(&2));
+        assert!(lru.contains_key(&1) && lru.contains_key(&3));
+        assert_eq!(*evicted.lock().unwrap(), vec![(2, "due".to_string())]);
+
+        // LFU: viene rimossa la voce letta meno volte
+        let mut lfu = EvictingMap::new(EvictionPolicy::LFU { max_entries: 2 });
+        lfu.insert(1, 10);
+        lfu.insert(2, 20);
+        lfu.get(&1);
+        lfu.get(&1);
+        lfu.get(&2);
+        lfu.insert(3, 30);
+        assert!(!lfu.contains_key(&2));
+        assert_eq!(lfu.len(), 2);
+
+        // TTL: le voci scadute vengono rimosse alla lettura successiva
+        let mut ttl = EvictingMap::new(EvictionPolicy::TTL { duration: Duration::from_millis(20) });
+        ttl.insert(1, "vecchia");
+        std::thread::sleep(Duration::from_millis(30));
+        ttl.insert(2, "nuova");
+        assert_eq!(ttl.len(), 2);
+        assert!(!ttl.contains_key(&1));
+        assert_eq!(ttl.get(&2), Some(&"nuova"));
+        assert_eq!(ttl.len(), 1);
+        assert_eq!(ttl.get(&1), None);
+    }
+
+    /// Test per verificare che la politica di rimozione si applichi alle mappe globali usate dagli store
+    #[cfg(all(feature = "crud", any(feature = "automation", feature = "embedded")))]
+    #[test]
+    fn test_global_map_eviction_policy() {
+        use solid_arx_lib::core::evicting_map::EvictionPolicy;
+        use solid_arx_lib::core::memory_management::CONFIGURATIONS_IN_MEMORY;
+        use solid_arx_lib::crud::models::default::configuration::model::Configuration;
+
+        let configuration = |id: u32| Configuration::new(
+            id,
+            #[cfg(feature = "embedded")] None,
+            format!("KEY_{}", id),
+            "valore".to_string(),
+            vec![0u8; 8].into_boxed_slice(),
+        );
+
+        let mut configurations = CONFIGURATIONS_IN_MEMORY.lock().unwrap();
+        configurations.clear();
+        for id in 8000..8003 {
+            configurations.insert(id, configuration(id));
+        }
+
+        // La nuova politica si applica subito alle voci presenti, a partire dalla meno recente
+        assert_eq!(configurations.set_policy(EvictionPolicy::LRU { max_entries: 2 }), 1);
+        assert!(!configurations.contains_key(&8000));
+        configurations.get(&8001);
+        configurations.insert(8003, configuration(8003));
+        assert!(configurations.contains_key(&8001));
+        assert!(!configurations.contains_key(&8002));
+        assert_eq!(configurations.len(), 2);
+
+        configurations.set_policy(EvictionPolicy::None);
+        configurations.clear();
+    }
+
+    /// Test per verificare che `defragment` ordini i buffer liberi per indirizzo crescente
+    #[test]
+    fn test_defragment() {
+        let mut manager = MemoryManager::new(ApplicationType::WebApp, MemoryConfig::new(8 * 64, 64, 1)).unwrap();
+        let mut buffers: Vec<_> = (0..8).map(|_| manager.allocate(None, 64).unwrap()).collect();
+        // Restituiti in ordine decrescente di indirizzo, i buffer lasciano il pool frammentato
+        buffers.sort_by_key(|buffer| std::cmp::Reverse(buffer.as_ptr() as usize));
+        for buffer in buffers {
+            manager.deallocate(buffer).unwrap();
+        }
+
+        assert!(manager.defragment().unwrap() > 0);
+        assert_eq!(manager.defragment().unwrap(), 0);
+        let addresses: Vec<usize> = (0..8).map(|_| manager.allocate(None, 64).unwrap().as_ptr() as usize).collect();
+        assert!(addresses.windows(2).all(|pair| pair[0] < pair[1]));
+
+        // Senza pool la deframmentazione è disponibile solo con la compattazione dell'heap
+        let mut standard = MemoryManager::new(ApplicationType::DesktopApp, MemoryConfig::default()).unwrap();
+        #[cfg(not(feature = "compact-retry"))]
+        assert!(matches!(standard.defragment(), Err(solid_arx_lib::core::system_core::CoreError::InvalidOperation(_, _))));
+        #[cfg(feature = "compact-retry")]
+        assert_eq!(standard.defragment().unwrap(), 0);
+    }
+
+    /// Test per verificare che `GameServer` usi `DoubleBuffered` e che `swap_buffers` scambi i due pool
+    #[test]
+    fn test_game_server_swap_buffers() {
+        use solid_arx_lib::core::system_core::CoreError;
+
+        // Due pool da 2 buffer ciascuno
+        let mut manager = MemoryManager::new(ApplicationType::GameServer, MemoryConfig::new(4 * 64, 64, 1)).unwrap();
+        assert_eq!(manager.memory_stats().strategy, "DoubleBuffered");
+        assert_eq!(manager.memory_stats().free_buffers, 2);
+
+        let frame: Vec<_> = (0..2).map(|_| manager.allocate(None, 64).unwrap()).collect();
+        let frame_addresses: Vec<usize> = frame.iter().map(|b| b.as_ptr() as usize).collect();
+        assert_eq!(manager.memory_stats().free_buffers, 0);
+
+        // Il pool preparato è disponibile per il frame successivo
+        manager.swap_buffers().unwrap();
+        assert_eq!(manager.memory_stats().free_buffers, 2);
+
+        // I buffer del frame concluso tornano al loro pool, ora in preparazione
+        let next: Vec<_> = (0..1).map(|_| manager.allocate(None, 64).unwrap()).collect();
+        for buffer in frame {
+            manager.deallocate(buffer).unwrap();
+        }
+        assert_eq!(manager.memory_stats().free_buffers, 1);
+
+        // Dopo lo scambio ciascun pool ha di nuovo i propri 2 buffer
+        manager.swap_buffers().unwrap();
+        assert_eq!(manager.memory_stats().free_buffers, 2);
+        let reused: Vec<_> = (0..2).map(|_| manager.allocate(None, 64).unwrap()).collect();
+        assert!(reused.iter().all(|b| frame_addresses.contains(&(b.as_ptr() as usize))));
+        for buffer in next {
+            manager.deallocate(buffer).unwrap();
+        }
+        manager.swap_buffers().unwrap();
+        assert_eq!(manager.memory_stats().free_buffers, 2);
+        for buffer in reused {
+            manager.deallocate(buffer).unwrap();
+        }
+
+        let mut web = MemoryManager::new(ApplicationType::WebApp, MemoryConfig::new(4 * 64, 64, 1)).unwrap();
+        assert!(matches!(web.swap_buffers(), Err(CoreError::InvalidOperation(_, _))));
+    }
+
+    /// Test per verificare che `SharedMemoryManager` permetta di allocare da più thread
+    #[test]
+    fn test_shared_memory_manager_threads() {
+        use solid_arx_lib::core::memory_management::SharedMemoryManager;
+
+        let manager = MemoryManager::new(ApplicationType::WebApp, MemoryConfig::new(4 * 64, 64, 1)).unwrap();
+        let shared = SharedMemoryManager::new(manager);
+
+        let workers: Vec<_> = (0..4)
+            .map(|_| {
+                let handle = shared.clone_handle();
+                std::thread::spawn(move || {
+                    for _ in 0..10 {
+                        let buffer = handle.allocate(None, 64).unwrap();
+                        handle.deallocate(buffer).unwrap();
+                    }
+                })
+            })
+            .collect();
+        for worker in workers {
+            worker.join().unwrap();
+        }
+
+        let stats = shared.lock().unwrap().memory_stats();
+        assert_eq!(stats.total_allocations, 40);
+        assert_eq!(stats.total_deallocations, 40);
+        assert_eq!(stats.free_buffers, 4);
+    }
+
+    /// Test per verificare che `transfer_boxed` sposti un `Box<[u8]>` senza copia o lo copi se le dimensioni differiscono
+    #[test]
+    fn test_transfer_boxed() {
+        let mut source = MemoryManager::new(ApplicationType::DesktopApp, MemoryConfig::new(1024, 64, 1)).unwrap();
+        let mut dest = MemoryManager::new(ApplicationType::DesktopApp, MemoryConfig::new(1024, 64, 1)).unwrap();
+
+        let mut buffer = vec![0u8; 64].into_boxed_slice();
+        buffer[0] = 42;
+        let address = buffer.as_ptr();
+        let moved = MemoryManager::transfer_boxed(buffer, &mut source, &mut dest).unwrap();
+        assert_eq!(moved.as_ptr(), address);
+        assert_eq!(moved[0], 42);
+
+        let mut larger = MemoryManager::new(ApplicationType::DesktopApp, MemoryConfig::new(1024, 128, 1)).unwrap();
+        let copied = MemoryManager::transfer_boxed(moved, &mut dest, &mut larger).unwrap();
+        assert_eq!(copied.len(), 64);
+        assert_eq!(copied[0], 42);
+    }
+
+    /// Test per verificare che `transfer` sposti il buffer senza copia o lo copi se le dimensioni differiscono
+    #[test]
+    fn test_transfer_between_managers() {
+        use solid_arx_lib::core::system_core::CoreError;
+
+        let mut source = MemoryManager::new(ApplicationType::WebApp, MemoryConfig::new(4 * 64, 64, 1)).unwrap();
+        let mut dest = MemoryManager::new(ApplicationType::WebApp, MemoryConfig::new(4 * 64, 64, 1)).unwrap();
+
+        let mut buffer = source.allocate(None, 64).unwrap();
+        buffer[0] = 42;
+        let address = buffer.as_ptr();
+        let moved = MemoryManager::transfer(buffer, &mut source, &mut dest).unwrap();
+        assert_eq!(moved.as_ptr(), address);
+        assert_eq!(moved[0], 42);
+        assert_eq!(source.memory_stats().pool_capacity, 3);
+        assert_eq!(dest.memory_stats().pool_capacity, 5);
+        dest.deallocate(moved).unwrap();
+        assert_eq!(dest.memory_stats().free_buffers, 5);
+
+        // Dimensioni diverse: il contenuto viene copiato in un buffer di `dest`
+        let mut larger = MemoryManager::new(ApplicationType::WebApp, MemoryConfig::new(4 * 128, 128, 1)).unwrap();
+        let mut buffer = source.allocate(None, 64).unwrap();
+        buffer[0] = 7;
+        let copied = MemoryManager::transfer(buffer, &mut source, &mut larger).unwrap();
+        assert_eq!(copied.len(), 128);
+        assert_eq!(copied[0], 7);
+        assert_eq!(source.memory_stats().free_buffers, 3);
+
+        let no_copy = MemoryConfig { copy_on_transfer: false, ..MemoryConfig::new(4 * 128, 128, 1) };
+        let mut strict = MemoryManager::new(ApplicationType::WebApp, no_copy).unwrap();
+        let mut buffer = source.allocate(None, 64).unwrap();
+        buffer[0] = 9;
+        // Il buffer rifiutato torna al chiamante con il suo contenuto ed è ancora di `source`
+        let rejected = match MemoryManager::transfer(buffer, &mut source, &mut strict) {
+            Err(CoreError::TransferSizeMismatch { src: 64, dst: 128, buffer, .. }) => buffer,
+            other => panic!("Atteso TransferSizeMismatch, ottenuto {:?}", other),
+        };
+        assert_eq!(rejected[0], 9);
+        assert_eq!(source.memory_stats().free_buffers, 2);
+        source.deallocate(rejected).unwrap();
+        assert_eq!(source.memory_stats().free_buffers, 3);
+
+        // Un buffer `Standard` di dimensione diversa non entra nel pool di `dest` senza copia
+        let odd = source.allocate(Some(solid_arx_lib::core::memory_management::AllocationStrategy::Standard), 32).unwrap();
+        let copied = MemoryManager::transfer(odd, &mut source, &mut dest).unwrap();
+        assert_eq!(copied.len(), 64);
+        assert_eq!(dest.memory_stats().pool_capacity, 5);
+    }
+
+    /// Test per verificare che le variabili d'ambiente sostituiscano i campi letti dal file JSON
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_config_from_env_with_fallback() {
+        use solid_arx_lib::config::memory_config::{ENV_BUFFER_SIZE, ENV_MEMORY_SCALE, ENV_POOL_SIZE};
+        use solid_arx_lib::core::system_core::CoreError;
+
+        let path = std::env::temp_dir().join(format!("arx_memory_env_{}.json", std::process::id()));
+        std::fs::write(&path, r#"{"pool_size": 4096, "buffer_size": 512, "memory_scale": 3}"#).unwrap();
+
+        std::env::set_var(ENV_BUFFER_SIZE, "128");
+        std::env::remove_var(ENV_POOL_SIZE);
+        std::env::remove_var(ENV_MEMORY_SCALE);
+        let config = MemoryConfig::from_env_with_fallback(Some(&path)).unwrap();
+        assert_eq!(config.buffer_size, 128);
+        assert_eq!(config.pool_size, 4096);
+        assert_eq!(config.memory_scale, 3);
+
+        std::env::set_var(ENV_POOL_SIZE, "molti");
+        let error = MemoryConfig::from_env_with_fallback(None).unwrap_err();
+        assert!(matches!(&error, CoreError::ConfigurationError(msg, _) if msg.contains(ENV_POOL_SIZE)));
+
+        std::env::remove_var(ENV_BUFFER_SIZE);
+        std::env::remove_var(ENV_POOL_SIZE);
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    /// Test per verificare `fragmentation_ratio` su un pool con buffer liberi alternati
+    #[test]
+    fn test_fragmentation_ratio_pool() {
+        use solid_arx_lib::core::memory_management::AllocationStrategy;
+
+        let mut manager = MemoryManager::with_strategy(AllocationStrategy::PoolBased, MemoryConfig::new(8 * 64, 64, 1)).unwrap();
+        let mut buffers: Vec<_> = (0..8).map(|_| Some(manager.allocate(None, 64).unwrap())).collect();
+        assert_eq!(manager.fragmentation_ratio(), 0.0, "Senza buffer liberi non c'è frammentazione");
+
+        // Vengono liberati solo i buffer di posizione pari
+        for slot in buffers.iter_mut().step_by(2) {
+            manager.deallocate(slot.take().unwrap()).unwrap();
+        }
+        assert!(manager.fragmentation_ratio() > 0.4, "Frammentazione {}", manager.fragmentation_ratio());
+
+        let standard = MemoryManager::with_strategy(AllocationStrategy::Standard, MemoryConfig::default()).unwrap();
+        assert_eq!(standard.fragmentation_ratio(), 0.0);
+    }
+
+    /// Test per verificare che un pool interamente libero sia meno frammentato di uno con buffer liberi alternati
+    #[test]
+    fn test_fragmentation_ratio_pool_contiguous() {
+        use solid_arx_lib::core::memory_management::AllocationStrategy;
+
+        let config = MemoryConfig::new(8 * 64, 64, 1);
+        let mut freed = MemoryManager::with_strategy(AllocationStrategy::PoolBased, config.clone()).unwrap();
+        let buffers: Vec<_> = (0..8).map(|_| freed.allocate(None, 64).unwrap()).collect();
+        for buffer in buffers {
+            freed.deallocate(buffer).unwrap();
+        }
+        freed.defragment().unwrap();
+        assert_eq!(freed.fragmentation_ratio(), 0.0, "I buffer del pool sono ricavati da un'area contigua");
+        assert_eq!(freed.largest_free_contiguous_bytes(), 8 * 64);
+
+        let mut alternating = MemoryManager::with_strategy(AllocationStrategy::PoolBased, config).unwrap();
+        let mut buffers: Vec<_> = (0..8).map(|_| Some(alternating.allocate(None, 64).unwrap())).collect();
+        for slot in buffers.iter_mut().step_by(2) {
+            alternating.deallocate(slot.take().unwrap()).unwrap();
+        }
+        assert!(freed.fragmentation_ratio() < alternating.fragmentation_ratio());
+        assert!((alternating.fragmentation_ratio() - 0.75).abs() < 0.01, "Frammentazione {}", alternating.fragmentation_ratio());
+    }
+
+    /// Test per verificare la strategia `HugePage` di `MLInference` e l'allocazione su pagine huge
+    #[test]
+    fn test_ml_inference_huge_page() {
+        use solid_arx_lib::core::system_core::CoreError;
+        use solid_arx_lib::core::memory_management::{AllocationStrategy, HUGE_PAGE_SIZE};
+
+        let mut manager = MemoryManager::new(ApplicationType::MLInference, MemoryConfig::new(4096, 1024, 1)).unwrap();
+        assert_eq!(manager.memory_stats().strategy, format!("{:?}", AllocationStrategy::HugePage { page_size: HUGE_PAGE_SIZE }));
+
+        // Le pagine huge dipendono dalla configurazione del kernel: senza pagine riservate l'errore è atteso
+        match manager.allocate_huge_page(3 * 1024 * 1024) {
+            Ok(mut buffer) => {
+                assert_eq!(buffer.len(), 3 * 1024 * 1024);
+                assert_eq!(buffer.as_ptr() as usize % HUGE_PAGE_SIZE, 0);
+                assert!(buffer.iter().all(|byte| *byte == 0));
+                buffer[0] = 1;
+            }
+            Err(CoreError::UnsupportedPlatform(_, _)) => {}
+            Err(e) => panic!("Errore inatteso: {}", e),
+        }
+
+        assert!(matches!(
+            MemoryManager::with_strategy(AllocationStrategy::HugePage { page_size: 3000 }, MemoryConfig::default()),
+            Err(CoreError::ConfigurationError(_, _))
+        ));
+    }
+
+    /// Test per verificare che `with_strategy` rifiuti `buffer_size == 0` invece di dividere per zero
+    #[test]
+    fn test_with_strategy_zero_buffer_size() {
+        use solid_arx_lib::core::memory_management::AllocationStrategy;
+        use solid_arx_lib::core::system_core::CoreError;
+
+        let strategies = [
+            AllocationStrategy::PoolBased,
+            AllocationStrategy::DoubleBuffered,
+            AllocationStrategy::GrowingPool { initial_size: 2, max_size: 4, growth_factor: 2.0 },
+            AllocationStrategy::Standard,
+        ];
+        for strategy in strategies {
+            assert!(matches!(
+                MemoryManager::with_strategy(strategy, MemoryConfig::new(1024, 0, 1)),
+                Err(CoreError::ConfigurationError(_, _))
+            ));
+        }
+    }
+
+    /// Test per verificare che `max_single_allocation` rifiuti le richieste oltre il limite
+    #[test]
+    fn test_max_single_allocation() {
+        use solid_arx_lib::core::memory_management::AllocationStrategy;
+        use solid_arx_lib::core::system_core::CoreError;
+
+        let mut config = MemoryConfig::new(1024, 1024, 1);
+        config.max_single_allocation = Some(1_000_000);
+        // L'alias limita anche gli altri percorsi tramite `max_allocation_size_for`
+        assert_eq!(config.max_allocation_size_for(&AllocationStrategy::Standard), 1_000_000);
+        let mut manager = MemoryManager::new(ApplicationType::DesktopApp, config).unwrap();
+        match manager.allocate(None, 2 * 1024 * 1024) {
+            Err(CoreError::ResourceAllocationError(message, _)) => {
+                assert_eq!(message, format!("requested {} exceeds max {}", 2 * 1024 * 1024, 1_000_000));
+            }
+            other => panic!("Atteso ResourceAllocationError, ottenuto {:?}", other.map(|b| b.len())),
+        }
+        assert_eq!(manager.allocate(None, 500 * 1024).unwrap().len(), 500 * 1024);
+    }
+
+    /// Test per verificare il limite invalicabile `max_allocation_size` ai suoi estremi
+    #[test]
+    fn test_max_allocation_size() {
+        use solid_arx_lib::core::memory_management::AllocationStrategy;
+        use solid_arx_lib::core::system_core::CoreError;
+
+        let mut config = MemoryConfig::new(1024, 1024, 1);
+        config.max_allocation_size = Some(1000);
+        let mut manager = MemoryManager::new(ApplicationType::DesktopApp, config).unwrap();
+        assert_eq!(manager.allocate(Some(AllocationStrategy::Standard), 999).unwrap().len(), 999);
+        assert_eq!(manager.allocate(Some(AllocationStrategy::Standard), 1000).unwrap().len(), 1000);
+        assert!(matches!(
+            manager.allocate(Some(AllocationStrategy::Standard), 1001),
+            Err(CoreError::AllocationTooLarge { requested: 1001, max: 1000, .. })
+        ));
+
+        // Con `PoolBased` il limite predefinito è `pool_size`, con le altre strategie `usize::MAX / 2`
+        let pooled_config = MemoryConfig::new(4 * 64, 64, 1);
+        assert_eq!(pooled_config.max_allocation_size_for(&AllocationStrategy::Standard), usize::MAX / 2);
+        let mut pooled = MemoryManager::new(ApplicationType::WebApp, pooled_config).unwrap();
+        assert!(pooled.allocate(None, 4 * 64).is_ok());
+        assert!(matches!(
+            pooled.allocate(None, 4 * 64 + 1),
+            Err(CoreError::AllocationTooLarge { requested: 257, max: 256, .. })
+        ));
+
+        // Con `CustomEmbedded` il limite è `buffer_size`
+        let mut embedded = MemoryManager::new(ApplicationType::EmbeddedSystem, MemoryConfig::new(1024, 128, 1)).unwrap();
+        assert!(matches!(
+            embedded.allocate(None, 129),
+            Err(CoreError::AllocationTooLarge { requested: 129, max: 128, .. })
+        ));
+    }
+
+    /// Test per verificare l'allocazione a incremento dell'arena e il suo azzeramento con `reset`
+    #[test]
+    fn test_memory_arena() {
+        use solid_arx_lib::core::memory_management::MemoryArena;
+        use solid_arx_lib::core::system_core::CoreError;
+
+        let mut manager = MemoryManager::new(ApplicationType::WebApp, MemoryConfig::new(4 * 256, 256, 1)).unwrap();
+        let mut arena = MemoryArena::from_manager(&mut manager).unwrap();
+        assert_eq!(arena.capacity(), 256);
+
+        arena.alloc(100).unwrap().fill(1);
+        let second = arena.alloc(156).unwrap();
+        assert_eq!(second.len(), 156);
+        assert_eq!(arena.used(), 256);
+        assert!(matches!(arena.alloc(1), Err(CoreError::ResourceAllocationError(_, _))));
+        assert!(matches!(arena.alloc(usize::MAX), Err(CoreError::ResourceAllocationError(_, _))));
+
+        arena.reset();
+        assert_eq!(arena.remaining(), 256);
+        assert_eq!(arena.alloc(256).unwrap().len(), 256);
+
+        // Al rilascio dell'arena il buffer torna al pool
+        drop(arena);
+        assert_eq!(manager.memory_stats().total_deallocations, 1);
+        assert_eq!(manager.memory_stats().free_buffers, 4);
+    }
+}
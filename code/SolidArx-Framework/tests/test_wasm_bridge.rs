@@ -0,0 +1,29 @@
+/// # Unit Tests per `monitoring/wasm_bridge.rs`
+///
+/// I test vengono eseguiti in un browser headless con `wasm-bindgen-test`:
+/// wasm-pack test --headless --firefox -- --features "wasm","wasm-bindgen"
+
+#[cfg(all(test, target_arch = "wasm32", feature = "wasm", feature = "wasm-bindgen"))]
+mod tests {
+    use wasm_bindgen_test::*;
+    use solid_arx_lib::config::{global_config::ApplicationType, memory_config::MemoryConfig};
+    use solid_arx_lib::core::memory_management::MemoryManager;
+    use solid_arx_lib::monitoring::wasm_bridge::{memory_stats, pool_capacity, pool_free_buffers};
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    /// Test per verificare che le funzioni esportate riflettano la configurazione del pool
+    #[wasm_bindgen_test]
+    fn test_pool_stats_from_js() {
+        let mut manager = MemoryManager::new(ApplicationType::WebApp, MemoryConfig::new(4096, 1024, 1)).unwrap();
+        manager.expose_to_js();
+        assert_eq!(pool_capacity(), 4);
+        assert_eq!(pool_free_buffers(), 4);
+
+        let _buffer = manager.allocate(None, 1024).unwrap();
+        assert_eq!(pool_free_buffers(), 3);
+
+        let stats = memory_stats().unwrap();
+        assert!(stats.is_object());
+    }
+}
@@ -0,0 +1,64 @@
+//! `arx-mem-cli`: ispezione a runtime del `MemoryManager` di un processo in esecuzione.
+//!
+//! Si collega al socket Unix aperto con `MemoryManager::start_ipc_server` e stampa in JSON
+//! la risposta del server, ad esempio:
+//! arx-mem-cli --socket /tmp/arx-mem.sock stats
+
+use std::path::PathBuf;
+use std::process::ExitCode;
+use clap::{Parser, ValueEnum};
+use solid_arx_lib::monitoring::ipc::{send_command, IpcCommand, IpcResponse};
+
+/// CLI per l'ispezione della memoria di un processo Arx
+#[derive(Parser)]
+#[command(name = "arx-mem-cli")]
+#[command(about = "Ispezione a runtime del MemoryManager tramite socket Unix", long_about = None)]
+struct Cli {
+    /// Percorso del socket del server IPC
+    #[arg(short = 's', long = "socket")]
+    socket: PathBuf,
+    /// Comando da eseguire
+    command: Command,
+}
+
+/// Comandi supportati dal server IPC
+#[derive(Clone, Copy, ValueEnum)]
+enum Command {
+    /// Statistiche correnti del pool
+    Stats,
+    /// Azzera i contatori delle allocazioni
+    Reset,
+    /// Rilascia i buffer liberi del pool
+    Drain,
+    /// Riporta il pool alla capacità configurata
+    Refill,
+}
+
+impl From<Command> for IpcCommand {
+    fn from(command: Command) -> Self {
+        match command {
+            Command::Stats => IpcCommand::Stats,
+            Command::Reset => IpcCommand::Reset,
+            Command::Drain => IpcCommand::Drain,
+            Command::Refill => IpcCommand::Refill,
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    match send_command(&cli.socket, cli.command.into()) {
+        Ok(IpcResponse::Stats(stats)) => {
+            println!("{}", serde_json::to_string_pretty(&stats).unwrap_or_default());
+            ExitCode::SUCCESS
+        }
+        Ok(IpcResponse::Error(message)) => {
+            eprintln!("Errore del server: {}", message);
+            ExitCode::FAILURE
+        }
+        Err(e) => {
+            eprintln!("{}", e);
+            ExitCode::FAILURE
+        }
+    }
+}